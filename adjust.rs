@@ -0,0 +1,326 @@
+//! Bulk price adjustment.
+//!
+//! `propose <target> <change> [fields]` works out new prices for every item
+//! the target picks, rounds them with the rounding policy and writes the
+//! change set for review. Nothing in the menu source is edited.
+//!
+//! Targets are `tag=combos`, `category=Combos`, `plu=480,490` or `id=<uuid>`.
+//! A category also picks the items its item shells offer. Changes are a
+//! percentage (`5%`, `-3%`) or flat cents (`+10`, `-10`). Fields default to
+//! `price` and may also include `upcharges`; `variations` and `overrides`
+//! are accepted, but `apply` cant patch those prices in the menu source, so
+//! a proposal that changes them is refused. Free (zero) prices are left
+//! alone.
+
+use crate::price_changes::{item_prices, PriceChange};
+use crate::rounding::RoundingPolicy;
+use crate::selection;
+use adjunct::{ItemDefinition, Menu, ID};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Target {
+    Tag(String),
+    Category(String),
+    Plus(Vec<String>),
+    Id(ID),
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let (kind, value) = s
+            .split_once('=')
+            .ok_or_else(|| format!("target {} should look like tag=combos", s))?;
+        match kind {
+            "tag" => Ok(Target::Tag(value.to_string())),
+            "category" => Ok(Target::Category(value.to_string())),
+            "plu" => Ok(Target::Plus(value.split(',').map(String::from).collect())),
+            "id" => ID::parse_str(value)
+                .map(Target::Id)
+                .map_err(|e| format!("bad id {}: {}", value, e)),
+            _ => Err(format!("unknown target kind {}", kind)),
+        }
+    }
+}
+
+impl Target {
+    pub fn items<'a>(&self, menu: &'a Menu) -> Result<Vec<&'a ItemDefinition>, String> {
+        Ok(match self {
+            Target::Tag(tag) => menu
+                .items
+                .iter()
+                .filter(|item| item.tags.contains(tag))
+                .collect(),
+            Target::Plus(plus) => menu
+                .items
+                .iter()
+                .filter(|item| plus.contains(&item.plu))
+                .collect(),
+            Target::Id(id) => menu.items.iter().filter(|item| item.id == *id).collect(),
+            Target::Category(name) => {
+                let category = menu
+                    .categories
+                    .iter()
+                    .find(|category| &category.name == name)
+                    .ok_or_else(|| format!("no category named {}", name))?;
+                selection::category_items(menu, category)
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    /// Percentage change, e.g. 5.0 for +5%.
+    Percent(f64),
+    /// Flat change in cents.
+    Flat(i64),
+}
+
+impl FromStr for Change {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s.strip_suffix('%') {
+            Some(percent) => percent
+                .parse()
+                .map(Change::Percent)
+                .map_err(|_| format!("bad percentage {}", s)),
+            None => s
+                .parse()
+                .map(Change::Flat)
+                .map_err(|_| format!("bad change {}, expected e.g. 5% or +10", s)),
+        }
+    }
+}
+
+impl Change {
+    /// `price` changed and rounded by `policy`. A change of nothing leaves
+    /// the price as it is, and a negative price (a combo credit) keeps its
+    /// sign while its magnitude is changed and rounded.
+    pub fn apply(&self, price: i64, policy: &RoundingPolicy) -> i64 {
+        if price == 0 || self.is_noop() {
+            return price;
+        }
+        if price < 0 {
+            return -self.apply(-price, policy);
+        }
+        let changed = match *self {
+            Change::Percent(percent) => (price as f64 * (100.0 + percent) / 100.0).round() as i64,
+            Change::Flat(cents) => price + cents,
+        };
+        policy.round(changed.max(0))
+    }
+
+    fn is_noop(&self) -> bool {
+        match *self {
+            Change::Percent(percent) => percent == 0.0,
+            Change::Flat(cents) => cents == 0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Field {
+    Price,
+    Variations,
+    Upcharges,
+    Overrides,
+}
+
+impl Field {
+    /// Whether `path`, a price field path as in a change set, is this field.
+    pub fn covers(&self, path: &str) -> bool {
+        match self {
+            Field::Price => path == "price",
+            Field::Variations => path.starts_with("variations."),
+            Field::Upcharges => path.starts_with("modifier_upcharge."),
+            Field::Overrides => path.starts_with("slots."),
+        }
+    }
+}
+
+impl FromStr for Field {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "price" => Ok(Field::Price),
+            "variations" => Ok(Field::Variations),
+            "upcharges" => Ok(Field::Upcharges),
+            "overrides" => Ok(Field::Overrides),
+            _ => Err(format!("unknown field {}", s)),
+        }
+    }
+}
+
+pub struct Adjustment {
+    pub target: Target,
+    pub change: Change,
+    pub fields: Vec<Field>,
+}
+
+impl Adjustment {
+    /// Parses `<target> <change> [fields]`.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let target = args.first().ok_or("propose needs a target")?.parse()?;
+        let change = args.get(1).ok_or("propose needs a change")?.parse()?;
+        let fields = match args.get(2) {
+            Some(fields) => fields
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()?,
+            None => vec![Field::Price],
+        };
+        Ok(Adjustment {
+            target,
+            change,
+            fields,
+        })
+    }
+
+    pub fn changes(
+        &self,
+        menu: &Menu,
+        policy: &RoundingPolicy,
+    ) -> Result<Vec<PriceChange>, String> {
+        let mut changes = Vec::new();
+        for item in self.target.items(menu)? {
+            for (field, old) in item_prices(item) {
+                if !self.fields.iter().any(|covered| covered.covers(&field)) {
+                    continue;
+                }
+                let new = self.change.apply(old, policy);
+                if new != old {
+                    changes.push(PriceChange {
+                        id: item.id,
+                        plu: item.plu.clone(),
+                        item: item.long_name.clone(),
+                        field,
+                        old,
+                        new,
+                    });
+                }
+            }
+        }
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{tags, ItemBuilder};
+    use crate::price_changes::apply_to_menu;
+    use crate::rounding::Direction;
+    use adjunct::Modification;
+
+    fn menu() -> Menu {
+        let mut menu = Menu::default();
+        ItemBuilder::new(
+            "00000000-0000-4000-8000-000000000001",
+            "Crispy Taco",
+            "Taco",
+        )
+        .price(335)
+        .plu("1")
+        .tags(&[tags::TACOS])
+        .upcharge(Modification::Extra, 50)
+        .add_to(&mut menu);
+        ItemBuilder::new("00000000-0000-4000-8000-000000000002", "Soft Taco", "Soft")
+            .price(199)
+            .plu("2")
+            .tags(&[tags::TACOS])
+            .add_to(&mut menu);
+        ItemBuilder::new(
+            "00000000-0000-4000-8000-000000000003",
+            "Large Drink",
+            "Drink",
+        )
+        .price(219)
+        .plu("3")
+        .tags(&[tags::DRINKS])
+        .upcharge(Modification::Extra, 50)
+        .add_to(&mut menu);
+        menu
+    }
+
+    fn adjust(args: &str, policy: RoundingPolicy) -> (Vec<PriceChange>, Menu) {
+        let mut menu = menu();
+        let args: Vec<String> = args.split(' ').map(String::from).collect();
+        let changes = Adjustment::parse(&args)
+            .unwrap()
+            .changes(&menu, &policy)
+            .unwrap();
+        apply_to_menu(&mut menu, &changes).unwrap();
+        (changes, menu)
+    }
+
+    fn prices(changes: &[PriceChange]) -> Vec<(&str, &str, i64, i64)> {
+        changes
+            .iter()
+            .map(|change| {
+                (
+                    change.plu.as_str(),
+                    change.field.as_str(),
+                    change.old,
+                    change.new,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn percent_change_is_rounded_by_the_policy() {
+        let policy = RoundingPolicy::Nearest {
+            cents: 5,
+            direction: Direction::Nearest,
+        };
+        let (changes, _) = adjust("tag=tacos 10%", policy);
+        // 368.5 and 218.9 before rounding.
+        assert_eq!(
+            prices(&changes),
+            [("1", "price", 335, 370), ("2", "price", 199, 220)]
+        );
+    }
+
+    #[test]
+    fn flat_change_covers_the_chosen_fields() {
+        let policy = RoundingPolicy::EndIn {
+            digit: 9,
+            direction: Direction::Nearest,
+        };
+        let (changes, _) = adjust("plu=1,2 +10 price,upcharges", policy);
+        assert_eq!(
+            prices(&changes),
+            [
+                ("1", "price", 335, 349),
+                ("1", "modifier_upcharge.Extra", 50, 59),
+                ("2", "price", 199, 209),
+            ]
+        );
+    }
+
+    #[test]
+    fn unselected_items_stay_unchanged() {
+        let (changes, adjusted) = adjust("tag=tacos -10 price,upcharges", RoundingPolicy::None);
+        assert!(changes.iter().all(|change| change.plu != "3"));
+        let before = menu();
+        assert_eq!(adjusted.items[2].price, before.items[2].price);
+        assert_eq!(
+            adjusted.items[2].modifier_upcharge,
+            before.items[2].modifier_upcharge
+        );
+        assert_eq!(adjusted.items[0].price, before.items[0].price - 10);
+    }
+
+    #[test]
+    fn credits_keep_their_sign_and_free_prices_stay_free() {
+        let change = Change::Percent(10.0);
+        assert_eq!(change.apply(-100, &RoundingPolicy::None), -110);
+        assert_eq!(change.apply(0, &RoundingPolicy::None), 0);
+        assert_eq!(Change::Flat(0).apply(341, &RoundingPolicy::None), 341);
+    }
+}
@@ -0,0 +1,141 @@
+//! Checks category images against the local asset directory and writes the
+//! asset manifest that ships next to `menu.json`.
+
+use crate::hash;
+use adjunct::Menu;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where images live unless `MENU_ASSET_DIR` says otherwise.
+pub const DEFAULT_ASSET_DIR: &str = "images";
+
+pub fn asset_dir() -> PathBuf {
+    std::env::var("MENU_ASSET_DIR")
+        .unwrap_or_else(|_| DEFAULT_ASSET_DIR.to_string())
+        .into()
+}
+
+#[derive(Serialize)]
+pub struct AssetEntry {
+    pub file: String,
+    pub bytes: u64,
+    pub hash: String,
+    pub used_by: Vec<String>,
+}
+
+pub struct AssetReport {
+    pub dir: PathBuf,
+    pub dir_exists: bool,
+    /// Image file name to the categories that reference it.
+    pub references: BTreeMap<String, Vec<String>>,
+    pub missing: Vec<String>,
+    pub manifest: Vec<AssetEntry>,
+}
+
+impl AssetReport {
+    pub fn check(menu: &Menu, dir: &Path) -> Self {
+        let mut references: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for category in &menu.categories {
+            if let Some(image) = &category.image {
+                references
+                    .entry(image.clone())
+                    .or_default()
+                    .push(category.name.clone());
+            }
+        }
+
+        let dir_exists = dir.is_dir();
+        let mut missing = Vec::new();
+        let mut manifest = Vec::new();
+        for (file, used_by) in &references {
+            match fs::read(dir.join(file)) {
+                Ok(bytes) => manifest.push(AssetEntry {
+                    file: file.clone(),
+                    bytes: bytes.len() as u64,
+                    hash: hash::hex(&bytes),
+                    used_by: used_by.clone(),
+                }),
+                Err(_) => missing.push(file.clone()),
+            }
+        }
+
+        AssetReport {
+            dir: dir.to_path_buf(),
+            dir_exists,
+            references,
+            missing,
+            manifest,
+        }
+    }
+
+    /// Images referenced by more than one category, usually a copy-paste leftover.
+    pub fn reused(&self) -> Vec<(&str, &[String])> {
+        self.references
+            .iter()
+            .filter(|(_, used_by)| used_by.len() > 1)
+            .map(|(file, used_by)| (file.as_str(), used_by.as_slice()))
+            .collect()
+    }
+
+    pub fn with_spaces(&self) -> Vec<&str> {
+        self.references
+            .keys()
+            .filter(|file| file.contains(char::is_whitespace))
+            .map(String::as_str)
+            .collect()
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !self.dir_exists {
+            warnings.push(format!(
+                "asset directory {} not found, images were not checked",
+                self.dir.display()
+            ));
+        } else {
+            for file in &self.missing {
+                warnings.push(format!(
+                    "image {} is missing from {}",
+                    file,
+                    self.dir.display()
+                ));
+            }
+        }
+        for file in self.with_spaces() {
+            warnings.push(format!("image \"{}\" has spaces in its file name", file));
+        }
+        for (file, used_by) in self.reused() {
+            warnings.push(format!(
+                "image {} is shared by {}",
+                file,
+                used_by.join(", ")
+            ));
+        }
+        warnings
+    }
+
+    pub fn manifest_json(&self) -> String {
+        serde_json::to_string_pretty(&self.manifest).expect("Couldnt serialize asset manifest")
+    }
+}
+
+impl fmt::Display for AssetReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Images in {}:", self.dir.display())?;
+        for (file, used_by) in &self.references {
+            let status = if self.missing.contains(file) {
+                "missing"
+            } else {
+                "ok"
+            };
+            writeln!(f, "  {} [{}]: {}", file, status, used_by.join(", "))?;
+        }
+        for warning in self.warnings() {
+            writeln!(f, "warning: {}", warning)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+//! Price change audit log.
+//!
+//! Every price change `apply` makes is appended to `price_audit.jsonl`, one
+//! JSON object per line. Timestamps are RFC 3339 in
+//! UTC, e.g. `2026-10-19T14:05:00Z`, so they sort as strings. Entries are
+//! never rewritten or removed.
+//!
+//! `audit [plu or item name] [--field <path>] [--unit <id>] [--since <date>]
+//! [--last]` lists matching changes, newest first.
+
+use crate::simulator::cents;
+use adjunct::Menu;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io::Write;
+
+pub const AUDIT_FILE: &str = "price_audit.jsonl";
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeSource {
+    /// `getPriceChanges`, via the Go patcher's `-propose` mode.
+    Database,
+    /// A `.csv` price file given to `propose --prices`.
+    Csv,
+    BulkAdjust,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AuditEntry {
+    pub plu: String,
+    /// Which price on the item, as in a change set, e.g. `price`.
+    pub field: String,
+    pub old: i64,
+    pub new: i64,
+    pub source: ChangeSource,
+    /// The unit the change was made for; none for the base menu.
+    #[serde(default)]
+    pub unit: Option<String>,
+    /// Who approved the change set; none for database changes.
+    #[serde(default)]
+    pub approved_by: Option<String>,
+    pub timestamp: String,
+}
+
+impl fmt::Display for AuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}  {:<8} {:<10} {:<32} {:>8} -> {:>8}  ({:?})",
+            self.timestamp,
+            self.unit.as_deref().unwrap_or("base"),
+            self.plu,
+            self.field,
+            cents(self.old),
+            cents(self.new),
+            self.source
+        )?;
+        match &self.approved_by {
+            Some(approver) => write!(f, " approved by {}", approver),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Every entry in the log at `path`; none if there is no log yet.
+pub fn load(path: &str) -> Vec<AuditEntry> {
+    let log = match fs::read_to_string(path) {
+        Ok(log) => log,
+        Err(_) => return vec![],
+    };
+    log.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .unwrap_or_else(|e| panic!("Couldnt parse {} line {}: {}", path, i + 1, e))
+        })
+        .collect()
+}
+
+/// Adds `entries` to the end of the log at `path`.
+pub fn append(path: &str, entries: &[AuditEntry]) {
+    let mut log = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .unwrap_or_else(|e| panic!("Couldnt open {}: {}", path, e));
+    for entry in entries {
+        let line = serde_json::to_string(entry).expect("Couldnt serialize audit entry");
+        writeln!(log, "{}", line).unwrap_or_else(|e| panic!("Couldnt write {}: {}", path, e));
+    }
+}
+
+/// The PLU `name` stands for: itself if it is a PLU on `menu` or names no
+/// item, e.g. one since taken off the menu, or else the PLU of the item it
+/// names.
+fn find_plu(menu: &Menu, name: &str) -> Result<String, String> {
+    if menu.items.iter().any(|item| item.plu == name) {
+        return Ok(name.to_string());
+    }
+    let mut plus: Vec<&str> = menu
+        .items
+        .iter()
+        .filter(|item| {
+            item.label
+                .as_deref()
+                .into_iter()
+                .chain([item.short_name.as_str(), item.long_name.as_str()])
+                .any(|item_name| item_name.eq_ignore_ascii_case(name))
+        })
+        .map(|item| item.plu.as_str())
+        .collect();
+    plus.sort_unstable();
+    plus.dedup();
+    match plus.as_slice() {
+        [] => Ok(name.to_string()),
+        [plu] => Ok(plu.to_string()),
+        _ => Err(format!(
+            "{} could be PLU {}; give the PLU",
+            name,
+            plus.join(", ")
+        )),
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AuditQuery {
+    pub plu: Option<String>,
+    pub field: Option<String>,
+    pub unit: Option<String>,
+    /// Only entries at or after this timestamp or date, e.g. `2026-10-01`.
+    pub since: Option<String>,
+    /// Only the most recent match.
+    pub last: bool,
+}
+
+impl AuditQuery {
+    /// Parses the `audit` arguments. An argument that is not a PLU on `menu`
+    /// is looked up as an item's label, short or long name, e.g. `#1`.
+    pub fn parse(menu: &Menu, args: &[String]) -> Result<Self, String> {
+        let mut query = AuditQuery::default();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| {
+                args.next()
+                    .cloned()
+                    .ok_or_else(|| format!("{} needs a value", flag))
+            };
+            match arg.as_str() {
+                "--field" => query.field = Some(value("--field")?),
+                "--unit" => query.unit = Some(value("--unit")?),
+                "--since" => query.since = Some(value("--since")?),
+                "--last" => query.last = true,
+                _ if query.plu.is_none() => query.plu = Some(find_plu(menu, arg)?),
+                _ => return Err(format!("unexpected argument {}", arg)),
+            }
+        }
+        Ok(query)
+    }
+
+    fn matches(&self, entry: &AuditEntry) -> bool {
+        self.plu.as_ref().is_none_or(|plu| &entry.plu == plu)
+            && self
+                .field
+                .as_ref()
+                .is_none_or(|field| &entry.field == field)
+            && self
+                .unit
+                .as_ref()
+                .is_none_or(|unit| entry.unit.as_ref() == Some(unit))
+            && self
+                .since
+                .as_ref()
+                .is_none_or(|since| entry.timestamp.as_str() >= since.as_str())
+    }
+
+    /// Matching entries, newest first.
+    pub fn run<'a>(&self, entries: &'a [AuditEntry]) -> Vec<&'a AuditEntry> {
+        let mut matching: Vec<&AuditEntry> =
+            entries.iter().filter(|entry| self.matches(entry)).collect();
+        matching.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        if self.last {
+            matching.truncate(1);
+        }
+        matching
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_finds_items_by_plu_label_and_name() {
+        let menu = menu::build().menu;
+        let plu = |name: &str| AuditQuery::parse(&menu, &args(&[name])).map(|query| query.plu);
+        let combo = Ok(Some("1000".to_string()));
+        assert_eq!(plu("1000"), combo);
+        assert_eq!(plu("#1"), combo);
+        assert_eq!(plu("2 crispy taco combo"), combo);
+        assert_eq!(plu("99999"), Ok(Some("99999".to_string())));
+
+        let query = AuditQuery::parse(&menu, &args(&["#1", "--field", "price", "--last"])).unwrap();
+        assert_eq!(query.field.as_deref(), Some("price"));
+        assert!(query.last);
+        assert!(plu("Shell").unwrap_err().starts_with("Shell could be PLU "));
+        assert!(AuditQuery::parse(&menu, &args(&["#1", "#2"])).is_err());
+    }
+}
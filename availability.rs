@@ -0,0 +1,165 @@
+//! Out-of-stock (86) overlay for a single store.
+//!
+//! The overlay only lists PLUs the store has run out of. Applying it to the
+//! built menu works out everything else that can no longer be sold: slot
+//! options that select an 86'd item, and items whose required slot is left
+//! with no choice, repeated until nothing else changes so combos built from
+//! other combos' entrees follow too.
+
+use crate::overlay::UnitOverlay;
+use crate::selection;
+use adjunct::{ItemDefinition, Menu, ID};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs;
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub struct UnavailableItem {
+    pub plu: String,
+    pub name: String,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub struct Availability {
+    pub unit: String,
+    pub unavailable_items: Vec<UnavailableItem>,
+    /// Slot path to the PLUs that can no longer be chosen in it.
+    pub unavailable_options: BTreeMap<String, Vec<String>>,
+    /// Overlay PLUs that are not on the menu.
+    #[serde(skip)]
+    pub unknown_plus: Vec<String>,
+}
+
+impl Availability {
+    pub fn apply(menu: &Menu, overlay: &UnitOverlay) -> Self {
+        let mut unavailable: BTreeMap<ID, String> = BTreeMap::new();
+        let mut unknown_plus = Vec::new();
+        for plu in &overlay.out_of_stock {
+            let mut found = false;
+            for item in menu.items.iter().filter(|item| &item.plu == plu) {
+                unavailable.insert(item.id, "out of stock".to_string());
+                found = true;
+            }
+            if !found {
+                unknown_plus.push(plu.clone());
+            }
+        }
+
+        loop {
+            let newly: Vec<(ID, String)> = menu
+                .items
+                .iter()
+                .filter(|item| !unavailable.contains_key(&item.id))
+                .filter_map(|item| {
+                    empty_required_slot(menu, item, &unavailable)
+                        .map(|slot| (item.id, format!("no choice left for {}", slot)))
+                })
+                .collect();
+            if newly.is_empty() {
+                break;
+            }
+            unavailable.extend(newly);
+        }
+
+        let unavailable_items = menu
+            .items
+            .iter()
+            .filter_map(|item| {
+                unavailable.get(&item.id).map(|reason| UnavailableItem {
+                    plu: item.plu.clone(),
+                    name: item.long_name.clone(),
+                    reason: reason.clone(),
+                })
+            })
+            .collect();
+
+        let mut unavailable_options = BTreeMap::new();
+        for (path, slot) in selection::all_slots(menu) {
+            let plus: Vec<String> = menu
+                .items
+                .iter()
+                .filter(|item| unavailable.contains_key(&item.id))
+                .filter(|item| selection::matches(&slot.selection, item))
+                .map(|item| item.plu.clone())
+                .collect();
+            if !plus.is_empty() {
+                unavailable_options.insert(path, plus);
+            }
+        }
+
+        Availability {
+            unit: overlay.unit.clone(),
+            unavailable_items,
+            unavailable_options,
+            unknown_plus,
+        }
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.unknown_plus
+            .iter()
+            .map(|plu| format!("{}: out-of-stock PLU {} is not on the menu", self.unit, plu))
+            .collect()
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("availability.{}.json", self.unit)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Couldnt serialize availability")
+    }
+
+    pub fn write(&self) {
+        fs::write(self.file_name(), self.to_json()).expect("Couldnt write availability file");
+    }
+}
+
+/// The first slot on `item` that needs a choice but has none left.
+fn empty_required_slot<'a>(
+    menu: &Menu,
+    item: &'a ItemDefinition,
+    unavailable: &BTreeMap<ID, String>,
+) -> Option<&'a str> {
+    item.slots
+        .iter()
+        .filter(|slot| slot.minimum_quantity as i64 > 0)
+        .find(|slot| {
+            let choices: BTreeSet<ID> = menu
+                .items
+                .iter()
+                .filter(|choice| selection::matches(&slot.selection, choice))
+                .map(|choice| choice.id)
+                .collect();
+            !choices.is_empty() && choices.iter().all(|id| unavailable.contains_key(id))
+        })
+        .map(|slot| slot.name.as_str())
+}
+
+impl fmt::Display for Availability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Unavailable items at {} ({}):",
+            self.unit,
+            self.unavailable_items.len()
+        )?;
+        for item in &self.unavailable_items {
+            writeln!(f, "  {} {}: {}", item.plu, item.name, item.reason)?;
+        }
+        writeln!(
+            f,
+            "Slots with unavailable options ({}):",
+            self.unavailable_options.len()
+        )?;
+        for (path, plus) in &self.unavailable_options {
+            writeln!(f, "  {}: {}", path, plus.join(", "))?;
+        }
+        for warning in self.warnings() {
+            writeln!(f, "warning: {}", warning)?;
+        }
+        Ok(())
+    }
+}
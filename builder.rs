@@ -0,0 +1,344 @@
+//! Typed builder for the menu.
+//!
+//! The old `item!`/`slot!` macros accepted any field with `.into()`, so a
+//! misspelled tag or a slot pointing at an item that was never put on the menu
+//! only showed up when a store complained. Here tags are named constants, and
+//! slots and price overrides only accept [`ItemHandle`]s, which can only be
+//! obtained by adding an item to a menu.
+
+// Prices and quantities go through `.into()` like the macros did, so the
+// builder keeps compiling whatever integer types adjunct settles on.
+#![allow(clippy::useless_conversion)]
+
+use crate::hash;
+use adjunct::{
+    Category, ItemDefinition, ItemSelection, Menu, Modification, PriceOverride, SlotDefinition,
+    SlotType, Variation, ID,
+};
+
+/// A menu tag. Only the constants in [`tags`] can be constructed outside this module.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tag(&'static str);
+
+impl Tag {
+    pub fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+/// Every tag the menu assigns or selects on.
+pub mod tags {
+    use super::Tag;
+
+    pub const BREAKFAST: Tag = Tag("breakfast");
+    pub const BREAKFAST_COMBO: Tag = Tag("breakfast_combo");
+    pub const BREAKFAST_PROTEINS: Tag = Tag("breakfast_proteins");
+    pub const BUNDLE: Tag = Tag("bundle");
+    pub const BURRITO: Tag = Tag("burrito");
+    pub const COMBOS: Tag = Tag("combos");
+    pub const COMBOSIDES: Tag = Tag("combosides");
+    pub const DESSERT: Tag = Tag("dessert");
+    pub const DINNER: Tag = Tag("dinner");
+    pub const DRESSING: Tag = Tag("dressing");
+    pub const DRINKS: Tag = Tag("drinks");
+    pub const GRILLED: Tag = Tag("grilled");
+    pub const GRILLED_BURRITO_PROTEINS: Tag = Tag("grilled_burrito_proteins");
+    pub const INCLUDEDDRINK: Tag = Tag("includeddrink");
+    pub const KIDS_MEAL: Tag = Tag("kids_meal");
+    pub const LTO: Tag = Tag("LTO");
+    pub const LUNCH: Tag = Tag("lunch");
+    pub const PREMIUMDRINK: Tag = Tag("premiumdrink");
+    pub const PROTEINS: Tag = Tag("proteins");
+    pub const QUICK: Tag = Tag("quick");
+    pub const SAUCE_ADDITION: Tag = Tag("sauce_addition");
+    pub const SAUCES: Tag = Tag("sauces");
+    pub const SECONDARYSIDE: Tag = Tag("secondaryside");
+    pub const SHELL: Tag = Tag("shell");
+    pub const SIDE_OF: Tag = Tag("side_of");
+    pub const SIDES: Tag = Tag("sides");
+    pub const SOFTDRINK: Tag = Tag("softdrink");
+    pub const SPECIALTIES: Tag = Tag("specialties");
+    pub const STREET_TACO_PROTEINS: Tag = Tag("street_taco_proteins");
+    pub const STREET_TACOS: Tag = Tag("street_tacos");
+    pub const TACOS: Tag = Tag("tacos");
+    pub const VALUE: Tag = Tag("value");
+}
+
+/// Proof that an item has been added to a menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItemHandle {
+    id: ID,
+}
+
+impl ItemHandle {
+    pub fn id(self) -> ID {
+        self.id
+    }
+}
+
+/// What a slot, its hidden set, a discount or a pricing rule selects on.
+#[derive(Clone, Copy, Debug)]
+pub enum Selection<'a> {
+    Item(ItemHandle),
+    AnyItem(&'a [ItemHandle]),
+    Tag(Tag),
+    AnyTag(&'a [Tag]),
+}
+
+impl From<Selection<'_>> for ItemSelection {
+    fn from(selection: Selection) -> Self {
+        match selection {
+            Selection::Item(item) => ItemSelection::Id(item.id),
+            Selection::AnyItem(items) => {
+                ItemSelection::AnyId(items.iter().map(|item| item.id).collect())
+            }
+            Selection::Tag(tag) => ItemSelection::Tag(tag.as_str().to_owned()),
+            Selection::AnyTag(tags) => {
+                ItemSelection::AnyTag(tags.iter().map(|tag| tag.as_str().into()).collect())
+            }
+        }
+    }
+}
+
+pub struct VariationBuilder {
+    variation: Variation,
+}
+
+impl VariationBuilder {
+    pub fn new(name: &str, identifier: &str) -> Self {
+        VariationBuilder {
+            variation: Variation {
+                name: name.into(),
+                identifier: identifier.into(),
+                ..Variation::default()
+            },
+        }
+    }
+
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.variation.prefix = Some(prefix.to_string());
+        self
+    }
+
+    pub fn price(mut self, price: i32) -> Self {
+        self.variation.price = Some(price.into());
+        self
+    }
+
+    /// The variation, with an id derived from its name, prefix and identifier.
+    pub fn build(mut self) -> Variation {
+        let variation = &self.variation;
+        self.variation.id = hash::uuid(&format!(
+            "{}/{:?}/{}",
+            variation.name, variation.prefix, variation.identifier
+        ));
+        self.variation
+    }
+}
+
+pub struct ItemBuilder {
+    item: ItemDefinition,
+}
+
+impl ItemBuilder {
+    pub fn new(id: &str, long_name: &str, short_name: &str) -> Self {
+        let id = ID::parse_str(id)
+            .unwrap_or_else(|e| panic!("{}: invalid item id {}: {}", long_name, id, e));
+        ItemBuilder {
+            item: ItemDefinition {
+                id,
+                long_name: long_name.into(),
+                short_name: short_name.into(),
+                ..ItemDefinition::default()
+            },
+        }
+    }
+
+    pub fn price(mut self, price: i32) -> Self {
+        self.item.price = price.into();
+        self
+    }
+
+    pub fn plu(mut self, plu: &str) -> Self {
+        self.item.plu = plu.into();
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.item.label = Some(label.to_string());
+        self
+    }
+
+    pub fn tags(mut self, tags: &[Tag]) -> Self {
+        self.item
+            .tags
+            .extend(tags.iter().map(|tag| tag.as_str().into()));
+        self
+    }
+
+    pub fn modifiers(mut self, modifiers: &[Modification]) -> Self {
+        self.item.modifiers.extend(modifiers.iter().cloned());
+        self
+    }
+
+    pub fn upcharge(mut self, modification: Modification, price: i32) -> Self {
+        self.item
+            .modifier_upcharge
+            .insert(modification, price.into());
+        self
+    }
+
+    pub fn variations(mut self, variations: &[Variation]) -> Self {
+        self.item.variations = variations.to_vec();
+        self
+    }
+
+    pub fn default_variation(mut self, variation: &Variation) -> Self {
+        self.item.default_variation = variation.id;
+        self
+    }
+
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.item.item_priority = priority.into();
+        self
+    }
+
+    pub fn slot(mut self, slot: SlotBuilder) -> Self {
+        self.item.slots.push(slot.slot);
+        self
+    }
+
+    /// Puts the item on `menu`.
+    pub fn add_to(self, menu: &mut Menu) -> ItemHandle {
+        let handle = ItemHandle { id: self.item.id };
+        menu.items.push(self.item);
+        handle
+    }
+}
+
+#[derive(Clone)]
+pub struct SlotBuilder {
+    slot: SlotDefinition,
+}
+
+impl SlotBuilder {
+    pub fn new(name: &str, slot_type: SlotType, selection: Selection) -> Self {
+        SlotBuilder {
+            slot: SlotDefinition {
+                name: name.into(),
+                slot_type,
+                selection: selection.into(),
+                ..SlotDefinition::default()
+            },
+        }
+    }
+
+    pub fn hidden(mut self, selection: Selection) -> Self {
+        self.slot.hidden = selection.into();
+        self
+    }
+
+    pub fn defaults(mut self, items: &[ItemHandle]) -> Self {
+        self.slot.default_item_ids = items.iter().map(|item| item.id).collect();
+        self
+    }
+
+    pub fn minimum_quantity(mut self, quantity: i32) -> Self {
+        self.slot.minimum_quantity = quantity.into();
+        self
+    }
+
+    pub fn maximum_quantity(mut self, quantity: i32) -> Self {
+        self.slot.maximum_quantity = quantity.into();
+        self
+    }
+
+    pub fn default_quantity(mut self, quantity: i32) -> Self {
+        self.slot.default_quantity = quantity.into();
+        self
+    }
+
+    pub fn free_quantity(mut self, quantity: i32) -> Self {
+        self.slot.free_quantity = quantity.into();
+        self
+    }
+
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.slot.collapsed = collapsed;
+        self
+    }
+
+    pub fn price_override(mut self, price_override: PriceOverrideBuilder) -> Self {
+        self.slot
+            .price_overrides
+            .push(price_override.price_override);
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct PriceOverrideBuilder {
+    price_override: PriceOverride,
+}
+
+impl PriceOverrideBuilder {
+    pub fn new() -> Self {
+        PriceOverrideBuilder::default()
+    }
+
+    pub fn items(mut self, items: &[ItemHandle]) -> Self {
+        self.price_override
+            .item_ids
+            .extend(items.iter().map(|item| item.id));
+        self
+    }
+
+    pub fn tags(mut self, tags: &[Tag]) -> Self {
+        self.price_override
+            .tags
+            .extend(tags.iter().map(|tag| tag.as_str().into()));
+        self
+    }
+
+    pub fn price(mut self, price: i32) -> Self {
+        self.price_override.price = price.into();
+        self
+    }
+
+    pub fn variation(mut self, name: &str) -> Self {
+        self.price_override.variation = Some(name.into());
+        self
+    }
+}
+
+pub struct CategoryBuilder {
+    category: Category,
+}
+
+impl CategoryBuilder {
+    /// A category shown on the POS menu.
+    pub fn new(name: &str, tags: &[Tag]) -> Self {
+        CategoryBuilder {
+            category: Category {
+                name: name.into(),
+                pos_menu: true,
+                tags: tags.iter().map(|tag| tag.as_str().into()).collect(),
+                ..Category::default()
+            },
+        }
+    }
+
+    pub fn image(mut self, image: &str) -> Self {
+        self.category.image = Some(image.to_string());
+        self
+    }
+
+    pub fn multi_select_modal(mut self) -> Self {
+        self.category.multi_select_modal = true;
+        self
+    }
+
+    pub fn build(self) -> Category {
+        self.category
+    }
+}
@@ -0,0 +1,391 @@
+//! What changed between two menus, for store managers.
+//!
+//! `MenuDiff` compares two menu documents item by item (matched by id),
+//! discount by discount (matched by identifier) and pricing rule set by rule
+//! set (matched by name). `changelog <old> [new] [--markdown]` renders it
+//! grouped by category as plain text or Markdown. `old` and `new` are menu
+//! files or published versions such as `v3`; `new` defaults to the current
+//! build.
+
+use crate::document::MenuDocument;
+use crate::price_changes::{item_prices, PriceChange};
+use crate::publish;
+use crate::selection;
+use crate::simulator::cents;
+use adjunct::{ItemDefinition, Menu, ID};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const OTHER: &str = "Other";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemEntry {
+    pub id: ID,
+    pub plu: String,
+    pub name: String,
+    pub price: i64,
+}
+
+impl ItemEntry {
+    fn new(item: &ItemDefinition) -> Self {
+        ItemEntry {
+            id: item.id,
+            plu: item.plu.clone(),
+            name: item.long_name.clone(),
+            price: item.price as i64,
+        }
+    }
+}
+
+/// Names added, removed and changed between two lists.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamedChanges {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl NamedChanges {
+    /// Compares `old` and `new` by key, using their JSON to tell if they
+    /// changed.
+    fn compare<T: Serialize>(old: &[T], new: &[T], key: impl Fn(&T) -> String) -> Self {
+        let json = |value: &T| serde_json::to_value(value).expect("Couldnt serialize menu entry");
+        let old: BTreeMap<String, &T> = old.iter().map(|value| (key(value), value)).collect();
+        let new: BTreeMap<String, &T> = new.iter().map(|value| (key(value), value)).collect();
+        let mut changes = NamedChanges::default();
+        for (name, value) in &new {
+            match old.get(name) {
+                None => changes.added.push(name.clone()),
+                Some(previous) if json(previous) != json(value) => {
+                    changes.changed.push(name.clone())
+                }
+                Some(_) => {}
+            }
+        }
+        for name in old.keys() {
+            if !new.contains_key(name) {
+                changes.removed.push(name.clone());
+            }
+        }
+        changes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MenuDiff {
+    pub added: Vec<ItemEntry>,
+    pub removed: Vec<ItemEntry>,
+    pub price_changes: Vec<PriceChange>,
+    pub discounts: NamedChanges,
+    pub rule_sets: NamedChanges,
+    /// The category each item above is listed under.
+    pub categories: BTreeMap<ID, String>,
+}
+
+/// The first category that shows each item, in menu order.
+fn item_categories(menu: &Menu) -> BTreeMap<ID, String> {
+    let mut categories = BTreeMap::new();
+    for category in &menu.categories {
+        for item in selection::category_items(menu, category) {
+            categories
+                .entry(item.id)
+                .or_insert_with(|| category.name.clone());
+        }
+    }
+    categories
+}
+
+impl MenuDiff {
+    pub fn compare(old: &Menu, new: &Menu) -> Self {
+        let old_categories = item_categories(old);
+        let new_categories = item_categories(new);
+        let mut diff = MenuDiff::default();
+        let category = |id: ID, categories: &BTreeMap<ID, String>| {
+            categories
+                .get(&id)
+                .map_or(OTHER.to_string(), |name| name.clone())
+        };
+
+        for item in &new.items {
+            match old.items.iter().find(|old_item| old_item.id == item.id) {
+                None => {
+                    diff.categories
+                        .insert(item.id, category(item.id, &new_categories));
+                    diff.added.push(ItemEntry::new(item));
+                }
+                Some(old_item) => {
+                    let old_prices: BTreeMap<String, i64> =
+                        item_prices(old_item).into_iter().collect();
+                    for (field, price) in item_prices(item) {
+                        match old_prices.get(&field) {
+                            Some(old_price) if *old_price != price => {
+                                diff.categories
+                                    .insert(item.id, category(item.id, &new_categories));
+                                diff.price_changes.push(PriceChange {
+                                    id: item.id,
+                                    plu: item.plu.clone(),
+                                    item: item.long_name.clone(),
+                                    field,
+                                    old: *old_price,
+                                    new: price,
+                                });
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        for item in &old.items {
+            if !new.items.iter().any(|new_item| new_item.id == item.id) {
+                diff.categories
+                    .insert(item.id, category(item.id, &old_categories));
+                diff.removed.push(ItemEntry::new(item));
+            }
+        }
+
+        diff.discounts = NamedChanges::compare(&old.discounts, &new.discounts, |discount| {
+            if discount.identifier.is_empty() {
+                discount.name.clone()
+            } else {
+                format!("{} ({})", discount.name, discount.identifier)
+            }
+        });
+        diff.rule_sets =
+            NamedChanges::compare(&old.dynamic_pricing, &new.dynamic_pricing, |rule_set| {
+                rule_set.name.clone()
+            });
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.price_changes.is_empty()
+            && self.discounts.is_empty()
+            && self.rule_sets.is_empty()
+    }
+
+    fn category(&self, id: ID) -> &str {
+        self.categories.get(&id).map_or(OTHER, |name| name.as_str())
+    }
+
+    /// Lines to show, by heading. Categories come first, in `order`, then
+    /// discounts and dynamic pricing.
+    fn sections(&self, order: &[String]) -> Vec<(String, Vec<String>)> {
+        let mut by_category: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for item in &self.added {
+            by_category
+                .entry(self.category(item.id))
+                .or_default()
+                .push(format!(
+                    "New: {} ({}) at {}",
+                    item.name,
+                    item.plu,
+                    cents(item.price)
+                ));
+        }
+        for item in &self.removed {
+            by_category
+                .entry(self.category(item.id))
+                .or_default()
+                .push(format!("Retired: {} ({})", item.name, item.plu));
+        }
+        for change in &self.price_changes {
+            by_category
+                .entry(self.category(change.id))
+                .or_default()
+                .push(format!(
+                    "Price: {}{} ({}) {} -> {}",
+                    change.item,
+                    field_label(&change.field),
+                    change.plu,
+                    cents(change.old),
+                    cents(change.new)
+                ));
+        }
+
+        let mut sections = Vec::new();
+        for name in order.iter().map(String::as_str).chain([OTHER]) {
+            if let Some(lines) = by_category.remove(name) {
+                sections.push((name.to_string(), lines));
+            }
+        }
+        // Categories only the old menu had.
+        for (name, lines) in by_category {
+            sections.push((name.to_string(), lines));
+        }
+
+        let named = |changes: &NamedChanges, added: &str, removed: &str| {
+            let mut lines = Vec::new();
+            lines.extend(
+                changes
+                    .added
+                    .iter()
+                    .map(|name| format!("{}: {}", added, name)),
+            );
+            lines.extend(
+                changes
+                    .removed
+                    .iter()
+                    .map(|name| format!("{}: {}", removed, name)),
+            );
+            lines.extend(
+                changes
+                    .changed
+                    .iter()
+                    .map(|name| format!("Changed: {}", name)),
+            );
+            lines
+        };
+        let discounts = named(&self.discounts, "New", "Expired");
+        if !discounts.is_empty() {
+            sections.push(("Discounts".to_string(), discounts));
+        }
+        let rule_sets = named(&self.rule_sets, "New", "Removed");
+        if !rule_sets.is_empty() {
+            sections.push(("Dynamic pricing".to_string(), rule_sets));
+        }
+        sections
+    }
+}
+
+/// `variations.Large.price` as ` Large`, `modifier_upcharge.Extra` as
+/// ` Extra upcharge`; the item's own price needs no label.
+fn field_label(field: &str) -> String {
+    let parts: Vec<&str> = field.split('.').collect();
+    match parts.as_slice() {
+        ["variations", name, "price"] => format!(" {}", name),
+        ["modifier_upcharge", modifier] => format!(" {} upcharge", modifier),
+        ["slots", slot, "price_overrides", i, "price"] => {
+            format!(
+                " {} override {}",
+                slot,
+                i.parse::<usize>().map_or(0, |i| i + 1)
+            )
+        }
+        _ => String::new(),
+    }
+}
+
+/// A changelog between two labelled menus.
+pub struct Changelog {
+    pub from: String,
+    pub to: String,
+    pub diff: MenuDiff,
+    /// Category order to list changes in, from the newer menu.
+    pub categories: Vec<String>,
+}
+
+impl Changelog {
+    pub fn new(from: &str, old: &Menu, to: &str, new: &Menu) -> Self {
+        Changelog {
+            from: from.to_string(),
+            to: to.to_string(),
+            diff: MenuDiff::compare(old, new),
+            categories: new
+                .categories
+                .iter()
+                .map(|category| category.name.clone())
+                .collect(),
+        }
+    }
+
+    pub fn markdown(&self) -> String {
+        let mut out = format!("# Menu changes: {} to {}\n", self.from, self.to);
+        if self.diff.is_empty() {
+            out.push_str("\nNo changes.\n");
+        }
+        for (heading, lines) in self.diff.sections(&self.categories) {
+            out.push_str(&format!("\n## {}\n\n", heading));
+            for line in lines {
+                out.push_str(&format!("- {}\n", line));
+            }
+        }
+        out
+    }
+
+    pub fn text(&self) -> String {
+        let title = format!("Menu changes: {} to {}", self.from, self.to);
+        let mut out = format!("{}\n{}\n", title, "=".repeat(title.len()));
+        if self.diff.is_empty() {
+            out.push_str("\nNo changes.\n");
+        }
+        for (heading, lines) in self.diff.sections(&self.categories) {
+            out.push_str(&format!("\n{}\n{}\n", heading, "-".repeat(heading.len())));
+            for line in lines {
+                out.push_str(&format!("  {}\n", line));
+            }
+        }
+        out
+    }
+}
+
+/// A menu file, or a published version such as `v3` from the archive.
+pub fn load_menu(name: &str) -> MenuDocument {
+    match name
+        .strip_prefix('v')
+        .and_then(|number| number.parse::<u32>().ok())
+    {
+        Some(version) => {
+            let root = Path::new(publish::PUBLISH_DIR);
+            MenuDocument::load(&publish::archive_path(root, version, "menu.json").to_string_lossy())
+        }
+        None => MenuDocument::load(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu;
+
+    fn position(menu: &Menu, plu: &str) -> usize {
+        menu.items
+            .iter()
+            .position(|item| item.plu == plu)
+            .unwrap_or_else(|| panic!("Couldnt find PLU {}", plu))
+    }
+
+    #[test]
+    fn compare_finds_item_price_and_discount_changes() {
+        let mut old = menu::build().menu;
+        let mut new = old.clone();
+        let added = old.items.remove(position(&old, "3300"));
+        let removed = new.items.remove(position(&new, "1830"));
+        let repriced = position(&new, "480");
+        let price = new.items[repriced].price as i64;
+        new.items[repriced].price = (price + 10) as _;
+        new.discounts[0].max_amount = Some(12345 as _);
+
+        let diff = MenuDiff::compare(&old, &new);
+        assert_eq!(diff.added, vec![ItemEntry::new(&added)]);
+        assert_eq!(diff.removed, vec![ItemEntry::new(&removed)]);
+        assert_eq!(diff.price_changes.len(), 1);
+        let change = &diff.price_changes[0];
+        assert_eq!(
+            (
+                change.plu.as_str(),
+                change.field.as_str(),
+                change.old,
+                change.new
+            ),
+            ("480", "price", price, price + 10)
+        );
+        let discount = &new.discounts[0];
+        assert_eq!(
+            diff.discounts,
+            NamedChanges {
+                added: vec![],
+                removed: vec![],
+                changed: vec![format!("{} ({})", discount.name, discount.identifier)],
+            }
+        );
+        assert!(diff.rule_sets.is_empty());
+    }
+}
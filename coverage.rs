@@ -0,0 +1,88 @@
+//! Which sellable items the POS categories actually show.
+//!
+//! An item is sellable if a category selects it or if no slot can hold it.
+//! Ingredients and other items that only exist as slot contents are left out.
+
+use crate::selection;
+use adjunct::Menu;
+use std::fmt;
+
+pub struct CategoryCoverage {
+    /// Sellable items no category shows.
+    pub orphans: Vec<String>,
+    /// Items shown by more than one category, with those categories.
+    pub shared: Vec<(String, Vec<String>)>,
+    /// Categories that show no item at all.
+    pub empty_categories: Vec<String>,
+}
+
+impl CategoryCoverage {
+    pub fn from_menu(menu: &Menu) -> Self {
+        let slot_contents = selection::slot_contents(menu);
+        let mut orphans = Vec::new();
+        let mut shared = Vec::new();
+
+        for item in &menu.items {
+            let categories: Vec<String> = menu
+                .categories
+                .iter()
+                .filter(|category| category.tags.iter().any(|tag| item.tags.contains(tag)))
+                .map(|category| category.name.clone())
+                .collect();
+            match categories.len() {
+                0 if !slot_contents.contains(&item.id) => {
+                    orphans.push(format!("{} ({})", item.long_name, item.plu))
+                }
+                0 | 1 => {}
+                _ => shared.push((item.long_name.clone(), categories)),
+            }
+        }
+
+        let empty_categories = menu
+            .categories
+            .iter()
+            .filter(|category| {
+                !menu
+                    .items
+                    .iter()
+                    .any(|item| category.tags.iter().any(|tag| item.tags.contains(tag)))
+            })
+            .map(|category| category.name.clone())
+            .collect();
+
+        CategoryCoverage {
+            orphans,
+            shared,
+            empty_categories,
+        }
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for item in &self.orphans {
+            warnings.push(format!("{} is not in any category", item));
+        }
+        for category in &self.empty_categories {
+            warnings.push(format!("category \"{}\" is empty", category));
+        }
+        warnings
+    }
+}
+
+impl fmt::Display for CategoryCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Items in no category ({}):", self.orphans.len())?;
+        for item in &self.orphans {
+            writeln!(f, "  {}", item)?;
+        }
+        writeln!(f, "Items in several categories ({}):", self.shared.len())?;
+        for (item, categories) in &self.shared {
+            writeln!(f, "  {}: {}", item, categories.join(", "))?;
+        }
+        writeln!(f, "Empty categories ({}):", self.empty_categories.len())?;
+        for category in &self.empty_categories {
+            writeln!(f, "  {}", category)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! Dayparts: when during the week an item or category can be sold.
+
+use adjunct::ID;
+use chrono::{Datelike, NaiveDateTime, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Daypart {
+    pub name: String,
+    pub days: Vec<Weekday>,
+    /// Seconds since midnight, like `OrderTimeConstraint`.
+    pub start_time: u32,
+    /// Seconds since midnight. A stop time before the start time runs past
+    /// midnight into the next day, e.g. late night from 22:00 to 02:00.
+    pub stop_time: u32,
+}
+
+impl Daypart {
+    pub fn contains(&self, at: NaiveDateTime) -> bool {
+        window_contains(&self.days, self.start_time, self.stop_time, at)
+    }
+}
+
+/// Whether `at` falls in a weekly window starting at `start_time` on one of
+/// `days`. A stop time before the start time runs past midnight, so the
+/// window's early hours belong to the day after.
+pub fn window_contains(
+    days: &[Weekday],
+    start_time: u32,
+    stop_time: u32,
+    at: NaiveDateTime,
+) -> bool {
+    let seconds = at.num_seconds_from_midnight();
+    if start_time <= stop_time {
+        days.contains(&at.weekday()) && seconds >= start_time && seconds < stop_time
+    } else {
+        (days.contains(&at.weekday()) && seconds >= start_time)
+            || (days.contains(&at.weekday().pred()) && seconds < stop_time)
+    }
+}
+
+/// The daypart definitions plus which items and categories are limited to them.
+/// Anything not listed sells all day.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct DaypartSchedule {
+    pub dayparts: Vec<Daypart>,
+    pub items: BTreeMap<ID, Vec<String>>,
+    pub categories: BTreeMap<String, Vec<String>>,
+}
+
+impl DaypartSchedule {
+    fn any_contains(&self, names: &[String], at: NaiveDateTime) -> bool {
+        self.dayparts
+            .iter()
+            .filter(|daypart| names.contains(&daypart.name))
+            .any(|daypart| daypart.contains(at))
+    }
+
+    pub fn item_available(&self, id: ID, at: NaiveDateTime) -> bool {
+        match self.items.get(&id) {
+            Some(names) => self.any_contains(names, at),
+            None => true,
+        }
+    }
+
+    pub fn category_available(&self, name: &str, at: NaiveDateTime) -> bool {
+        match self.categories.get(name) {
+            Some(names) => self.any_contains(names, at),
+            None => true,
+        }
+    }
+
+    /// Daypart names an item is limited to, empty if it sells all day.
+    pub fn item_dayparts(&self, id: ID) -> &[String] {
+        self.items.get(&id).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
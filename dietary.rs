@@ -0,0 +1,179 @@
+//! Allergen and dietary flags.
+//!
+//! Flags are authored per ingredient and rolled up through slot contents:
+//! an item contains every allergen of its default slot items and is
+//! vegetarian only if all of them are. Items with no flags of their own and
+//! nothing in their slots have no data; anything built from them is reported
+//! as incomplete and never as vegetarian.
+
+use crate::selection;
+use crate::simulator::OrderLine;
+use adjunct::{ItemDefinition, Menu, ID};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Allergen {
+    Dairy,
+    Gluten,
+    Shellfish,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DietaryFlags {
+    pub allergens: BTreeSet<Allergen>,
+    pub vegetarian: bool,
+}
+
+impl Default for DietaryFlags {
+    fn default() -> Self {
+        DietaryFlags {
+            allergens: BTreeSet::new(),
+            vegetarian: true,
+        }
+    }
+}
+
+impl DietaryFlags {
+    pub fn new(allergens: &[Allergen], vegetarian: bool) -> Self {
+        DietaryFlags {
+            allergens: allergens.iter().copied().collect(),
+            vegetarian,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ItemDietary {
+    #[serde(flatten)]
+    pub flags: DietaryFlags,
+    /// Ingredients without allergen data; the flags above ignore them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unknown: Vec<String>,
+}
+
+impl ItemDietary {
+    fn add(&mut self, other: ItemDietary) {
+        self.flags.allergens.extend(other.flags.allergens);
+        self.flags.vegetarian &= other.flags.vegetarian;
+        for name in other.unknown {
+            if !self.unknown.contains(&name) {
+                self.unknown.push(name);
+            }
+        }
+    }
+}
+
+impl fmt::Display for ItemDietary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let allergens: Vec<String> = self
+            .flags
+            .allergens
+            .iter()
+            .map(|allergen| format!("{:?}", allergen).to_lowercase())
+            .collect();
+        if allergens.is_empty() {
+            write!(f, "no listed allergens")?;
+        } else {
+            write!(f, "contains {}", allergens.join(", "))?;
+        }
+        if self.flags.vegetarian {
+            write!(f, "; vegetarian")?;
+        }
+        if !self.unknown.is_empty() {
+            write!(f, "; no data for {}", self.unknown.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Authored ingredient flags and the rolled-up flags of every sellable item
+/// and variation, as published in `menu.json`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Dietary {
+    pub ingredients: BTreeMap<ID, DietaryFlags>,
+    pub items: BTreeMap<ID, ItemDietary>,
+    /// Variations change size and price but not contents, so each carries
+    /// its item's flags.
+    pub variations: BTreeMap<ID, ItemDietary>,
+}
+
+impl Dietary {
+    pub fn from_menu(menu: &Menu, ingredients: BTreeMap<ID, DietaryFlags>) -> Self {
+        let mut dietary = Dietary {
+            ingredients,
+            ..Default::default()
+        };
+        for item in selection::sellable(menu) {
+            let flags = dietary.rollup(menu, item, None);
+            for variation in &item.variations {
+                dietary.variations.insert(variation.id, flags.clone());
+            }
+            dietary.items.insert(item.id, flags);
+        }
+        dietary
+    }
+
+    /// Flags for `item` as ordered. Slots the order fills use the customer's
+    /// choices instead of the defaults, so substitutions are reflected.
+    pub fn rollup(
+        &self,
+        menu: &Menu,
+        item: &ItemDefinition,
+        line: Option<&OrderLine>,
+    ) -> ItemDietary {
+        let mut dietary = match self.ingredients.get(&item.id) {
+            Some(flags) => ItemDietary {
+                flags: flags.clone(),
+                unknown: vec![],
+            },
+            None if item.slots.is_empty() => ItemDietary {
+                flags: DietaryFlags::new(&[], false),
+                unknown: vec![item.long_name.clone()],
+            },
+            None => ItemDietary::default(),
+        };
+
+        for slot in &item.slots {
+            let chosen =
+                line.and_then(|line| line.slots.iter().find(|choice| choice.slot == slot.name));
+            match chosen {
+                Some(choice) => {
+                    for choice in &choice.items {
+                        if let Some(content) = menu.items.iter().find(|item| item.plu == choice.plu)
+                        {
+                            dietary.add(self.rollup(menu, content, Some(choice)));
+                        }
+                    }
+                }
+                None => {
+                    for id in &slot.default_item_ids {
+                        if let Some(content) = menu.items.iter().find(|item| item.id == *id) {
+                            dietary.add(self.rollup(menu, content, None));
+                        }
+                    }
+                }
+            }
+        }
+        dietary
+    }
+}
+
+/// Allergen and dietary flags of every sellable item.
+pub struct DietaryReport<'a> {
+    pub menu: &'a Menu,
+    pub dietary: &'a Dietary,
+}
+
+impl fmt::Display for DietaryReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for item in &self.menu.items {
+            if let Some(dietary) = self.dietary.items.get(&item.id) {
+                writeln!(f, "{} ({}): {}", item.long_name, item.plu, dietary)?;
+            }
+        }
+        Ok(())
+    }
+}
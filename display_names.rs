@@ -0,0 +1,199 @@
+//! POS and kitchen display-name checks for `long_name` and `short_name`.
+//!
+//! Each output (register, kitchen printer, kiosk) has its own length and
+//! character limits. The defaults below can be replaced by a
+//! `display_names.json` file holding a list of [`NameRule`]s.
+
+use crate::selection;
+use adjunct::{ItemDefinition, Menu};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+
+pub const RULES_FILE: &str = "display_names.json";
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum NameField {
+    LongName,
+    ShortName,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct NameRule {
+    pub output: String,
+    pub field: NameField,
+    pub max_len: usize,
+    /// Printable ASCII only; kitchen printers drop anything else.
+    #[serde(default)]
+    pub ascii_only: bool,
+    #[serde(default)]
+    pub uppercase_only: bool,
+}
+
+impl NameRule {
+    fn new(output: &str, field: NameField, max_len: usize, ascii_only: bool) -> Self {
+        NameRule {
+            output: output.to_string(),
+            field,
+            max_len,
+            ascii_only,
+            uppercase_only: false,
+        }
+    }
+
+    fn check(&self, name: &str) -> Vec<String> {
+        let mut problems = Vec::new();
+        let len = name.chars().count();
+        if len > self.max_len {
+            problems.push(format!("{} characters, limit {}", len, self.max_len));
+        }
+        if self.ascii_only && !name.chars().all(|c| c == ' ' || c.is_ascii_graphic()) {
+            problems.push("non-ASCII characters".to_string());
+        }
+        if self.uppercase_only && name.chars().any(char::is_lowercase) {
+            problems.push("lowercase characters".to_string());
+        }
+        problems
+    }
+}
+
+pub fn default_rules() -> Vec<NameRule> {
+    vec![
+        NameRule::new("pos", NameField::ShortName, 12, true),
+        NameRule::new("kitchen", NameField::ShortName, 16, true),
+        NameRule::new("kiosk", NameField::LongName, 32, false),
+    ]
+}
+
+/// Rules from `display_names.json`, or the defaults when there is no such file.
+pub fn load_rules() -> Vec<NameRule> {
+    match fs::read_to_string(RULES_FILE) {
+        Ok(json) => serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Couldnt parse {}: {}", RULES_FILE, e)),
+        Err(_) => default_rules(),
+    }
+}
+
+fn field(item: &ItemDefinition, field: NameField) -> &str {
+    match field {
+        NameField::LongName => &item.long_name,
+        NameField::ShortName => &item.short_name,
+    }
+}
+
+pub struct NameReport {
+    pub violations: Vec<String>,
+    pub duplicates: Vec<String>,
+}
+
+impl NameReport {
+    pub fn check(menu: &Menu, rules: &[NameRule]) -> Self {
+        let mut violations = Vec::new();
+        for item in &menu.items {
+            for rule in rules {
+                let name = field(item, rule.field);
+                for problem in rule.check(name) {
+                    violations.push(format!(
+                        "{}: {:?} \"{}\" of {} ({}): {}",
+                        rule.output, rule.field, name, item.long_name, item.plu, problem
+                    ));
+                }
+            }
+        }
+
+        let mut duplicates = Vec::new();
+        for category in &menu.categories {
+            let items = menu
+                .items
+                .iter()
+                .filter(|item| category.tags.iter().any(|tag| item.tags.contains(tag)));
+            duplicates.extend(duplicate_short_names(
+                &format!("category {}", category.name),
+                items,
+            ));
+        }
+        for (path, slot) in selection::all_slots(menu) {
+            let items = menu
+                .items
+                .iter()
+                .filter(|item| selection::matches(&slot.selection, item));
+            duplicates.extend(duplicate_short_names(&format!("slot {}", path), items));
+        }
+        duplicates.dedup();
+
+        NameReport {
+            violations,
+            duplicates,
+        }
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.violations
+            .iter()
+            .chain(&self.duplicates)
+            .cloned()
+            .collect()
+    }
+}
+
+fn duplicate_short_names<'a>(
+    scope: &str,
+    items: impl Iterator<Item = &'a ItemDefinition>,
+) -> Vec<String> {
+    let mut by_name: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for item in items {
+        by_name
+            .entry(item.short_name.as_str())
+            .or_default()
+            .push(item.long_name.as_str());
+    }
+    by_name
+        .into_iter()
+        .filter(|(_, items)| items.len() > 1)
+        .map(|(short_name, items)| {
+            format!(
+                "{}: short name \"{}\" is used by {}",
+                scope,
+                short_name,
+                items.join(", ")
+            )
+        })
+        .collect()
+}
+
+impl fmt::Display for NameReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Name limit violations ({}):", self.violations.len())?;
+        for violation in &self.violations {
+            writeln!(f, "  {}", violation)?;
+        }
+        writeln!(f, "Duplicate short names ({}):", self.duplicates.len())?;
+        for duplicate in &self.duplicates {
+            writeln!(f, "  {}", duplicate)?;
+        }
+        Ok(())
+    }
+}
+
+/// How each item's short name prints on a kitchen chit `width` characters wide.
+///
+/// Lines are `1 <short name>`, cut at the width the same way the printer does,
+/// with a `|` marking the paper edge and a `~` after names that were cut.
+pub fn chit_preview(menu: &Menu, width: usize) -> String {
+    let mut preview = String::new();
+    preview.push_str(&format!("+{}+\n", "-".repeat(width)));
+    for item in &menu.items {
+        let line = format!("1 {}", item.short_name);
+        let printed: String = line.chars().take(width).collect();
+        let cut = if line.chars().count() > width {
+            "~"
+        } else {
+            ""
+        };
+        preview.push_str(&format!("|{:<width$}|{}\n", printed, cut, width = width));
+    }
+    preview.push_str(&format!("+{}+\n", "-".repeat(width)));
+    preview
+}
@@ -0,0 +1,40 @@
+//! What `menu.json` holds: the `adjunct::Menu` plus the data we publish
+//! alongside it that the menu schema has no room for.
+
+use crate::dayparts::DaypartSchedule;
+use crate::dietary::Dietary;
+use crate::publish::MenuVersion;
+use crate::tax::TaxAssignments;
+use adjunct::Menu;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MenuDocument {
+    #[serde(flatten)]
+    pub menu: Menu,
+    #[serde(default)]
+    pub dayparts: DaypartSchedule,
+    #[serde(default)]
+    pub dietary: Dietary,
+    #[serde(default)]
+    pub tax: TaxAssignments,
+    /// Set when the menu is published.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<MenuVersion>,
+}
+
+impl MenuDocument {
+    pub fn load(path: &str) -> Self {
+        let json =
+            fs::read_to_string(path).unwrap_or_else(|e| panic!("Couldnt read {}: {}", path, e));
+        serde_json::from_str(&json).unwrap_or_else(|e| panic!("Couldnt parse {}: {}", path, e))
+    }
+
+    pub fn to_json(&self) -> String {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => json,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
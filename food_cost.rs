@@ -0,0 +1,230 @@
+//! Plated food cost and margin for every sellable item and variation.
+//!
+//! Ingredient costs come from `food_cost.json`, keyed by PLU, and are rolled
+//! up through default slot contents times `default_quantity`. Sell prices are
+//! what the simulator charges for the item with its defaults, so slot price
+//! overrides and combo sizes are taken into account.
+
+use crate::selection;
+use crate::simulator::{cents, default_lines, OrderLine, Simulator};
+use adjunct::{ItemDefinition, Menu};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+
+pub const COST_FILE: &str = "food_cost.json";
+
+fn target_margin() -> f64 {
+    70.0
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct CostTable {
+    /// Cost in cents of one portion, by PLU.
+    #[serde(default)]
+    pub costs: BTreeMap<String, i64>,
+    /// Per-PLU costs by variation name, replacing `costs` for that size.
+    #[serde(default)]
+    pub variation_costs: BTreeMap<String, BTreeMap<String, i64>>,
+    /// Margin percentage below which items are flagged.
+    #[serde(default = "target_margin")]
+    pub target_margin: f64,
+}
+
+impl Default for CostTable {
+    fn default() -> Self {
+        CostTable {
+            costs: BTreeMap::new(),
+            variation_costs: BTreeMap::new(),
+            target_margin: target_margin(),
+        }
+    }
+}
+
+impl CostTable {
+    /// The table in `food_cost.json`, or an empty one when there is no such file.
+    pub fn load() -> Self {
+        match fs::read_to_string(COST_FILE) {
+            Ok(json) => serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("Couldnt parse {}: {}", COST_FILE, e)),
+            Err(_) => CostTable::default(),
+        }
+    }
+
+    fn own_cost(&self, item: &ItemDefinition, variation: Option<&str>) -> Option<i64> {
+        variation
+            .and_then(|variation| {
+                self.variation_costs
+                    .get(&item.plu)
+                    .and_then(|costs| costs.get(variation))
+            })
+            .or_else(|| self.costs.get(&item.plu))
+            .copied()
+    }
+
+    /// Plated cost of one `item` with its slot defaults, plus anything that
+    /// had no cost. Components take the parent's variation when they have it.
+    pub fn plated_cost(
+        &self,
+        menu: &Menu,
+        item: &ItemDefinition,
+        variation: Option<&str>,
+    ) -> (i64, Vec<String>) {
+        let mut cost = 0;
+        let mut missing = Vec::new();
+        match self.own_cost(item, variation) {
+            Some(own) => cost += own,
+            None if item.slots.is_empty() => missing.push(item.long_name.clone()),
+            None => {}
+        }
+
+        for slot in &item.slots {
+            let lines: Vec<OrderLine> = default_lines(menu, slot);
+            if lines.is_empty() && slot.minimum_quantity as i64 > 0 {
+                missing.push(format!("{} (no default)", slot.name));
+            }
+            for line in lines {
+                let content = match menu.items.iter().find(|item| item.plu == line.plu) {
+                    Some(content) => content,
+                    None => continue,
+                };
+                let variation = variation.filter(|name| {
+                    content
+                        .variations
+                        .iter()
+                        .any(|variation| variation.name == *name)
+                });
+                let (content_cost, content_missing) = self.plated_cost(menu, content, variation);
+                cost += content_cost * line.quantity;
+                for name in content_missing {
+                    if !missing.contains(&name) {
+                        missing.push(name);
+                    }
+                }
+            }
+        }
+        (cost, missing)
+    }
+}
+
+pub struct MarginRow {
+    pub name: String,
+    pub plu: String,
+    pub variation: Option<String>,
+    pub price: i64,
+    pub cost: i64,
+    pub missing: Vec<String>,
+}
+
+impl MarginRow {
+    pub fn margin(&self) -> f64 {
+        if self.price == 0 {
+            return 0.0;
+        }
+        (self.price - self.cost) as f64 * 100.0 / self.price as f64
+    }
+
+    fn label(&self) -> String {
+        match &self.variation {
+            Some(variation) => format!("{} {} ({})", variation, self.name, self.plu),
+            None => format!("{} ({})", self.name, self.plu),
+        }
+    }
+}
+
+pub struct MarginReport {
+    pub rows: Vec<MarginRow>,
+    pub target_margin: f64,
+}
+
+impl MarginReport {
+    pub fn from_menu(simulator: &Simulator, menu: &Menu, costs: &CostTable) -> Self {
+        let mut rows = Vec::new();
+        for item in selection::sellable(menu)
+            .into_iter()
+            .filter(|item| !selection::is_shell(item))
+        {
+            let mut variations: Vec<Option<&str>> = item
+                .variations
+                .iter()
+                .map(|variation| Some(variation.name.as_str()))
+                .collect();
+            if variations.is_empty() {
+                variations.push(None);
+            }
+            for variation in variations {
+                let price = match simulator.sell_price(item, variation) {
+                    Ok(price) => price,
+                    Err(_) => continue,
+                };
+                let (cost, missing) = costs.plated_cost(menu, item, variation);
+                rows.push(MarginRow {
+                    name: item.long_name.clone(),
+                    plu: item.plu.clone(),
+                    variation: variation.map(String::from),
+                    price,
+                    cost,
+                    missing,
+                });
+            }
+        }
+        MarginReport {
+            rows,
+            target_margin: costs.target_margin,
+        }
+    }
+
+    /// Fully costed rows under the target margin, lowest margin first.
+    pub fn below_target(&self) -> Vec<&MarginRow> {
+        let mut rows: Vec<&MarginRow> = self
+            .rows
+            .iter()
+            .filter(|row| row.missing.is_empty() && row.margin() < self.target_margin)
+            .collect();
+        rows.sort_by(|a, b| a.margin().partial_cmp(&b.margin()).unwrap());
+        rows
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.below_target()
+            .into_iter()
+            .map(|row| {
+                format!(
+                    "{} margin {:.1}% is below the {:.1}% target",
+                    row.label(),
+                    row.margin(),
+                    self.target_margin
+                )
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for MarginReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<48} {:>8} {:>8} {:>8}",
+            "Item", "Price", "Cost", "Margin"
+        )?;
+        for row in &self.rows {
+            write!(
+                f,
+                "{:<48} {:>8} {:>8} {:>7.1}%",
+                row.label(),
+                cents(row.price),
+                cents(row.cost),
+                row.margin()
+            )?;
+            if !row.missing.is_empty() {
+                write!(f, "  incomplete, no cost for {}", row.missing.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+        for warning in self.warnings() {
+            writeln!(f, "warning: {}", warning)?;
+        }
+        Ok(())
+    }
+}
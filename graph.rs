@@ -0,0 +1,281 @@
+//! The item/slot graph, as Graphviz DOT or Mermaid.
+//!
+//! Every item is a node and every slot an edge from the item to each item the
+//! slot can hold, labelled with the slot name, its type and quantities. Edges
+//! to the slot's default items are drawn bold and item shells are rounded.
+//! `graph [--mermaid] [item]` prints the whole menu, or only what one item or
+//! combo reaches through its slots; `item` is a PLU or an item name, so shells
+//! such as "Six Pack" can be picked by name.
+
+use crate::selection;
+use adjunct::{ItemDefinition, Menu, SlotDefinition, SlotType};
+use std::collections::BTreeSet;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Edge {
+    /// Index of the parent item in the menu.
+    pub from: usize,
+    /// Index of the item the slot can hold.
+    pub to: usize,
+    pub label: String,
+    /// Whether the slot holds this item by default.
+    pub default: bool,
+}
+
+pub struct MenuGraph<'a> {
+    menu: &'a Menu,
+    /// Menu indices of the items shown, in menu order.
+    pub nodes: Vec<usize>,
+    pub edges: Vec<Edge>,
+}
+
+/// e.g. `Entree: Items 1..1, 1 free`; a slot without a maximum shows `1..`.
+fn slot_label(slot: &SlotDefinition) -> String {
+    let slot_type = match slot.slot_type {
+        SlotType::Ingredient => "Ingredient",
+        SlotType::Items => "Items",
+        SlotType::Replace => "Replace",
+        SlotType::ItemShell => "ItemShell",
+    };
+    let maximum = slot.maximum_quantity as i64;
+    let mut label = format!(
+        "{}: {} {}..{}",
+        slot.name,
+        slot_type,
+        slot.minimum_quantity,
+        if maximum > 0 {
+            maximum.to_string()
+        } else {
+            String::new()
+        }
+    );
+    if slot.default_quantity as i64 > 1 {
+        label.push_str(&format!(", {} by default", slot.default_quantity));
+    }
+    if slot.free_quantity as i64 > 0 {
+        label.push_str(&format!(", {} free", slot.free_quantity));
+    }
+    label
+}
+
+fn node_label(item: &ItemDefinition) -> String {
+    if item.plu.is_empty() {
+        item.long_name.clone()
+    } else {
+        format!("{}\n{}", item.long_name, item.plu)
+    }
+}
+
+impl<'a> MenuGraph<'a> {
+    /// The whole menu, or with `roots` only the items they reach.
+    pub fn new(menu: &'a Menu, roots: Option<&[&ItemDefinition]>) -> Self {
+        let edges_from = |from: usize| -> Vec<Edge> {
+            let parent = &menu.items[from];
+            let mut edges = Vec::new();
+            for slot in &parent.slots {
+                let label = slot_label(slot);
+                for (to, item) in menu.items.iter().enumerate() {
+                    if selection::matches(&slot.selection, item) {
+                        edges.push(Edge {
+                            from,
+                            to,
+                            label: label.clone(),
+                            default: slot.default_item_ids.contains(&item.id),
+                        });
+                    }
+                }
+            }
+            edges
+        };
+
+        let mut shown: BTreeSet<usize> = match roots {
+            None => (0..menu.items.len()).collect(),
+            Some(roots) => menu
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| roots.iter().any(|root| root.id == item.id))
+                .map(|(i, _)| i)
+                .collect(),
+        };
+        let mut edges = Vec::new();
+        let mut pending: Vec<usize> = shown.iter().copied().collect();
+        let mut visited = BTreeSet::new();
+        while let Some(from) = pending.pop() {
+            if !visited.insert(from) {
+                continue;
+            }
+            for edge in edges_from(from) {
+                shown.insert(edge.to);
+                pending.push(edge.to);
+                edges.push(edge);
+            }
+        }
+        edges.sort_by_key(|edge| (edge.from, edge.to));
+
+        MenuGraph {
+            menu,
+            nodes: shown.into_iter().collect(),
+            edges,
+        }
+    }
+
+    pub fn dot(&self) -> String {
+        let quote = |text: &str| {
+            format!(
+                "\"{}\"",
+                text.replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+            )
+        };
+        let mut out = String::from("digraph menu {\n    rankdir=LR;\n    node [shape=box];\n");
+        for &i in &self.nodes {
+            let item = &self.menu.items[i];
+            let shape = if selection::is_shell(item) {
+                " style=rounded"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "    n{} [label={}{}];\n",
+                i,
+                quote(&node_label(item)),
+                shape
+            ));
+        }
+        for edge in &self.edges {
+            let style = if edge.default { " style=bold" } else { "" };
+            out.push_str(&format!(
+                "    n{} -> n{} [label={}{}];\n",
+                edge.from,
+                edge.to,
+                quote(&edge.label),
+                style
+            ));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    pub fn mermaid(&self) -> String {
+        let quote =
+            |text: &str| format!("\"{}\"", text.replace('"', "#quot;").replace('\n', "<br>"));
+        let mut out = String::from("flowchart LR\n");
+        for &i in &self.nodes {
+            let item = &self.menu.items[i];
+            let label = quote(&node_label(item));
+            if selection::is_shell(item) {
+                out.push_str(&format!("    n{}({})\n", i, label));
+            } else {
+                out.push_str(&format!("    n{}[{}]\n", i, label));
+            }
+        }
+        for edge in &self.edges {
+            let arrow = if edge.default { "==>" } else { "-->" };
+            out.push_str(&format!(
+                "    n{} {}|{}| n{}\n",
+                edge.from,
+                arrow,
+                quote(&edge.label),
+                edge.to
+            ));
+        }
+        out
+    }
+}
+
+/// Items `name` picks out: the item with that PLU, otherwise every item with
+/// that name.
+pub fn find_items<'a>(menu: &'a Menu, name: &str) -> Vec<&'a ItemDefinition> {
+    let by_plu: Vec<&ItemDefinition> = menu.items.iter().filter(|item| item.plu == name).collect();
+    if !by_plu.is_empty() {
+        return by_plu;
+    }
+    menu.items
+        .iter()
+        .filter(|item| item.long_name.eq_ignore_ascii_case(name))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu;
+
+    fn index(menu: &Menu, plu: &str) -> usize {
+        menu.items
+            .iter()
+            .position(|item| item.plu == plu)
+            .unwrap_or_else(|| panic!("Couldnt find PLU {}", plu))
+    }
+
+    fn six_pack(menu: &Menu) -> (MenuGraph<'_>, usize) {
+        let roots = find_items(menu, "Six Pack");
+        assert_eq!(roots.len(), 1);
+        let shell = menu
+            .items
+            .iter()
+            .position(|item| item.id == roots[0].id)
+            .unwrap();
+        (MenuGraph::new(menu, Some(&roots)), shell)
+    }
+
+    #[test]
+    fn six_pack_dot_labels_edges_with_slots() {
+        let menu = &menu::build().menu;
+        let (graph, shell) = six_pack(menu);
+        let dot = graph.dot();
+        let mixed = index(menu, "3300");
+        assert!(dot.contains(&format!(
+            "    n{} [label=\"Six Pack\" style=rounded];",
+            shell
+        )));
+        assert!(dot.contains(&format!(
+            "    n{} -> n{} [label=\"Style: ItemShell 1..\"];",
+            shell, mixed
+        )));
+        assert!(dot.contains(&format!(
+            "    n{} -> n{} [label=\"Side: Items 2..2, 2 by default\" style=bold];",
+            mixed,
+            index(menu, "1830")
+        )));
+    }
+
+    #[test]
+    fn six_pack_mermaid_draws_defaults_bold() {
+        let menu = &menu::build().menu;
+        let (graph, shell) = six_pack(menu);
+        let mermaid = graph.mermaid();
+        let crispy = index(menu, "3290");
+        assert!(mermaid.contains(&format!("    n{}(\"Six Pack\")", shell)));
+        assert!(mermaid.contains(&format!(
+            "    n{} -->|\"Style: ItemShell 1..\"| n{}",
+            shell, crispy
+        )));
+        assert!(mermaid.contains(&format!(
+            "    n{} ==>|\"Entree: Items 6..6, 6 by default\"| n{}",
+            crispy,
+            index(menu, "100")
+        )));
+        assert!(mermaid.contains(&format!(
+            "    n{} -->|\"Entree: Items 6..6, 6 by default\"| n{}",
+            crispy,
+            index(menu, "110")
+        )));
+    }
+
+    #[test]
+    fn one_root_leaves_out_unrelated_items() {
+        let menu = &menu::build().menu;
+        let (graph, _) = six_pack(menu);
+        let burrito = index(menu, "480");
+        assert!(graph.nodes.contains(&index(menu, "3280")));
+        assert!(!graph.nodes.contains(&burrito));
+        assert!(graph
+            .edges
+            .iter()
+            .all(|edge| edge.from != burrito && edge.to != burrito));
+        assert!(MenuGraph::new(menu, None).nodes.contains(&burrito));
+    }
+}
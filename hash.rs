@@ -0,0 +1,30 @@
+//! Stable content hashing. `DefaultHasher` is not guaranteed to stay the same
+//! between Rust releases, so anything we write to disk uses FNV-1a instead.
+
+use adjunct::ID;
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Hex form used in manifests and file names.
+pub fn hex(bytes: &[u8]) -> String {
+    format!("{:016x}", fnv1a(bytes))
+}
+
+/// A UUID derived from `name`, for ids that have to be the same on every
+/// build but are not written out in the menu source.
+pub fn uuid(name: &str) -> ID {
+    let mut bytes = [0; 16];
+    bytes[..8].copy_from_slice(&fnv1a(name.as_bytes()).to_be_bytes());
+    bytes[8..].copy_from_slice(&fnv1a(format!("{}/", name).as_bytes()).to_be_bytes());
+    // Version 8 (custom), RFC 4122 variant.
+    bytes[6] = (bytes[6] & 0x0f) | 0x80;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    ID::from_bytes(bytes)
+}
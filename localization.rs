@@ -0,0 +1,163 @@
+//! Localized item and category names.
+//!
+//! Each `locales/<locale>.json` holds a translation table: item names keyed by
+//! item id and category names keyed by their English name. The build writes a
+//! `menu.<locale>.json` with the names swapped in, falling back to English for
+//! anything not yet translated.
+
+use crate::document::MenuDocument;
+use adjunct::ID;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+pub const LOCALE_DIR: &str = "locales";
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ItemNames {
+    pub long_name: Option<String>,
+    pub short_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Translations {
+    #[serde(default)]
+    pub items: BTreeMap<ID, ItemNames>,
+    #[serde(default)]
+    pub categories: BTreeMap<String, String>,
+}
+
+/// Every translation table in `dir`, by locale, e.g. `("es", ...)`.
+pub fn load_locales(dir: &Path) -> Vec<(String, Translations)> {
+    let mut locales = Vec::new();
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return locales,
+    };
+    for entry in entries {
+        let path = entry.expect("Couldnt read locale directory").path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        let locale = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .expect("Couldnt read locale file name")
+            .to_string();
+        let json = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Couldnt read {}: {}", path.display(), e));
+        let translations = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Couldnt parse {}: {}", path.display(), e));
+        locales.push((locale, translations));
+    }
+    locales.sort_by(|a, b| a.0.cmp(&b.0));
+    locales
+}
+
+pub struct TranslationReport {
+    pub locale: String,
+    /// English strings with no translation.
+    pub missing: Vec<String>,
+    /// Table entries for items or categories that are not on the menu.
+    pub unknown: Vec<String>,
+}
+
+impl TranslationReport {
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if !self.missing.is_empty() {
+            warnings.push(format!(
+                "{}: {} strings have no translation",
+                self.locale,
+                self.missing.len()
+            ));
+        }
+        for unknown in &self.unknown {
+            warnings.push(format!(
+                "{}: translation for unknown {}",
+                self.locale, unknown
+            ));
+        }
+        warnings
+    }
+}
+
+impl fmt::Display for TranslationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Missing {} translations ({}):",
+            self.locale,
+            self.missing.len()
+        )?;
+        for missing in &self.missing {
+            writeln!(f, "  {}", missing)?;
+        }
+        for unknown in &self.unknown {
+            writeln!(f, "warning: translation for unknown {}", unknown)?;
+        }
+        Ok(())
+    }
+}
+
+/// `document` with names in `locale`, and what could not be translated.
+pub fn localize(
+    document: &MenuDocument,
+    locale: &str,
+    translations: &Translations,
+) -> (MenuDocument, TranslationReport) {
+    let mut localized = document.clone();
+    let mut missing = Vec::new();
+
+    for item in &mut localized.menu.items {
+        let names = translations.items.get(&item.id);
+        match names.and_then(|names| names.long_name.as_ref()) {
+            Some(long_name) => item.long_name = long_name.clone(),
+            None => missing.push(format!("item {} long name \"{}\"", item.id, item.long_name)),
+        }
+        match names.and_then(|names| names.short_name.as_ref()) {
+            Some(short_name) => item.short_name = short_name.clone(),
+            None => missing.push(format!(
+                "item {} short name \"{}\"",
+                item.id, item.short_name
+            )),
+        }
+    }
+    for category in &mut localized.menu.categories {
+        match translations.categories.get(&category.name) {
+            Some(name) => {
+                if let Some(dayparts) = localized.dayparts.categories.remove(&category.name) {
+                    localized.dayparts.categories.insert(name.clone(), dayparts);
+                }
+                category.name = name.clone();
+            }
+            None => missing.push(format!("category \"{}\"", category.name)),
+        }
+    }
+
+    let mut unknown = Vec::new();
+    for id in translations.items.keys() {
+        if !document.menu.items.iter().any(|item| item.id == *id) {
+            unknown.push(format!("item {}", id));
+        }
+    }
+    for name in translations.categories.keys() {
+        if !document
+            .menu
+            .categories
+            .iter()
+            .any(|category| &category.name == name)
+        {
+            unknown.push(format!("category \"{}\"", name));
+        }
+    }
+
+    let report = TranslationReport {
+        locale: locale.to_string(),
+        missing,
+        unknown,
+    };
+    (localized, report)
+}
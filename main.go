@@ -1,11 +1,14 @@
 package main
 
 import (
+	"encoding/json"
 	"fmt"
 	"io"
 	"io/ioutil"
 	"log"
 	"os"
+	"path/filepath"
+	"strconv"
 	"strings"
 
 	"github.com/terryberlin/CarbonMenuPriceUpdate/db"
@@ -18,16 +21,32 @@ type (
 		PriceOld string `db:"priceOld"`
 		PriceNew string `db:"priceNew"`
 	}
+
+	//PriceRequest : PriceRequest is one new price for the Rust `propose --prices` step.
+	PriceRequest struct {
+		PLU   string `json:"plu"`
+		Price int    `json:"price"`
+	}
+
+	//PriceRequests : PriceRequests is the file read by `propose --prices`.
+	PriceRequests struct {
+		Source string         `json:"source"`
+		Unit   string         `json:"unit"`
+		Prices []PriceRequest `json:"prices"`
+	}
 )
 
+//RequestFile is where -propose writes the database prices for review.
+const RequestFile = "price_requests.json"
+
 func main() {
 
 	unit_id := 94
 	plu := "999"
 	priceStandard := "0"
 	price := "0"
-	oldString := `price: `
-	newString := `price: `
+	oldString := `.price(`
+	newString := `.price(`
 
 	Jobs := []Job{}
 	sql := `exec quikserve.dbo.getPriceChanges $1`
@@ -36,33 +55,82 @@ func main() {
 		log.Println(errSQL)
 	}
 
-	data, err1 := ioutil.ReadFile("main.rs")
+	//With -propose the prices are written out for `propose --prices` instead of
+	//being patched in, so they go through review and `apply`, which records
+	//them in the price audit log.
+	if len(os.Args) > 1 && os.Args[1] == "-propose" {
+		err := WriteRequests(RequestFile, Jobs, unit_id)
+		if err != nil {
+			log.Fatal(err)
+		}
+		return
+	}
+
+	//The menu source is split into one file per section under menu/.
+	files, err1 := filepath.Glob("menu/*.rs")
 	if err1 != nil {
 		log.Fatal(err1)
 	}
 
-	//newdata := strings.Replace(string(data), oldString, newString, 1)
-	newdata := string(data)
+	sources := map[string]string{}
+	for _, file := range files {
+		data, err := ioutil.ReadFile(file)
+		if err != nil {
+			log.Fatal(err)
+		}
+		sources[file] = string(data)
+	}
 
 	for i := range Jobs {
 		priceStandard = fmt.Sprint(*&Jobs[i].PriceOld)
 		price = fmt.Sprint(*&Jobs[i].PriceNew)
 		plu = fmt.Sprint(*&Jobs[i].PLU)
 
-		oldString = `price: ` + priceStandard + `, plu: "` + plu + `"`
-		newString = `price: ` + price + `, plu: "` + plu + `"`
+		oldString = `.price(` + priceStandard + `).plu("` + plu + `")`
+		newString = `.price(` + price + `).plu("` + plu + `")`
 
 		log.Println(oldString, newString)
-		newdata = strings.Replace(string(newdata), oldString, newString, 1)
+		for _, file := range files {
+			if strings.Contains(sources[file], oldString) {
+				sources[file] = strings.Replace(sources[file], oldString, newString, 1)
+				break
+			}
+		}
 	}
 
-	err2 := WriteToFile("main2.rs", newdata)
+	//Patched copies go to menu2/, the same way main.rs used to be copied to main2.rs.
+	//Nothing is applied to menu/ here, so nothing goes in the price audit log.
+	err2 := os.MkdirAll("menu2", 0755)
 	if err2 != nil {
 		log.Fatal(err2)
 	}
+	for _, file := range files {
+		err3 := WriteToFile(filepath.Join("menu2", filepath.Base(file)), sources[file])
+		if err3 != nil {
+			log.Fatal(err3)
+		}
+	}
 
 }
 
+//WriteRequests : WriteRequests writes the database prices as a price request file.
+func WriteRequests(filename string, jobs []Job, unitID int) error {
+	requests := PriceRequests{Source: "database", Unit: strconv.Itoa(unitID), Prices: []PriceRequest{}}
+	for _, job := range jobs {
+		price, err := strconv.Atoi(job.PriceNew)
+		if err != nil {
+			return fmt.Errorf("bad new price %q for PLU %d", job.PriceNew, job.PLU)
+		}
+		requests.Prices = append(requests.Prices, PriceRequest{PLU: strconv.Itoa(job.PLU), Price: price})
+	}
+
+	data, err := json.MarshalIndent(requests, "", "  ")
+	if err != nil {
+		return err
+	}
+	return WriteToFile(filename, string(data))
+}
+
 func WriteToFile(filename string, data string) error {
 	file, err := os.Create(filename)
 	if err != nil {
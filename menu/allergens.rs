@@ -0,0 +1,57 @@
+use super::Registry;
+use super::drinks::Drinks;
+use super::ingredients::Ingredients;
+use super::proteins::Proteins;
+use super::sauces::Sauces;
+use crate::dietary::Allergen::{Dairy, Gluten, Shellfish};
+use crate::dietary::DietaryFlags;
+
+pub fn register(
+    reg: &mut Registry,
+    proteins: &Proteins,
+    sauces: &Sauces,
+    ingredients: &Ingredients,
+    drinks: &Drinks,
+) {
+    // Proteins
+    reg.ingredient_flags(proteins.sausage, DietaryFlags::new(&[], false));
+    reg.ingredient_flags(proteins.bacon, DietaryFlags::new(&[], false));
+    reg.ingredient_flags(proteins.ground_beef, DietaryFlags::new(&[], false));
+    reg.ingredient_flags(proteins.chicken, DietaryFlags::new(&[], false));
+    reg.ingredient_flags(proteins.steak, DietaryFlags::new(&[], false));
+    reg.ingredient_flags(proteins.fried_chicken, DietaryFlags::new(&[Gluten], false));
+    reg.ingredient_flags(proteins.shrimp, DietaryFlags::new(&[Shellfish], false));
+    reg.ingredient_flags(proteins.fish, DietaryFlags::new(&[], false));
+
+    // Dairy
+    reg.ingredient_flags(ingredients.cheese, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(ingredients.cheddar_cheese, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(ingredients.nacho_cheese, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(ingredients.queso_fresco, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(sauces.sour_cream, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(ingredients.blue_cheese, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(ingredients.cream, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(drinks.milk, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(drinks.chocolate_milk, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(ingredients.ranch, DietaryFlags::new(&[Dairy], true));
+    reg.ingredient_flags(ingredients.bacon_ranch, DietaryFlags::new(&[Dairy], false));
+
+    // Shells
+    reg.ingredient_flags(ingredients.small_tortilla, DietaryFlags::new(&[Gluten], true));
+    reg.ingredient_flags(ingredients.med_tortilla, DietaryFlags::new(&[Gluten], true));
+    reg.ingredient_flags(ingredients.large_tortilla, DietaryFlags::new(&[Gluten], true));
+    reg.ingredient_flags(ingredients.burger_bun, DietaryFlags::new(&[Gluten], true));
+    reg.ingredient_flags(ingredients.corn_tortilla, DietaryFlags::new(&[], true));
+
+    // Produce and eggs
+    reg.ingredient_flags(ingredients.scrambled_eggs, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.lettuce, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.tomato, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.onions, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.poblano_peppers, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.jalapeno, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.pico_de_gallo, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.salsa, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.lime, DietaryFlags::new(&[], true));
+    reg.ingredient_flags(ingredients.black_beans, DietaryFlags::new(&[], true));
+}
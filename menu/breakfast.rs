@@ -0,0 +1,325 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle, PriceOverrideBuilder, Selection, SlotBuilder};
+use super::{dynamic_add_ons, Registry};
+use super::ingredients::Ingredients;
+use super::proteins::Proteins;
+use super::sauces::Sauces;
+use super::sides::Sides;
+use adjunct::SlotType;
+
+/// What later sections use from this one.
+pub struct Breakfast {
+    pub bacon_and_egg_burrito: ItemHandle,
+    pub bacon_junior_breakfast_burrito: ItemHandle,
+    pub bacon_potato_breakfast_burrito: ItemHandle,
+    pub bacon_scrambler_burrito: ItemHandle,
+    pub beef_potato_breakfast_burrito: ItemHandle,
+    pub potato_griller_shell_item: ItemHandle,
+    pub sausage_and_egg_burrito: ItemHandle,
+    pub sausage_junior_breakfast_burrito: ItemHandle,
+    pub sausage_potato_breakfast_burrito: ItemHandle,
+    pub sausage_scrambler_burrito: ItemHandle,
+    pub spicy_chorizo_breakfast_burrito: ItemHandle,
+    pub steak_and_egg_burrito: ItemHandle,
+    pub steak_junior_breakfast_burrito: ItemHandle,
+    pub steak_potato_breakfast_burrito: ItemHandle,
+    pub steak_scrambler_burrito: ItemHandle,
+    pub triple_meat_potato_breakfast_burrito: ItemHandle,
+}
+
+pub fn register(
+    reg: &mut Registry,
+    proteins: &Proteins,
+    sauces: &Sauces,
+    ingredients: &Ingredients,
+    sides: &Sides,
+) -> Breakfast {
+    let add_ons_vec = sides.add_ons_vec.clone();
+    let bacon = proteins.bacon;
+    let breakfast_proteins = ingredients.breakfast_proteins.clone();
+    let cheddar_cheese = ingredients.cheddar_cheese;
+    let chicken = proteins.chicken;
+    let creamy_chipotle = sauces.creamy_chipotle;
+    let ground_beef = proteins.ground_beef;
+    let jalapeno = ingredients.jalapeno;
+    let large_tortilla = ingredients.large_tortilla;
+    let med_tortilla = ingredients.med_tortilla;
+    let mild_sauce = sauces.mild_sauce;
+    let nacho_cheese = ingredients.nacho_cheese;
+    let onions = ingredients.onions;
+    let poblano_peppers = ingredients.poblano_peppers;
+    let potato_ole = ingredients.potato_ole;
+    let potato_ole_seasoning = ingredients.potato_ole_seasoning;
+    let salsa = ingredients.salsa;
+    let sausage = proteins.sausage;
+    let scrambled_eggs = ingredients.scrambled_eggs;
+    let small_tortilla = ingredients.small_tortilla;
+    let steak = proteins.steak;
+    let super_hot = sauces.super_hot;
+    let tomato = ingredients.tomato;
+
+    let _potato_ole_scrambler = reg.add_item(
+        ItemBuilder::new("3e7a774b-3546-4e54-ae61-9a0db3348be9", "Potato Ole Scrambler", "POS")
+            .price(729).plu("4150")
+            .tags(&[tags::BREAKFAST])
+            .priority(11)
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Replace, Selection::AnyItem(&[sausage, bacon]))
+                    .collapsed(false)
+                    .price_override(PriceOverrideBuilder::new().price(0).items(&[sausage, bacon]))
+            )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[scrambled_eggs, bacon, sausage, nacho_cheese, cheddar_cheese, potato_ole, poblano_peppers, onions, tomato]))
+                    .collapsed(false)
+                    .defaults(&[scrambled_eggs, bacon, sausage, nacho_cheese, cheddar_cheese, potato_ole, poblano_peppers, onions, tomato])
+            )
+            .slot(dynamic_add_ons(&[scrambled_eggs, bacon, sausage, nacho_cheese, cheddar_cheese, potato_ole, poblano_peppers, onions, tomato], &add_ons_vec))
+    );
+
+    let spicy_chorizo_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("3f94a710-e2ad-4f32-bd2c-2291d6830957", "Spicy Chorizo Breakfast Burrito", "BUR-SPCY")
+            .price(395).plu("4600")
+            .tags(&[tags::BREAKFAST])
+            .priority(12)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[scrambled_eggs, nacho_cheese, large_tortilla, jalapeno, super_hot]))
+                    .collapsed(false)
+                    .defaults(&[scrambled_eggs, nacho_cheese, large_tortilla, jalapeno, super_hot])
+            )
+            .slot(dynamic_add_ons(&[scrambled_eggs, nacho_cheese, large_tortilla, jalapeno, super_hot], &add_ons_vec))
+    );
+
+    let beef_potato_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("b0f1f629-1303-48e0-be50-033a7310eb77", "Beef M&P Breakfast Burrito", "BKMPB-BF")
+            .price(389).plu("4702")
+            .priority(4)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla])
+            )
+            .slot(dynamic_add_ons(&[ground_beef, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla], &add_ons_vec))
+    );
+
+    let bacon_potato_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("f7e4219c-9dec-448b-9a26-1d114406601e", "Bacon M&P Breakfast Burrito", "BKMPB-BA")
+            .price(389).plu("4700")
+            .priority(4)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[bacon, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[bacon, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla])
+            )
+            .slot(dynamic_add_ons(&[scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla], &add_ons_vec))
+    );
+
+    let sausage_potato_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("ef95c69e-2d38-4a71-8e52-994d33d36cc3", "Sausage M&P Breakfast Burrito", "BKMPB-SA")
+            .price(389).plu("4710")
+            .priority(4)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[sausage, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[sausage, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla])
+            )
+            .slot(dynamic_add_ons(&[scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla], &add_ons_vec))
+    );
+
+    let steak_potato_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("4163382d-3eed-46cb-aaa7-f8a02b8b8f0b", "Steak M&P Breakfast Burrito", "BKMPB-STK")
+            .price(499).plu("3060")
+            .priority(4)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[steak, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla])
+            )
+            .slot(dynamic_add_ons(&[scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, potato_ole_seasoning, large_tortilla], &add_ons_vec))
+    );
+
+    let spicy_chicken_and_potato_griller = reg.add_item(
+        ItemBuilder::new("416514e0-dc30-443f-a7da-cf4548bba3c0", "Spicy Chicken and Potato Griller", "ORGRL-CK")
+            .price(200).plu("12310")
+            .priority(12)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, creamy_chipotle, nacho_cheese, potato_ole, potato_ole_seasoning, super_hot, med_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[chicken, creamy_chipotle, nacho_cheese, potato_ole, potato_ole_seasoning, super_hot, med_tortilla])
+            )
+            .slot(dynamic_add_ons(&[chicken, creamy_chipotle, nacho_cheese, potato_ole, potato_ole_seasoning, super_hot, med_tortilla], &add_ons_vec))
+    );
+
+    let steak_and_potato_griller = reg.add_item(
+        ItemBuilder::new("be2d02d0-2971-4780-8614-0ac7060b6357", "Steak and Potato Griller", "Spicy S&P Griller")
+            .price(300).plu("12320")
+            .priority(12)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, creamy_chipotle, nacho_cheese, potato_ole, potato_ole_seasoning, super_hot, med_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[steak, creamy_chipotle, nacho_cheese, potato_ole, potato_ole_seasoning, super_hot, med_tortilla])
+            )
+            .slot(dynamic_add_ons(&[steak, creamy_chipotle, nacho_cheese, potato_ole, potato_ole_seasoning, super_hot, med_tortilla], &add_ons_vec))
+    );
+
+    let potato_griller_shell_item = reg.add_item(
+        ItemBuilder::new("3d706676-19f8-43af-8aef-8065b84eedf0", "Meat and Potato Griller", "M&P Griler")
+            .tags(&[tags::VALUE])
+            .priority(20)
+            .slot(
+                SlotBuilder::new("Burrito", SlotType::ItemShell, Selection::AnyItem(&[spicy_chicken_and_potato_griller, steak_and_potato_griller]))
+                    .minimum_quantity(1)
+            )
+    );
+
+    let triple_meat_potato_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("42626cf1-f04f-44d2-b0bb-99e172e802fa", "Triple Meat and Potato Breakfast Burrito", "TRIP-MT-B")
+            .price(449).plu("6430")
+            .priority(13)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[bacon, sausage, steak, scrambled_eggs, nacho_cheese, large_tortilla, tomato, potato_ole, potato_ole_seasoning, large_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[bacon, sausage, steak, scrambled_eggs, nacho_cheese, large_tortilla, tomato, potato_ole, potato_ole_seasoning, large_tortilla])
+            )
+            .slot(dynamic_add_ons(&[bacon, sausage, steak, scrambled_eggs, nacho_cheese, large_tortilla, tomato, potato_ole, potato_ole_seasoning, large_tortilla], &add_ons_vec))
+    );
+
+    let bacon_scrambler_burrito = reg.add_item(
+        ItemBuilder::new("429300cc-b31d-4f11-ab8b-50d76dc02b6b", "Bacon Scrambler Burrito", "SCRAM-BUR-BA")
+            .price(419).plu("4100")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[bacon, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato]))
+                    .collapsed(false)
+                    .defaults(&[bacon, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato])
+            )
+            .slot(dynamic_add_ons(&[scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato], &add_ons_vec))
+    );
+
+    let sausage_scrambler_burrito = reg.add_item(
+        ItemBuilder::new("429eaa27-c3c4-44c6-982d-2a532d8f43f3", "Sausage Scrambler Burrito", "SCRAM-BUR-SAU")
+            .price(419).plu("4110")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[sausage, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato]))
+                    .collapsed(false)
+                    .defaults(&[sausage, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato])
+            )
+            .slot(dynamic_add_ons(&[scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato], &add_ons_vec))
+    );
+
+    let steak_scrambler_burrito = reg.add_item(
+        ItemBuilder::new("42d0c56b-5d8c-499d-b72e-74acb45e48cf", "Steak Scrambler Burrito", "SCRAM-BUR-STK")
+            .price(489).plu("3070")
+            .slot(breakfast_proteins.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato]))
+                    .collapsed(false)
+                    .defaults(&[steak, scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato])
+            )
+            .slot(dynamic_add_ons(&[scrambled_eggs, nacho_cheese, large_tortilla, potato_ole, poblano_peppers, onions, tomato], &add_ons_vec))
+    );
+
+    let scrambler_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("539790d3-ec2d-4f91-bac9-f1937e85cb82", "Scrambler Burrito", "Scr Burr")
+            .tags(&[tags::BREAKFAST])
+            .priority(85)
+            .slot(
+                SlotBuilder::new("Burrito", SlotType::ItemShell, Selection::AnyItem(&[bacon_scrambler_burrito, sausage_scrambler_burrito, steak_scrambler_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[bacon_scrambler_burrito])
+            )
+    );
+
+    let bacon_junior_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("43922e21-fcb8-4d56-b331-4f1b1c987227", "Bacon Jr. Breakfast Burrito", "BUR-JBA")
+            .price(239).plu("3650")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[bacon, nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs]))
+                    .collapsed(false)
+                    .defaults(&[bacon, nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs])
+            )
+            .slot(dynamic_add_ons(&[nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs], &add_ons_vec))
+    );
+
+    let sausage_junior_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("45e44a2c-01b2-4f76-b140-c311f8a0c5c6", "Sausage Jr. Breakfast Burrito", "BUR-JSAU")
+            .price(239).plu("3660")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[sausage, nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs]))
+                    .collapsed(false)
+                    .defaults(&[sausage, nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs])
+            )
+            .slot(dynamic_add_ons(&[nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs], &add_ons_vec))
+    );
+
+    let steak_junior_breakfast_burrito = reg.add_item(
+        ItemBuilder::new("46503465-9dce-43b2-ac03-9281af1c91fb", "Steak Jr. Breakfast Burrito", "BUR-JSTK")
+            .price(339).plu("3140")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs]))
+                    .collapsed(false)
+                    .defaults(&[steak, nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs])
+            )
+            .slot(dynamic_add_ons(&[nacho_cheese, mild_sauce, small_tortilla, scrambled_eggs], &add_ons_vec))
+    );
+
+    let junior_breakfast_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("faa1ef25-78d4-4b4b-ba1a-e59e285fe408", "Junior Breakfast Burrito", "J Brk Burr")
+            .tags(&[tags::BREAKFAST])
+            .priority(85)
+            .slot(
+                SlotBuilder::new("Burrito", SlotType::ItemShell, Selection::AnyItem(&[bacon_junior_breakfast_burrito, sausage_junior_breakfast_burrito, steak_junior_breakfast_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[bacon_junior_breakfast_burrito])
+            )
+    );
+
+    let bacon_and_egg_burrito = reg.add_item(
+        ItemBuilder::new("79bb3918-8b3c-494f-b4b2-d97e9c4f4045", "Bacon M&E Burrito", "Bcn M&E Burr")
+            .price(379).plu("4010")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[bacon, cheddar_cheese, salsa, large_tortilla, scrambled_eggs]))
+                    .collapsed(false)
+                    .defaults(&[bacon, cheddar_cheese, salsa, large_tortilla, scrambled_eggs])
+            )
+            .slot(dynamic_add_ons(&[bacon, cheddar_cheese, salsa, large_tortilla, scrambled_eggs], &add_ons_vec))
+    );
+
+    let sausage_and_egg_burrito = reg.add_item(
+        ItemBuilder::new("e7786aeb-d7e1-46a9-b0f0-5a65661a23a6", "Sausage M&E Burrito", "Saus M&E Burr")
+            .price(379).plu("4020")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[sausage, cheddar_cheese, salsa, large_tortilla, scrambled_eggs]))
+                    .collapsed(false)
+                    .defaults(&[sausage, cheddar_cheese, salsa, large_tortilla, scrambled_eggs])
+            )
+            .slot(dynamic_add_ons(&[sausage, cheddar_cheese, salsa, large_tortilla, scrambled_eggs], &add_ons_vec))
+    );
+
+    let steak_and_egg_burrito = reg.add_item(
+        ItemBuilder::new("7a88c26e-a3ac-4f4d-9edf-e4ef3e93f6be", "Steak M&E Burrito", "Stk M&E Burr")
+            .price(499).plu("3050")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, cheddar_cheese, salsa, large_tortilla, scrambled_eggs]))
+                    .collapsed(false)
+                    .defaults(&[steak, cheddar_cheese, salsa, large_tortilla, scrambled_eggs])
+            )
+            .slot(dynamic_add_ons(&[steak, cheddar_cheese, salsa, large_tortilla, scrambled_eggs], &add_ons_vec))
+    );
+
+    Breakfast {
+        bacon_and_egg_burrito,
+        bacon_junior_breakfast_burrito,
+        bacon_potato_breakfast_burrito,
+        bacon_scrambler_burrito,
+        beef_potato_breakfast_burrito,
+        potato_griller_shell_item,
+        sausage_and_egg_burrito,
+        sausage_junior_breakfast_burrito,
+        sausage_potato_breakfast_burrito,
+        sausage_scrambler_burrito,
+        spicy_chorizo_breakfast_burrito,
+        steak_and_egg_burrito,
+        steak_junior_breakfast_burrito,
+        steak_potato_breakfast_burrito,
+        steak_scrambler_burrito,
+        triple_meat_potato_breakfast_burrito,
+    }
+}
@@ -0,0 +1,488 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle, PriceOverrideBuilder, Selection, SlotBuilder};
+use super::{dynamic_add_ons, Registry};
+use super::breakfast::Breakfast;
+use super::ingredients::Ingredients;
+use super::proteins::Proteins;
+use super::sauces::Sauces;
+use super::sides::Sides;
+use adjunct::{Modification, SlotType};
+
+/// What later sections use from this one.
+pub struct Burritos {
+    pub bean_burrito: ItemHandle,
+    pub bean_grilled_burrito: ItemHandle,
+    pub bean_meat_potato_burrito: ItemHandle,
+    pub bean_super_burrito: ItemHandle,
+    pub beef_boss_bowl: ItemHandle,
+    pub beef_boss_burrito: ItemHandle,
+    pub beef_burrito: ItemHandle,
+    pub beef_grilled_burrito: ItemHandle,
+    pub beef_meat_potato_burrito: ItemHandle,
+    pub beef_super_burrito: ItemHandle,
+    pub chicken_boss_bowl: ItemHandle,
+    pub chicken_boss_burrito: ItemHandle,
+    pub chicken_burrito: ItemHandle,
+    pub chicken_grilled_burrito: ItemHandle,
+    pub chicken_meat_potato_burrito: ItemHandle,
+    pub chicken_super_burrito: ItemHandle,
+    pub combination_burrito: ItemHandle,
+    pub fried_chicken_burrito: ItemHandle,
+    pub fried_chicken_grilled_burrito: ItemHandle,
+    pub fried_chicken_meat_potato_burrito: ItemHandle,
+    pub meat_and_potato_breakfast_burrito_shell_item: ItemHandle,
+    pub saus_and_bacon_meat_potato_burrito: ItemHandle,
+    pub steak_boss_bowl: ItemHandle,
+    pub steak_boss_burrito: ItemHandle,
+    pub steak_burrito: ItemHandle,
+    pub steak_grilled_burrito: ItemHandle,
+    pub steak_meat_potato_burrito: ItemHandle,
+    pub steak_super_burrito: ItemHandle,
+}
+
+pub fn register(
+    reg: &mut Registry,
+    proteins: &Proteins,
+    sauces: &Sauces,
+    ingredients: &Ingredients,
+    sides: &Sides,
+    breakfast: &Breakfast,
+) -> Burritos {
+    let add_ons_vec = sides.add_ons_vec.clone();
+    let bacon = proteins.bacon;
+    let bacon_potato_breakfast_burrito = breakfast.bacon_potato_breakfast_burrito;
+    let beef_potato_breakfast_burrito = breakfast.beef_potato_breakfast_burrito;
+    let black_beans = ingredients.black_beans;
+    let cheddar_cheese = ingredients.cheddar_cheese;
+    let chicken = proteins.chicken;
+    let cilantro_lime_rice = ingredients.cilantro_lime_rice;
+    let corn_salsa = ingredients.corn_salsa;
+    let fried_chicken = proteins.fried_chicken;
+    let ground_beef = proteins.ground_beef;
+    let house_salsa = sauces.house_salsa;
+    let large_tortilla = ingredients.large_tortilla;
+    let lettuce = ingredients.lettuce;
+    let mild_sauce = sauces.mild_sauce;
+    let nacho_cheese = ingredients.nacho_cheese;
+    let onions = ingredients.onions;
+    let pico_de_gallo = ingredients.pico_de_gallo;
+    let potato_ole = ingredients.potato_ole;
+    let refried_beans = ingredients.refried_beans;
+    let sausage = proteins.sausage;
+    let sausage_potato_breakfast_burrito = breakfast.sausage_potato_breakfast_burrito;
+    let side_ranch = ingredients.side_ranch;
+    let sour_cream = sauces.sour_cream;
+    let steak = proteins.steak;
+    let steak_potato_breakfast_burrito = breakfast.steak_potato_breakfast_burrito;
+    let tomato = ingredients.tomato;
+
+    let boss_sauce = SlotBuilder::new("Sauce", SlotType::Items, Selection::AnyItem(&[pico_de_gallo, corn_salsa]))
+        .minimum_quantity(1)
+        .maximum_quantity(4)
+        .price_override(PriceOverrideBuilder::new().items(&[pico_de_gallo, corn_salsa]).price(0));
+
+    let beef_boss_bowl = reg.add_item(
+        ItemBuilder::new("48d3360b-f6ba-42a8-a5fe-91766db9af98", "Beef Boss Bowl", "B-BWL-BF")
+            .price(629).plu("12030")
+            .slot(boss_sauce.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, cheddar_cheese, sour_cream, lettuce, house_salsa, black_beans, cilantro_lime_rice]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, cheddar_cheese, sour_cream, lettuce, house_salsa, black_beans, cilantro_lime_rice])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice], &add_ons_vec))
+    );
+
+    let chicken_boss_bowl = reg.add_item(
+        ItemBuilder::new("48fb0de9-6f28-4209-b58b-277637e30718", "Chicken Boss Bowl", "B-BWL-CK")
+            .price(699).plu("12040")
+            .slot(boss_sauce.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice]))
+                    .collapsed(false)
+                    .defaults(&[chicken, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice], &add_ons_vec))
+    );
+
+    let steak_boss_bowl = reg.add_item(
+        ItemBuilder::new("49f4d3a7-5ef3-4888-9c36-5a31655e09b3", "Steak Boss Bowl", "B-BWL-STK")
+            .price(799).plu("12050")
+            .slot(boss_sauce.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice]))
+                    .collapsed(false)
+                    .defaults(&[steak, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice], &add_ons_vec))
+    );
+
+    let steak_boss_burrito = reg.add_item(
+        ItemBuilder::new("4a5f9ef1-50a6-4ea5-b62e-0c6e8d9fd2a8", "Steak Boss Burrito", "B-BUR-STK")
+            .price(799).plu("12020")
+            .slot(boss_sauce.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice]))
+                    .collapsed(false)
+                    .defaults(&[steak, large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice], &add_ons_vec))
+    );
+
+    let beef_boss_burrito = reg.add_item(
+        ItemBuilder::new("4a75d7e9-60a5-4f06-9a3f-583904e1a0d5", "Beef Boss Burrito", "B-BUR-BF")
+            .price(629).plu("12000")
+            .slot(boss_sauce.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice], &add_ons_vec))
+    );
+
+    let chicken_boss_burrito = reg.add_item(
+        ItemBuilder::new("4bd037df-bc9c-45f6-bfd3-0e91d3f05d6a", "Chicken Boss Burrito", "B-BUR-CK")
+            .price(699).plu("12010")
+            .slot(boss_sauce.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice]))
+                    .collapsed(false)
+                    .defaults(&[chicken, large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, cheddar_cheese, sour_cream, lettuce, pico_de_gallo, house_salsa, black_beans, cilantro_lime_rice], &add_ons_vec))
+    );
+
+    let steak_meat_potato_burrito = reg.add_item(
+        ItemBuilder::new("4d8ae5f3-abab-4975-87c5-596e722b94b6", "Steak M&P  Burrito", "MPB-STK")
+            .price(549).plu("520")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, large_tortilla, sour_cream, lettuce, nacho_cheese, potato_ole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[steak, large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato], &add_ons_vec))
+    );
+
+    let beef_meat_potato_burrito = reg.add_item(
+        ItemBuilder::new("4fbd95a4-1ba9-40ac-9ec7-853ed45c8758", "Beef M&P Burrito", "MPB-BF")
+            .price(399).plu("500")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, large_tortilla, sour_cream, lettuce, nacho_cheese, potato_ole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato], &add_ons_vec))
+    );
+
+    let chicken_meat_potato_burrito = reg.add_item(
+        ItemBuilder::new("51814ea5-2c97-472e-8ebc-c903526051f2", "Chicken M&P Burrito", "MPB-CK")
+            .price(499).plu("510")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, large_tortilla, sour_cream, lettuce, nacho_cheese, potato_ole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[chicken, large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato], &add_ons_vec))
+    );
+
+    let fried_chicken_meat_potato_burrito = reg.add_item(
+        ItemBuilder::new("2bab8913-49ee-4e6e-9e4e-e1486ec8733e", "FC M&P Burrito", "MPB-FC")
+            .price(489).plu("12520")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[fried_chicken, large_tortilla, sour_cream, lettuce, nacho_cheese, potato_ole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[fried_chicken, large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato], &add_ons_vec))
+    );
+
+    let bean_meat_potato_burrito = reg.add_item(
+        ItemBuilder::new("527f423c-50ef-4790-ba2d-a916a2725a3d", "Bean M&P Burrito", "MPB-BN")
+            .price(399).plu("540")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[fried_chicken, large_tortilla, sour_cream, lettuce, nacho_cheese, potato_ole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[fried_chicken, large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato], &add_ons_vec))
+    );
+
+    let saus_and_bacon_meat_potato_burrito = reg.add_item(
+        ItemBuilder::new("c714c46c-e2a0-4480-82ee-8c83bd63c841", "Half BCN SSG M&P Burrito", "MPB-BACSAU")
+            .price(389).plu("4701")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[bacon, sausage, large_tortilla, sour_cream, lettuce, nacho_cheese, potato_ole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[bacon, sausage, large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato])
+            )
+            .slot(dynamic_add_ons(&[bacon, sausage, large_tortilla, sour_cream, nacho_cheese, lettuce, potato_ole, tomato], &add_ons_vec))
+    );
+    let half_and_half = reg.add_item(ItemBuilder::new("aec2f71c-5a4b-48ee-8458-0634d0f934f0", "Half Bacon Suasage", "BACSAU").price(0).plu("999124").tags(&[tags::BREAKFAST_PROTEINS]).modifiers(&[Modification::Extra, Modification::Light]));
+
+    let meat_and_potato_breakfast_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("3208567a-3716-4c32-ae17-5b6d33ba20f1", "M&P Breakfast Burrito", "Taco")
+            .tags(&[tags::BREAKFAST, tags::QUICK])
+            .priority(91)
+            .slot(
+                SlotBuilder::new("Burrito", SlotType::ItemShell, Selection::AnyItem(&[bacon_potato_breakfast_burrito, beef_potato_breakfast_burrito, sausage_potato_breakfast_burrito, steak_potato_breakfast_burrito, saus_and_bacon_meat_potato_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[bacon_potato_breakfast_burrito])
+            )
+    );
+
+    let combination_burrito = reg.add_item(
+        ItemBuilder::new("52f47ccc-c137-458b-b2dc-c087c39fa2f0", "Combination Burrito", "BUR-CB")
+            .price(319).plu("490")
+            .tags(&[tags::DINNER, tags::LUNCH])
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Ingredient, Selection::Tag(tags::PROTEINS))
+                    .defaults(&[ground_beef, refried_beans])
+            )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[large_tortilla, mild_sauce, cheddar_cheese, onions]))
+                    .collapsed(false)
+                    .defaults(&[large_tortilla, mild_sauce, cheddar_cheese, onions])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, mild_sauce, cheddar_cheese, onions], &add_ons_vec))
+    );
+
+    let bean_burrito = reg.add_item(
+        ItemBuilder::new("53a21d3f-77d0-4a09-8eac-fe7ac535871c", "Bean Burrito", "BUR-BN")
+            .price(219).plu("400")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BURRITO])
+            .priority(1)
+            /*.slot(
+                SlotBuilder::new("Protein", SlotType::Ingredient, Selection::AnyItem(&[refried_beans]))
+                    .collapsed(true)
+                    .defaults(&[refried_beans])
+            )*/
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[refried_beans, large_tortilla, mild_sauce, cheddar_cheese, onions]))
+                    .collapsed(false)
+                    .defaults(&[refried_beans, large_tortilla, mild_sauce, cheddar_cheese, onions])
+            )
+            .slot(dynamic_add_ons(&[refried_beans, large_tortilla, mild_sauce, cheddar_cheese, onions], &add_ons_vec))
+    );
+
+    let steak_burrito = reg.add_item(
+        ItemBuilder::new("54642316-47b2-4215-9fe2-e81e7663ded7", "Steak Burrito", "BUR-STK")
+            .price(540).plu("420")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, large_tortilla, mild_sauce, cheddar_cheese, onions]))
+                    .collapsed(false)
+                    .defaults(&[steak, large_tortilla, mild_sauce, cheddar_cheese, onions])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, mild_sauce, cheddar_cheese, onions], &add_ons_vec))
+    );
+
+    let beef_burrito = reg.add_item(
+        ItemBuilder::new("549c0676-5ebd-4695-9a83-5669c2c124f0", "Beef Burrito", "BUR-BF")
+            .price(335).plu("480")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, large_tortilla, mild_sauce, cheddar_cheese, onions]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, large_tortilla, mild_sauce, cheddar_cheese, onions])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, mild_sauce, cheddar_cheese, onions], &add_ons_vec))
+    );
+
+    let chicken_burrito = reg.add_item(
+        ItemBuilder::new("54c610fa-a45a-4317-b960-252bb5e8b72a", "Chicken Burrito", "BUR-CK")
+            .price(379).plu("410")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, large_tortilla, mild_sauce, cheddar_cheese, onions]))
+                    .collapsed(false)
+                    .defaults(&[chicken, large_tortilla, mild_sauce, cheddar_cheese, onions])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, mild_sauce, cheddar_cheese, onions], &add_ons_vec))
+    );
+
+    let fried_chicken_burrito = reg.add_item(
+        ItemBuilder::new("a258eacc-d7d0-44cc-8b43-579ccdd267f9", "Fried Chicken Burrito", "BUR-FC")
+            .price(489).plu("12520")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[fried_chicken, large_tortilla, mild_sauce, cheddar_cheese, onions]))
+                    .collapsed(false)
+                    .defaults(&[fried_chicken, large_tortilla, mild_sauce, cheddar_cheese, onions])
+            )
+            .slot(dynamic_add_ons(&[large_tortilla, mild_sauce, cheddar_cheese, onions], &add_ons_vec))
+    );
+
+    let steak_super_burrito = reg.add_item(
+        ItemBuilder::new("5c8b8314-e3b6-4c8b-a0a4-df904223b576", "Steak Super Burrito", "SB-STK")
+            .price(529).plu("620")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, refried_beans, cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, onions, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[steak, cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, mild_sauce, onions])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, mild_sauce, onions], &add_ons_vec))
+    );
+
+    let beef_super_burrito = reg.add_item(
+        ItemBuilder::new("5cab3ebd-5877-4aff-83bd-e9b4652c367c", "Super Burrito", "SB")
+            .price(399).plu("600")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, refried_beans, cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, onions, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, mild_sauce, onions])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, mild_sauce, onions], &add_ons_vec))
+    );
+
+    let chicken_super_burrito = reg.add_item(
+        ItemBuilder::new("5eb467bd-0f1f-4ad9-ae4f-d39fbe579bb4", "Chicken Super Burrito", "SB-CK")
+            .price(479).plu("610")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, refried_beans, cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, onions, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[chicken, cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, mild_sauce, onions])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, mild_sauce, onions], &add_ons_vec))
+    );
+
+    let bean_super_burrito = reg.add_item(
+        ItemBuilder::new("5efdabd6-2570-490a-b841-09e7737ec5e4", "Bean Super Burrito", "SB-BN")
+            .price(379).plu("640")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[refried_beans, cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, onions, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, mild_sauce, onions])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, lettuce, large_tortilla, tomato, sour_cream, mild_sauce, onions], &add_ons_vec))
+    );
+
+    let grilled_burrito = reg.add_item(
+        ItemBuilder::new("7aa2cb22-8c9e-484a-9b78-6dd03b831d12", "Grilled Burrito", "GRLD")
+            .price(469).plu("840")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::GRILLED])
+            .priority(1)
+            .modifiers(&[Modification::Custom("Not Grilled".to_string())])
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Ingredient, Selection::Tag(tags::PROTEINS))
+                    .defaults(&[chicken])
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::PROTEINS]).price(0))
+            )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese]))
+                    .collapsed(false)
+                    .defaults(&[potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese], &add_ons_vec))
+    );
+
+    let steak_grilled_burrito = reg.add_item(
+        ItemBuilder::new("7b6ab5be-cd5a-40fe-bd93-f403080cee44", "Steak Grilled Burrito", "GRLD-STK")
+            .price(589).plu("820")
+            .tags(&[tags::GRILLED])
+            .priority(1)
+            .modifiers(&[Modification::Custom("Not Grilled".to_string())])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese]))
+                    .collapsed(false)
+                    .defaults(&[steak, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese], &add_ons_vec))
+    );
+
+    let beef_grilled_burrito = reg.add_item(
+        ItemBuilder::new("7bb48f46-4e09-4e9f-8472-1919c135af25", "Beef Grilled Burrito", "GRLD-BF")
+            .price(499).plu("800")
+            .tags(&[tags::GRILLED])
+            .priority(1)
+            .modifiers(&[Modification::Custom("Not Grilled".to_string())])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese], &add_ons_vec))
+    );
+
+    let chicken_grilled_burrito = reg.add_item(
+        ItemBuilder::new("7e0dc31a-27e6-40b8-a2e1-81eaa2c1b827", "Chicken Grilled Burrito", "GRLD-CK")
+            .price(529).plu("810")
+            .tags(&[tags::GRILLED])
+            .priority(1)
+            .modifiers(&[Modification::Custom("Not Grilled".to_string())])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese]))
+                    .collapsed(false)
+                    .defaults(&[chicken, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese], &add_ons_vec))
+    );
+
+    let fried_chicken_grilled_burrito = reg.add_item(
+        ItemBuilder::new("ebb99831-1e87-4c6e-88d8-20646c920680", "FC Grilled Burrito", "GRLD-FC")
+            .price(529).plu("811")
+            .tags(&[tags::GRILLED])
+            .priority(1)
+            .modifiers(&[Modification::Custom("Not Grilled".to_string())])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[fried_chicken, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese]))
+                    .collapsed(false)
+                    .defaults(&[fried_chicken, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese], &add_ons_vec))
+    );
+
+    let bean_grilled_burrito = reg.add_item(
+        ItemBuilder::new("c3ee9a89-3271-4bab-908c-b40f1a05bccc", "Bean Grilled Burrito", "GRLD-BN")
+            .price(469).plu("840")
+            .tags(&[tags::GRILLED])
+            .priority(1)
+            .modifiers(&[Modification::Custom("Not Grilled".to_string())])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[refried_beans, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese]))
+                    .collapsed(false)
+                    .defaults(&[refried_beans, potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, side_ranch, cheddar_cheese, mild_sauce, nacho_cheese], &add_ons_vec))
+    );
+
+    Burritos {
+        bean_burrito,
+        bean_grilled_burrito,
+        bean_meat_potato_burrito,
+        bean_super_burrito,
+        beef_boss_bowl,
+        beef_boss_burrito,
+        beef_burrito,
+        beef_grilled_burrito,
+        beef_meat_potato_burrito,
+        beef_super_burrito,
+        chicken_boss_bowl,
+        chicken_boss_burrito,
+        chicken_burrito,
+        chicken_grilled_burrito,
+        chicken_meat_potato_burrito,
+        chicken_super_burrito,
+        combination_burrito,
+        fried_chicken_burrito,
+        fried_chicken_grilled_burrito,
+        fried_chicken_meat_potato_burrito,
+        meat_and_potato_breakfast_burrito_shell_item,
+        saus_and_bacon_meat_potato_burrito,
+        steak_boss_bowl,
+        steak_boss_burrito,
+        steak_burrito,
+        steak_grilled_burrito,
+        steak_meat_potato_burrito,
+        steak_super_burrito,
+    }
+}
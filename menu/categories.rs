@@ -0,0 +1,19 @@
+use crate::builder::{tags, CategoryBuilder};
+use super::Registry;
+
+pub fn register(reg: &mut Registry) {
+    // Categories
+    reg.category(CategoryBuilder::new("Quick Picks", &[tags::QUICK]).image("quick_picks.png"));
+    reg.category(CategoryBuilder::new("Combos", &[tags::COMBOS]).image("combos.png"));
+    reg.category(CategoryBuilder::new("Tacos", &[tags::TACOS]).image("tacos.png"));
+    reg.category(CategoryBuilder::new("Burritos", &[tags::BURRITO]).image("burritos.png"));
+    reg.category(CategoryBuilder::new("Specialties", &[tags::SPECIALTIES]).image("specialties.png"));
+    reg.category(CategoryBuilder::new("Breakfast", &[tags::BREAKFAST, tags::BREAKFAST_COMBO]).image("breakfast.png"));
+    reg.category(CategoryBuilder::new("Sides", &[tags::SIDES]).image("sides.png"));
+    reg.category(CategoryBuilder::new("Drinks", &[tags::DRINKS]).image("breakfast.png"));
+    reg.category(CategoryBuilder::new("Kids Meal", &[tags::KIDS_MEAL]).image("favorites.png"));
+    reg.category(CategoryBuilder::new("Valuest", &[tags::VALUE]).image("valuest.png"));
+    reg.category(CategoryBuilder::new("LTO", &[tags::LTO]).image("limited time.png"));
+    reg.category(CategoryBuilder::new("Desserts", &[tags::DESSERT]).image("desserts.png"));
+    reg.category(CategoryBuilder::new("Side Of...", &[tags::SIDE_OF]).image("limited time.png").multi_select_modal());
+}
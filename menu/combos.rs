@@ -0,0 +1,1060 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle, PriceOverrideBuilder, Selection, SlotBuilder, VariationBuilder};
+use super::{dynamic_add_ons, Registry};
+use super::breakfast::Breakfast;
+use super::burritos::Burritos;
+use super::drinks::Drinks;
+use super::ingredients::Ingredients;
+use super::proteins::Proteins;
+use super::sauces::Sauces;
+use super::sides::Sides;
+use super::specialties::Specialties;
+use super::tacos::Tacos;
+use adjunct::SlotType;
+
+/// What later sections use from this one.
+pub struct Combos {
+    pub boss_bowl_shell_item: ItemHandle,
+    pub boss_burrito_shell_item: ItemHandle,
+    pub fried_chicken_taco_shell_item: ItemHandle,
+    pub kids_meal: ItemHandle,
+    pub kids_meal_burr: ItemHandle,
+    pub kids_meal_fried_chicken: ItemHandle,
+    pub kids_meal_quesadilla: ItemHandle,
+    pub kids_meal_soft: ItemHandle,
+    pub kids_meal_taco_burger: ItemHandle,
+    pub meat_and_potato_burrito_shell_item: ItemHandle,
+    pub six_pack_and_pound_combo_crispy: ItemHandle,
+    pub six_pack_and_pound_combo_mix: ItemHandle,
+    pub six_pack_and_pound_combo_soft: ItemHandle,
+    pub six_pack_shell_item: ItemHandle,
+    pub softshell_shell_item: ItemHandle,
+    pub super_nachos_shell_item: ItemHandle,
+    pub taco_shell_item: ItemHandle,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn register(
+    reg: &mut Registry,
+    proteins: &Proteins,
+    sauces: &Sauces,
+    ingredients: &Ingredients,
+    sides: &Sides,
+    drinks: &Drinks,
+    specialties: &Specialties,
+    breakfast: &Breakfast,
+    burritos: &Burritos,
+    tacos: &Tacos,
+) -> Combos {
+    let add_ons_vec = sides.add_ons_vec.clone();
+    let bacon_and_egg_burrito = breakfast.bacon_and_egg_burrito;
+    let bacon_junior_breakfast_burrito = breakfast.bacon_junior_breakfast_burrito;
+    let bacon_potato_breakfast_burrito = breakfast.bacon_potato_breakfast_burrito;
+    let bacon_scrambler_burrito = breakfast.bacon_scrambler_burrito;
+    let bean_burrito = burritos.bean_burrito;
+    let bean_crispy_taco = tacos.bean_crispy_taco;
+    let bean_grilled_burrito = burritos.bean_grilled_burrito;
+    let bean_meat_potato_burrito = burritos.bean_meat_potato_burrito;
+    let bean_softshell_taco = tacos.bean_softshell_taco;
+    let bean_stuffed_grilled_taco = tacos.bean_stuffed_grilled_taco;
+    let bean_super_burrito = burritos.bean_super_burrito;
+    let bean_taco_bravo = tacos.bean_taco_bravo;
+    let beef_boss_bowl = burritos.beef_boss_bowl;
+    let beef_boss_burrito = burritos.beef_boss_burrito;
+    let beef_burrito = burritos.beef_burrito;
+    let beef_crispy_taco = tacos.beef_crispy_taco;
+    let beef_grilled_burrito = burritos.beef_grilled_burrito;
+    let beef_meat_potato_burrito = burritos.beef_meat_potato_burrito;
+    let beef_potato_breakfast_burrito = breakfast.beef_potato_breakfast_burrito;
+    let beef_quesadilla = specialties.beef_quesadilla;
+    let beef_softshell_taco = tacos.beef_softshell_taco;
+    let beef_stuffed_grilled_taco = tacos.beef_stuffed_grilled_taco;
+    let beef_super_burrito = burritos.beef_super_burrito;
+    let beef_super_nachos = specialties.beef_super_nachos;
+    let beef_taco_bravo = tacos.beef_taco_bravo;
+    let beef_taco_burger = tacos.beef_taco_burger;
+    let cheddar_cheese = ingredients.cheddar_cheese;
+    let chicken_boss_bowl = burritos.chicken_boss_bowl;
+    let chicken_boss_burrito = burritos.chicken_boss_burrito;
+    let chicken_burrito = burritos.chicken_burrito;
+    let chicken_crispy_taco = tacos.chicken_crispy_taco;
+    let chicken_grilled_burrito = burritos.chicken_grilled_burrito;
+    let chicken_meat_potato_burrito = burritos.chicken_meat_potato_burrito;
+    let chicken_quesadilla = specialties.chicken_quesadilla;
+    let chicken_softshell_taco = tacos.chicken_softshell_taco;
+    let chicken_street_taco = tacos.chicken_street_taco;
+    let chicken_super_burrito = burritos.chicken_super_burrito;
+    let chicken_super_nachos = specialties.chicken_super_nachos;
+    let chicken_taco_bravo = tacos.chicken_taco_bravo;
+    let chicken_taco_burger = tacos.chicken_taco_burger;
+    let chipotle_lime = ingredients.chipotle_lime;
+    let citrus_chipotle_fried_chicken_softshell_taco = tacos.citrus_chipotle_fried_chicken_softshell_taco;
+    let coffee = drinks.coffee;
+    let combination_burrito = burritos.combination_burrito;
+    let fried_chicken = proteins.fried_chicken;
+    let fried_chicken_burrito = burritos.fried_chicken_burrito;
+    let fried_chicken_grilled_burrito = burritos.fried_chicken_grilled_burrito;
+    let fried_chicken_meat_potato_burrito = burritos.fried_chicken_meat_potato_burrito;
+    let jalapeno_ranch = ingredients.jalapeno_ranch;
+    let kids_beverages = drinks.kids_beverages.clone();
+    let large_tortilla = ingredients.large_tortilla;
+    let lettuce = ingredients.lettuce;
+    let mexican_donut_bites = specialties.mexican_donut_bites;
+    let mild_sauce = sauces.mild_sauce;
+    let onions = ingredients.onions;
+    let potato_ole_side = sides.potato_ole_side;
+    let quesadilla = specialties.quesadilla;
+    let ranch = ingredients.ranch;
+    let salsa = ingredients.salsa;
+    let saus_and_bacon_meat_potato_burrito = burritos.saus_and_bacon_meat_potato_burrito;
+    let sausage_and_egg_burrito = breakfast.sausage_and_egg_burrito;
+    let sausage_junior_breakfast_burrito = breakfast.sausage_junior_breakfast_burrito;
+    let sausage_potato_breakfast_burrito = breakfast.sausage_potato_breakfast_burrito;
+    let sausage_scrambler_burrito = breakfast.sausage_scrambler_burrito;
+    let scrambled_eggs = ingredients.scrambled_eggs;
+    let side_ranch = ingredients.side_ranch;
+    let snack_cheese_quesadilla = specialties.snack_cheese_quesadilla;
+    let spicy_chorizo_breakfast_burrito = breakfast.spicy_chorizo_breakfast_burrito;
+    let spicy_jalapeno_ranch_fried_chicken_taco = tacos.spicy_jalapeno_ranch_fried_chicken_taco;
+    let steak_and_egg_burrito = breakfast.steak_and_egg_burrito;
+    let steak_boss_bowl = burritos.steak_boss_bowl;
+    let steak_boss_burrito = burritos.steak_boss_burrito;
+    let steak_burrito = burritos.steak_burrito;
+    let steak_crispy_taco = tacos.steak_crispy_taco;
+    let steak_grilled_burrito = burritos.steak_grilled_burrito;
+    let steak_junior_breakfast_burrito = breakfast.steak_junior_breakfast_burrito;
+    let steak_meat_potato_burrito = burritos.steak_meat_potato_burrito;
+    let steak_potato_breakfast_burrito = breakfast.steak_potato_breakfast_burrito;
+    let steak_quesadilla = specialties.steak_quesadilla;
+    let steak_scrambler_burrito = breakfast.steak_scrambler_burrito;
+    let steak_softshell_taco = tacos.steak_softshell_taco;
+    let steak_street_taco = tacos.steak_street_taco;
+    let steak_stuffed_grilled_taco = tacos.steak_stuffed_grilled_taco;
+    let steak_super_burrito = burritos.steak_super_burrito;
+    let steak_super_nachos = specialties.steak_super_nachos;
+    let steak_taco_bravo = tacos.steak_taco_bravo;
+    let steak_taco_burger = tacos.steak_taco_burger;
+    let stuffed_grilled_chipotle_chicken_taco = tacos.stuffed_grilled_chipotle_chicken_taco;
+    let taco_proteins = ingredients.taco_proteins.clone();
+    let tomato = ingredients.tomato;
+    let triple_meat_potato_breakfast_burrito = breakfast.triple_meat_potato_breakfast_burrito;
+    let drinks = drinks.drinks.clone();
+    let sides = sides.sides.clone();
+
+    let combo_size_variations = vec![
+        VariationBuilder::new("Small", "SM").build(),
+        VariationBuilder::new("Medium", "MD").build(),
+        VariationBuilder::new("Large", "LG").build(),
+    ];
+
+    let kids_meal_size_variations =
+        vec![VariationBuilder::new("Kid", "64").prefix("KD").build()];
+
+    let med_large_variations = vec![
+        VariationBuilder::new("Medium", "128").prefix("MD").build(),
+        VariationBuilder::new("Large", "256").prefix("LG").build(),
+    ];
+
+    let kids_sides = SlotBuilder::new("Sides", SlotType::Replace, Selection::AnyItem(&[potato_ole_side]))
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::SIDES]).price(0).variation("Kid"))
+        .defaults(&[potato_ole_side]);
+
+    let boss_bowl_shell_item = reg.add_item(
+        ItemBuilder::new("7e40f41f-6b67-4ed7-bdc0-df06383ef14c", "Boss Bowl", "B-BWL")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::SPECIALTIES])
+            .priority(6)
+            .slot(
+                SlotBuilder::new("Boss Bowl", SlotType::ItemShell, Selection::AnyItem(&[beef_boss_bowl, chicken_boss_bowl, steak_boss_bowl]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_boss_bowl])
+            )
+    );
+
+    let burrito_shell_item = reg.add_item(
+        ItemBuilder::new("7f5e5082-75aa-4522-93c0-8f0319fbd5bb", "Burrito", "BUR")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BURRITO])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Burrito", SlotType::ItemShell, Selection::AnyItem(&[beef_burrito, chicken_burrito, fried_chicken_burrito, steak_burrito, bean_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_burrito])
+            )
+    );
+
+    let meat_and_egg_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("7fad5929-18e8-4486-ac99-3a74de3460dd", "Meat and Egg Burrito", "ME-BUR")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BREAKFAST])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Meat and Egg Burrito", SlotType::ItemShell, Selection::AnyItem(&[bacon_and_egg_burrito, sausage_and_egg_burrito, steak_and_egg_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[bacon_and_egg_burrito])
+            )
+    );
+
+    let boss_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("e872b650-6bb5-4135-8406-180afef31bd5", "Boss Burrito", "BUR-BOSS")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BURRITO])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Boss Burrito", SlotType::ItemShell, Selection::AnyItem(&[beef_boss_burrito, chicken_boss_burrito, steak_boss_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_boss_burrito])
+            )
+    );
+
+    let combination_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("e887ef09-27d1-46c0-86de-1809f0401f8f", "Combination Burrito", "BUR-CB")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BURRITO])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Combination Burrito", SlotType::ItemShell, Selection::Item(combination_burrito))
+                    .minimum_quantity(1)
+                    .defaults(&[combination_burrito])
+            )
+    );
+
+    let grilled_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("80212043-75f0-49dc-8a20-0a8cc876cdbe", "Grilled Burrito", "BUR-GRLD")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BURRITO])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Grilled Burrito", SlotType::ItemShell, Selection::AnyItem(&[beef_grilled_burrito, chicken_grilled_burrito, steak_grilled_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_grilled_burrito])
+            )
+    );
+
+    let meat_and_potato_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("80bfb7b8-b52f-46a1-82bf-d31aa6072103", "Meat and Potato Burrito", "MPB")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BURRITO, tags::QUICK])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Meat and Potato Burrito", SlotType::ItemShell, Selection::AnyItem(&[beef_meat_potato_burrito, chicken_meat_potato_burrito, fried_chicken_meat_potato_burrito, steak_meat_potato_burrito, bean_meat_potato_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_meat_potato_burrito])
+            )
+    );
+
+    let super_burrito_shell_item = reg.add_item(
+        ItemBuilder::new("8127e5d7-2836-4018-bf4c-7af1bb241d36", "Super Burrito", "BUR-SUP")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BURRITO])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Super Burrito", SlotType::ItemShell, Selection::AnyItem(&[beef_super_burrito, chicken_super_burrito, steak_super_burrito, bean_super_burrito]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_super_burrito])
+            )
+    );
+
+    let quesadilla_shell_item = reg.add_item(
+        ItemBuilder::new("8172e1ca-4bb5-4448-a914-61657b380ab5", "Quesadilla", "QUES")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::QUICK, tags::SPECIALTIES])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Quesadilla", SlotType::ItemShell, Selection::AnyItem(&[quesadilla, beef_quesadilla, chicken_quesadilla, steak_quesadilla]))
+                    .minimum_quantity(1)
+                    .defaults(&[quesadilla])
+            )
+    );
+
+    let super_nachos_shell_item = reg.add_item(
+        ItemBuilder::new("81eff678-8194-4009-83f3-304f75b63e00", "Super Nachos", "SN")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::SPECIALTIES])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Super Nachos", SlotType::ItemShell, Selection::AnyItem(&[beef_super_nachos, chicken_super_nachos, steak_super_nachos]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_super_nachos])
+            )
+    );
+
+    let fried_chicken_taco_shell_item = reg.add_item(
+        ItemBuilder::new("47566a7a-3ee0-42f6-9ae0-cc93c1434c75", "Fried Chicken Taco", "T-FC")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Fried Chicken Taco", SlotType::ItemShell, Selection::AnyItem(&[citrus_chipotle_fried_chicken_softshell_taco, spicy_jalapeno_ranch_fried_chicken_taco]))
+                    .minimum_quantity(1)
+                    .defaults(&[citrus_chipotle_fried_chicken_softshell_taco])
+            )
+    );
+
+    let taco_bravo_shell_item = reg.add_item(
+        ItemBuilder::new("f2173e5d-a601-43f0-84e5-3d7c0325958c", "Taco Bravo", "T-BRG")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS, tags::QUICK])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Taco Bravo", SlotType::ItemShell, Selection::AnyItem(&[beef_taco_bravo, chicken_taco_bravo, steak_taco_bravo]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_taco_bravo])
+            )
+    );
+
+    let taco_burger_shell_item = reg.add_item(
+        ItemBuilder::new("832c8ca1-69be-4355-b2a2-07eec12be90c", "Taco Burger", "T-BUR")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Taco Burger", SlotType::ItemShell, Selection::AnyItem(&[beef_taco_burger, chicken_taco_burger, steak_taco_burger]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_taco_burger])
+            )
+    );
+
+    let taco_shell_item = reg.add_item(
+        ItemBuilder::new("8504a103-e3dc-4085-af7b-107b210bb94b", "Crispy Taco", "Taco")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS, tags::QUICK])
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Taco", SlotType::ItemShell, Selection::AnyItem(&[beef_crispy_taco, chicken_crispy_taco, steak_crispy_taco, bean_crispy_taco]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_crispy_taco])
+            )
+    );
+
+    let softshell_shell_item = reg.add_item(
+        ItemBuilder::new("85715cc6-74db-4194-9855-4f3b0b888ee3", "Softshell", "SS")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS, tags::QUICK])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Softshell", SlotType::ItemShell, Selection::AnyItem(&[beef_softshell_taco, chicken_softshell_taco, steak_softshell_taco, bean_softshell_taco]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_softshell_taco])
+            )
+    );
+
+    let _crispy_taco_combo = reg.add_item(
+        ItemBuilder::new("85cbfd7e-28c5-4f54-a03f-3d047047d74f", "2 Crispy Taco Combo", "#1")
+            .label("#1")
+            .price(720).plu("1000")
+            .priority(3)
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::QUICK, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Items, Selection::AnyItem(&[beef_crispy_taco, chicken_crispy_taco, steak_crispy_taco, bean_crispy_taco]))
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_crispy_taco, bean_crispy_taco]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_crispy_taco]).price(29))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_crispy_taco]).price(54))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _super_burrito_combo = reg.add_item(
+        ItemBuilder::new("e9715ae8-012c-4221-8995-18084c5425f6", "Super Burrito Combo", "#2")
+            .label("#2")
+            .price(739).plu("1100")
+            .priority(3)
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_super_burrito, chicken_super_burrito, steak_super_burrito, bean_super_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_super_burrito, bean_super_burrito]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_super_burrito]).price(10))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_super_burrito]).price(108))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _boss_burrito_combo = reg.add_item(
+        ItemBuilder::new("e9fa5ab7-5f38-4bab-910c-afaed91764d3", "Boss Burrito Combo", "Boss Burr Cmb")
+            .label("#10")
+            .price(999).plu("12400")
+            .priority(2)
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[chicken_boss_burrito, steak_boss_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[steak_boss_burrito])
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_boss_burrito]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_boss_burrito]).price(50))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _fried_chicken_combo = reg.add_item(
+        ItemBuilder::new("69aec63e-ebdb-476d-8857-dbbdf0c518f9", "Fried Chicken Combo", "#11")
+            .label("#11")
+            .price(799).plu("12580")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .priority(2)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Items, Selection::AnyItem(&[citrus_chipotle_fried_chicken_softshell_taco, spicy_jalapeno_ranch_fried_chicken_taco]))
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    // .defaults(&[citrus_chipotle_fried_chicken_softshell_taco])
+                    .price_override(PriceOverrideBuilder::new().items(&[citrus_chipotle_fried_chicken_softshell_taco, spicy_jalapeno_ranch_fried_chicken_taco]).price(0))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _two_softshells_combo = reg.add_item(
+        ItemBuilder::new("85f6310e-aed5-4773-ae86-9e63d652ea29", "2 SS Taco Combo", "#3")
+            .label("#3")
+            .price(765).plu("1200")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::QUICK, tags::BUNDLE])
+            .priority(3)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Items, Selection::AnyItem(&[beef_softshell_taco, chicken_softshell_taco, steak_softshell_taco, bean_softshell_taco]))
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_softshell_taco, bean_softshell_taco]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_softshell_taco]).price(37))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_softshell_taco]).price(134))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _taco_bravo_combo = reg.add_item(
+        ItemBuilder::new("ebcb411a-a698-4e46-aea5-2295086f8fd1", "Taco Bravo Combo", "#4")
+            .label("#4")
+            .price(679).plu("1300")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .priority(3)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_taco_bravo, steak_taco_bravo, chicken_taco_bravo, bean_taco_bravo]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[beef_taco_bravo])
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_taco_bravo, bean_taco_bravo]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_taco_bravo]).price(20))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_taco_bravo]).price(50))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _kids_meal = reg.add_item(
+        ItemBuilder::new("ec359191-3352-4c02-91fd-bd5845f13f5e", "Kids Meal Crispy Taco", "KM-TACO")
+            .price(479).plu("1900")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::KIDS_MEAL])
+            .priority(6)
+            .variations(&kids_meal_size_variations)
+            .default_variation(&kids_meal_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_crispy_taco, steak_crispy_taco, chicken_crispy_taco]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[beef_crispy_taco])
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+            )
+            .slot(kids_sides.clone())
+            .slot(kids_beverages.clone())
+    );
+
+    let _kids_meal_soft = reg.add_item(
+        ItemBuilder::new("ec7be9fd-bd29-4951-a689-f81d7bb5f98a", "Kids Meal Soft Taco", "KM-SS")
+            .price(479).plu("1910")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::KIDS_MEAL])
+            .priority(5)
+            .variations(&kids_meal_size_variations)
+            .default_variation(&kids_meal_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_softshell_taco, steak_softshell_taco, chicken_softshell_taco, bean_softshell_taco, citrus_chipotle_fried_chicken_softshell_taco]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[beef_softshell_taco])
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+            )
+            .slot(kids_sides.clone())
+            .slot(kids_beverages.clone())
+    );
+
+    let _kids_meal_burr = reg.add_item(
+        ItemBuilder::new("ee77484c-f3da-494f-8347-a26ae6e7ed46", "Kids Meal Burrito", "KM-BUR")
+            .price(479).plu("1920")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::KIDS_MEAL])
+            .priority(3)
+            .variations(&kids_meal_size_variations)
+            .default_variation(&kids_meal_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_burrito, steak_burrito, chicken_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[beef_burrito])
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::BURRITO]).price(0))
+            )
+            .slot(kids_sides.clone())
+            .slot(kids_beverages.clone())
+    );
+
+    let _kids_meal_taco_burger = reg.add_item(
+        ItemBuilder::new("eecc8f3c-51e8-45ca-ab48-b337c18c2d96", "Kids Meal Taco Burger", "KM-TBURG")
+            .price(529).plu("1930")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::KIDS_MEAL])
+            .priority(1)
+            .variations(&kids_meal_size_variations)
+            .default_variation(&kids_meal_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_taco_burger, chicken_taco_burger, steak_taco_burger]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[beef_taco_burger])
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_taco_burger, chicken_taco_burger, steak_taco_burger]).price(0))
+            )
+            .slot(kids_sides.clone())
+            .slot(kids_beverages.clone())
+    );
+
+    let _kids_meal_fried_chicken = reg.add_item(
+        ItemBuilder::new("f016131a-5af2-48a5-b9be-605d8094c8cd", "Kids Meal Fried Chicken", "KM-FC")
+            .price(549).plu("12560")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::KIDS_MEAL])
+            .priority(4)
+            .variations(&kids_meal_size_variations)
+            .default_variation(&kids_meal_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[fried_chicken]))
+                    .collapsed(true)
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+                    .defaults(&[fried_chicken])
+            )
+            .slot(
+                SlotBuilder::new("Sauce", SlotType::Replace, Selection::AnyItem(&[ranch, jalapeno_ranch, chipotle_lime]))
+                    .maximum_quantity(1)
+                    .free_quantity(1)
+            )
+            .slot(kids_sides.clone())
+            .slot(kids_beverages.clone())
+    );
+
+    let _kids_meal_quesadilla = reg.add_item(
+        ItemBuilder::new("f052eabb-b797-440f-a323-1a379ae74277", "Kids Meal Quesadilla", "KM-QUES")
+            .price(479).plu("1950")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::KIDS_MEAL])
+            .priority(2)
+            .variations(&kids_meal_size_variations)
+            .default_variation(&kids_meal_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[snack_cheese_quesadilla]))
+                    .collapsed(true)
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[snack_cheese_quesadilla])
+            )
+            .slot(kids_sides.clone())
+            .slot(kids_beverages.clone())
+    );
+
+    let _meat_potato_burrito_combo = reg.add_item(
+        ItemBuilder::new("f076d8f1-7a05-4417-807d-a2366f7cb163", "M&P Burrito Combo", "#5")
+            .label("#5")
+            .price(755).plu("1400")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::QUICK, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(3)
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_meat_potato_burrito, chicken_meat_potato_burrito, fried_chicken_meat_potato_burrito, steak_meat_potato_burrito, bean_meat_potato_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_meat_potato_burrito]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[bean_meat_potato_burrito]).price(-36))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_meat_potato_burrito]).price(84))
+                    .price_override(PriceOverrideBuilder::new().items(&[fried_chicken_meat_potato_burrito]).price(44))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_meat_potato_burrito]).price(144))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _grilled_burrito_combo = reg.add_item(
+        ItemBuilder::new("f173cc55-589f-4d66-bfd7-f6152e232ae9", "Grilled Burrito Combo", "#6")
+            .label("#6")
+            .price(815).plu("1500")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(3)
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[chicken_grilled_burrito, fried_chicken_grilled_burrito, beef_grilled_burrito, steak_grilled_burrito, bean_grilled_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[chicken_grilled_burrito])
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_grilled_burrito]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[bean_grilled_burrito]).price(-76))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_grilled_burrito]).price(44))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_grilled_burrito, fried_chicken_grilled_burrito]).price(24))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _street_taco_trio_combo = reg.add_item(
+        ItemBuilder::new("f2cb379d-c138-4320-a104-49baa08d4dc2", "Street Taco Trio Combo", "#7")
+            .label("#7")
+            .price(979).plu("6920")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .priority(3)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Tacos", SlotType::Items, Selection::AnyItem(&[steak_street_taco, chicken_street_taco]))
+                    .minimum_quantity(3)
+                    .maximum_quantity(3)
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_street_taco]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_street_taco]).price(30))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _meat_and_potato_burrito_and_softshell_combo = reg.add_item(
+        ItemBuilder::new("f2dca75d-0b8c-487a-908e-2344410b3526", "M&P & SS Taco Combo", "#8")
+            .label("#8")
+            .price(935).plu("1700")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(3)
+            .slot(
+                SlotBuilder::new("Burrito", SlotType::Replace, Selection::AnyItem(&[beef_meat_potato_burrito, chicken_meat_potato_burrito, steak_meat_potato_burrito, fried_chicken_meat_potato_burrito, bean_meat_potato_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_meat_potato_burrito, bean_meat_potato_burrito, fried_chicken_meat_potato_burrito]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_meat_potato_burrito]).price(50))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_meat_potato_burrito]).price(100))
+            )
+            .slot(
+                SlotBuilder::new("Taco", SlotType::Replace, Selection::AnyItem(&[beef_crispy_taco, beef_softshell_taco, chicken_crispy_taco, chicken_softshell_taco, steak_crispy_taco, steak_softshell_taco, bean_crispy_taco, bean_softshell_taco]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_crispy_taco, beef_softshell_taco, bean_crispy_taco, bean_softshell_taco]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_crispy_taco, steak_softshell_taco]).price(100))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_crispy_taco, chicken_softshell_taco]).price(50))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _quesadilla_combo = reg.add_item(
+        ItemBuilder::new("f2fce470-d3dc-4af8-b2fb-4c341f1a6b40", "Quesadilla Combo", "Ques Cmb")
+            .price(688).plu("1600")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[quesadilla, steak_quesadilla, beef_quesadilla, chicken_quesadilla]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[quesadilla, steak_quesadilla, beef_quesadilla, chicken_quesadilla]).price(0))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _stuffed_grilled_taco_combo = reg.add_item(
+        ItemBuilder::new("f3f079fb-8475-4de7-9ed7-7688de6a9110", "Stuffed Grilled Taco Combo", "#9")
+            .label("#9")
+            .price(719).plu("2970")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(3)
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_stuffed_grilled_taco, stuffed_grilled_chipotle_chicken_taco, steak_stuffed_grilled_taco, bean_stuffed_grilled_taco]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_stuffed_grilled_taco]).price(96))
+                    .price_override(PriceOverrideBuilder::new().items(&[stuffed_grilled_chipotle_chicken_taco]).price(10))
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_stuffed_grilled_taco, bean_stuffed_grilled_taco]).price(0))
+                    .defaults(&[beef_stuffed_grilled_taco])
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _ranch_burrito = reg.add_item(
+        ItemBuilder::new("f4951f8f-e959-4e24-a56e-245651dc22fe", "Ranch Burrito", "Rch Burr")
+            .price(419).plu("3900")
+            .tags(&[tags::LUNCH, tags::DINNER, tags::BURRITO])
+            .slot(taco_proteins.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[cheddar_cheese, mild_sauce, large_tortilla, lettuce, onions, tomato, side_ranch]))
+                    .collapsed(false)
+                    .defaults(&[cheddar_cheese, mild_sauce, large_tortilla, lettuce, onions, tomato, side_ranch])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, mild_sauce, large_tortilla, lettuce, onions, tomato, side_ranch], &add_ons_vec))
+    );
+
+    let _egg_burrito = reg.add_item(
+        ItemBuilder::new("f4f87c59-4543-47a2-b96b-3126d3011d26", "Egg Burrito", "Egg Burr")
+            .price(299).plu("4000")
+            .tags(&[tags::BREAKFAST])
+            .priority(15)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[cheddar_cheese, salsa, large_tortilla, scrambled_eggs]))
+                    .collapsed(false)
+                    .defaults(&[cheddar_cheese, salsa, large_tortilla, scrambled_eggs])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, salsa, large_tortilla, scrambled_eggs], &add_ons_vec))
+    );
+
+    let family_pack = reg.add_item(
+        ItemBuilder::new("b2181628-9a77-4070-a3ae-5a804bab1ab7", "Family Pack", "Family Pack")
+            .price(1600).plu("10800")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS, tags::BUNDLE])
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Tacos", SlotType::Items, Selection::AnyItem(&[beef_softshell_taco, beef_crispy_taco]))
+                    .collapsed(false)
+                    .minimum_quantity(4)
+                    .maximum_quantity(4)
+                    .default_quantity(4)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_softshell_taco, beef_crispy_taco]).price(0))
+            )
+            .slot(
+                SlotBuilder::new("Burritos", SlotType::Items, Selection::AnyItem(&[beef_meat_potato_burrito]))
+                    .collapsed(false)
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_meat_potato_burrito]).price(0))
+            )
+            .slot(
+                SlotBuilder::new("Side", SlotType::Items, Selection::AnyItem(&[potato_ole_side, potato_ole_side]))
+                    .collapsed(true)
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+                    .defaults(&[potato_ole_side; 2])
+            )
+    );
+
+    let six_pack_and_pound_combo_mix = reg.add_item(
+        ItemBuilder::new("f516cb4a-4403-4090-9cca-28eafe384044", "Six Pack Mixed", "Six Mix")
+            .price(1399).plu("3300")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BUNDLE])
+            .priority(2)
+            .variations(&med_large_variations)
+            .default_variation(&med_large_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Items, Selection::AnyItem(&[beef_softshell_taco, beef_crispy_taco, chicken_crispy_taco, chicken_softshell_taco, steak_crispy_taco, steak_softshell_taco]))
+                    .collapsed(false)
+                    .minimum_quantity(6)
+                    .maximum_quantity(6)
+                    .default_quantity(6)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_crispy_taco, bean_crispy_taco, beef_softshell_taco, bean_softshell_taco]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[chicken_crispy_taco, chicken_softshell_taco]).price(30))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_crispy_taco, steak_softshell_taco]).price(55))
+            )
+            .slot(
+                SlotBuilder::new("Side", SlotType::Items, Selection::AnyItem(&[potato_ole_side, potato_ole_side]))
+                    .collapsed(true)
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+                    .defaults(&[potato_ole_side; 2])
+            )
+    );
+
+    let six_pack_and_pound_combo_crispy = reg.add_item(
+        ItemBuilder::new("f6171f2b-03c6-4c9e-9b88-3e01d968b309", "Six Pack Crispy", "Six Crispy")
+            .price(1399).plu("3290")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BUNDLE])
+            .priority(2)
+            .variations(&med_large_variations)
+            .default_variation(&med_large_variations[0])
+            .slot(
+                // Selection::AnyItem(&[beef_crispy_taco, beef_crispy_taco, beef_crispy_taco, beef_crispy_taco, beef_crispy_taco, beef_crispy_taco])
+                SlotBuilder::new("Entree", SlotType::Items, Selection::AnyItem(&[beef_crispy_taco, chicken_crispy_taco, steak_crispy_taco]))
+                    .collapsed(false)
+                    .minimum_quantity(6)
+                    .maximum_quantity(6)
+                    .default_quantity(6)
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+                    .defaults(&[beef_crispy_taco; 6])
+            )
+            .slot(
+                SlotBuilder::new("Side", SlotType::Items, Selection::AnyItem(&[potato_ole_side, potato_ole_side]))
+                    .collapsed(true)
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    .defaults(&[potato_ole_side; 2])
+            )
+    );
+
+    let six_pack_and_pound_combo_soft = reg.add_item(
+        ItemBuilder::new("f6b37992-ff7e-4bde-a106-69456e69af7e", "Six Pack Soft", "Six Soft")
+            .price(1399).plu("3280")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::BUNDLE])
+            .priority(2)
+            .variations(&med_large_variations)
+            .default_variation(&med_large_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Items, Selection::AnyItem(&[beef_softshell_taco, chicken_softshell_taco, steak_softshell_taco]))
+                    .collapsed(false)
+                    .minimum_quantity(6)
+                    .maximum_quantity(6)
+                    .default_quantity(6)
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+                    .defaults(&[beef_softshell_taco; 6])
+            )
+            .slot(
+                SlotBuilder::new("Side", SlotType::Items, Selection::AnyItem(&[potato_ole_side, potato_ole_side]))
+                    .collapsed(true)
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+                    .defaults(&[potato_ole_side; 2])
+            )
+    );
+
+    let six_pack_shell_item = reg.add_item(
+        ItemBuilder::new("860dd6a9-608c-4a5b-815a-c88abdd77038", "Six Pack", "Six Pack")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::COMBOS])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Style", SlotType::ItemShell, Selection::AnyItem(&[six_pack_and_pound_combo_crispy, six_pack_and_pound_combo_soft, six_pack_and_pound_combo_mix]))
+                    .minimum_quantity(1)
+            )
+    );
+
+    let _meat_potato_breakfast_burrito_combo = reg.add_item(
+        ItemBuilder::new("f6d0260d-d899-46d2-b359-0e6b85b5dd5f", "M&P Breakfast Burrito Combo", "B1-MPB")
+            .label("#B1")
+            .price(639).plu("3100")
+            .tags(&[tags::BREAKFAST_COMBO, tags::QUICK, tags::BUNDLE])
+            .priority(100)
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[beef_potato_breakfast_burrito, bacon_potato_breakfast_burrito, sausage_potato_breakfast_burrito, steak_potato_breakfast_burrito, saus_and_bacon_meat_potato_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[beef_potato_breakfast_burrito, bacon_potato_breakfast_burrito, sausage_potato_breakfast_burrito, saus_and_bacon_meat_potato_burrito]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_potato_breakfast_burrito]).price(126))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _egg_burrito_combo = reg.add_item(
+        ItemBuilder::new("f87a8da8-cb90-4602-944d-67b9fb4f8ab4", "Egg Breakfast Burrito Combo", "Egg Bk Br Cmb")
+            .price(499).plu("4260")
+            .tags(&[tags::BREAKFAST_COMBO, tags::BUNDLE])
+            .priority(90)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::Item(_egg_burrito))
+                    .collapsed(true)
+                    .defaults(&[_egg_burrito])
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[_egg_burrito]).price(0))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _meat_egg_burrito_combo = reg.add_item(
+        ItemBuilder::new("f892c1a5-901b-4a6a-bc9d-5be763e53aa5", "Meat Egg Breakfast Burrito Combo", "Mt Egg Bk Br Cmb")
+            .price(529).plu("4120")
+            .tags(&[tags::BREAKFAST_COMBO, tags::BUNDLE])
+            .priority(89)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[bacon_and_egg_burrito, sausage_and_egg_burrito, steak_and_egg_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[bacon_and_egg_burrito, sausage_and_egg_burrito, steak_and_egg_burrito]).price(0))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _triple_meat_potato_breakfast_burrito_combo = reg.add_item(
+        ItemBuilder::new("f8c664c7-12e9-4e0c-ad1b-e7d4223ff6bf", "Triple M&P Breakfast Burrito Combo", "Trp M&P Bk Br Cmb")
+            .price(699).plu("6440")
+            .tags(&[tags::BREAKFAST_COMBO, tags::BUNDLE])
+            .priority(88)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::Item(triple_meat_potato_breakfast_burrito))
+                    .collapsed(false)
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .defaults(&[triple_meat_potato_breakfast_burrito])
+                    .price_override(PriceOverrideBuilder::new().items(&[triple_meat_potato_breakfast_burrito]).price(0))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _junior_breakfast_burrito_combo = reg.add_item(
+        ItemBuilder::new("faa88261-36f3-40b0-870b-78a739a56049", "Jr. Breakfast Burrito Combo", "B2-JBUR")
+            .label("#B2")
+            .price(679).plu("3110")
+            .tags(&[tags::BREAKFAST_COMBO, tags::BUNDLE])
+            .priority(99)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Items, Selection::AnyItem(&[steak_junior_breakfast_burrito, bacon_junior_breakfast_burrito, sausage_junior_breakfast_burrito]))
+                    .minimum_quantity(2)
+                    .maximum_quantity(2)
+                    .default_quantity(2)
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_junior_breakfast_burrito, bacon_junior_breakfast_burrito, sausage_junior_breakfast_burrito]).price(0))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _scrambler_burrito_combo = reg.add_item(
+        ItemBuilder::new("fb689474-4a8c-40f9-baf9-96f1c951d248", "Scrambler Burrito Combo", "B3-SCRM")
+            .label("#B3")
+            .price(699).plu("3120")
+            .tags(&[tags::BREAKFAST_COMBO, tags::BUNDLE])
+            .priority(98)
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::AnyItem(&[bacon_scrambler_burrito, sausage_scrambler_burrito, steak_scrambler_burrito]))
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[bacon_scrambler_burrito, sausage_scrambler_burrito]).price(0))
+                    .price_override(PriceOverrideBuilder::new().items(&[steak_scrambler_burrito]).price(100))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _spicy_chorizo_breakfast_burrito_combo = reg.add_item(
+        ItemBuilder::new("fb88463d-5754-4533-910d-590b6757c5ba", "Spicy Chorizo Burrito Combo", "B4-SPCY")
+            .label("#B4")
+            .price(635).plu("4290")
+            .tags(&[tags::BREAKFAST_COMBO, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(97)
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::Item(spicy_chorizo_breakfast_burrito))
+                    .collapsed(true)
+                    .defaults(&[spicy_chorizo_breakfast_burrito])
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[spicy_chorizo_breakfast_burrito]).price(0))
+            )
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _make_it_combo_breakfast = reg.add_item(
+        ItemBuilder::new("f876aab8-49bb-490c-a958-40a49242e6b4", "Breakfast Combo", "Breakfast Combo")
+            .price(432).plu("4500")
+            .tags(&[tags::BREAKFAST_COMBO, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(96)
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _make_it_combo = reg.add_item(
+        ItemBuilder::new("fdd3d419-dbcd-45a4-86ee-9e51c33bf51b", "Make It A Combo", "MAKIT-CMB")
+            .price(432).plu("1800")
+            .tags(&[tags::COMBOS, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(2)
+            .slot(sides.clone())
+            .slot(drinks.clone())
+    );
+
+    let _coffee_and_donut = reg.add_item(
+        ItemBuilder::new("bce1e4fc-2f74-4bf1-a56b-80eb27ae35fc", "Coffee And Donut", "Cfe Dnt")
+            .price(319).plu("4300")
+            .tags(&[tags::BREAKFAST_COMBO, tags::BUNDLE])
+            .variations(&combo_size_variations)
+            .default_variation(&combo_size_variations[0])
+            .priority(87)
+            .slot(
+                SlotBuilder::new("Entree", SlotType::Replace, Selection::Item(mexican_donut_bites))
+                    .collapsed(true)
+                    .defaults(&[mexican_donut_bites])
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+                    .default_quantity(1)
+                    .price_override(PriceOverrideBuilder::new().items(&[mexican_donut_bites]).price(0))
+            )
+            .slot(
+                SlotBuilder::new("Drinks", SlotType::Replace, Selection::AnyTag(&[tags::DRINKS]))
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::SOFTDRINK, tags::PREMIUMDRINK]).variation("Small"))
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::SOFTDRINK, tags::PREMIUMDRINK]).variation("Medium").price(49))
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::SOFTDRINK, tags::PREMIUMDRINK]).variation("Large").price(99))
+                    .defaults(&[coffee])
+                    .hidden(Selection::AnyTag(&[tags::PREMIUMDRINK]))
+                    .default_quantity(1)
+                    .minimum_quantity(1)
+            )
+    );
+
+    Combos {
+        boss_bowl_shell_item,
+        boss_burrito_shell_item,
+        fried_chicken_taco_shell_item,
+        kids_meal: _kids_meal,
+        kids_meal_burr: _kids_meal_burr,
+        kids_meal_fried_chicken: _kids_meal_fried_chicken,
+        kids_meal_quesadilla: _kids_meal_quesadilla,
+        kids_meal_soft: _kids_meal_soft,
+        kids_meal_taco_burger: _kids_meal_taco_burger,
+        meat_and_potato_burrito_shell_item,
+        six_pack_and_pound_combo_crispy,
+        six_pack_and_pound_combo_mix,
+        six_pack_and_pound_combo_soft,
+        six_pack_shell_item,
+        softshell_shell_item,
+        super_nachos_shell_item,
+        taco_shell_item,
+    }
+}
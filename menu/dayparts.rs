@@ -0,0 +1,47 @@
+use super::Registry;
+use crate::builder::{tags, Selection};
+use crate::dayparts::Daypart;
+use chrono::Weekday;
+
+pub fn register(reg: &mut Registry) {
+    // Dayparts, in seconds since midnight
+    let every_day = vec![
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+    reg.daypart(Daypart {
+        name: "breakfast".into(),
+        days: every_day.clone(),
+        start_time: 18000,
+        stop_time: 37800,
+    });
+    reg.daypart(Daypart {
+        name: "lunch".into(),
+        days: every_day.clone(),
+        start_time: 37800,
+        stop_time: 57600,
+    });
+    reg.daypart(Daypart {
+        name: "dinner".into(),
+        days: every_day.clone(),
+        start_time: 57600,
+        stop_time: 79200,
+    });
+    reg.daypart(Daypart {
+        name: "late_night".into(),
+        days: every_day,
+        start_time: 79200,
+        stop_time: 7200,
+    });
+
+    reg.limit_items_to_daypart(
+        "breakfast",
+        Selection::AnyTag(&[tags::BREAKFAST, tags::BREAKFAST_COMBO]),
+    );
+    reg.limit_category_to_daypart("Breakfast", "breakfast");
+}
@@ -0,0 +1,430 @@
+use crate::builder::Selection;
+use super::Registry;
+use super::breakfast::Breakfast;
+use super::burritos::Burritos;
+use super::combos::Combos;
+use super::sides::Sides;
+use super::specialties::Specialties;
+use super::tacos::Tacos;
+use adjunct::{DiscountAmount, ItemQuantityConstraint, OrderConstraint, OrderTotalConstraint};
+
+pub fn register(
+    reg: &mut Registry,
+    sides: &Sides,
+    specialties: &Specialties,
+    breakfast: &Breakfast,
+    burritos: &Burritos,
+    tacos: &Tacos,
+    combos: &Combos,
+) {
+    let bacon_and_egg_burrito = breakfast.bacon_and_egg_burrito;
+    let bean_burrito = burritos.bean_burrito;
+    let beef_crispy_taco = tacos.beef_crispy_taco;
+    let beef_softshell_taco = tacos.beef_softshell_taco;
+    let beef_super_nachos = specialties.beef_super_nachos;
+    let beef_taco_bravo = tacos.beef_taco_bravo;
+    let beef_taco_salad = specialties.beef_taco_salad;
+    let boss_bowl_shell_item = combos.boss_bowl_shell_item;
+    let boss_burrito_shell_item = combos.boss_burrito_shell_item;
+    let chicken_super_nachos = specialties.chicken_super_nachos;
+    let chicken_taco_salad = specialties.chicken_taco_salad;
+    let cinnamon_sugar_tortilla_crisps = specialties.cinnamon_sugar_tortilla_crisps;
+    let citrus_chipotle_fried_chicken_softshell_taco = tacos.citrus_chipotle_fried_chicken_softshell_taco;
+    let fried_chicken_potato_snacker = specialties.fried_chicken_potato_snacker;
+    let fried_chicken_taco_shell_item = combos.fried_chicken_taco_shell_item;
+    let kids_meal = combos.kids_meal;
+    let kids_meal_burr = combos.kids_meal_burr;
+    let kids_meal_fried_chicken = combos.kids_meal_fried_chicken;
+    let kids_meal_quesadilla = combos.kids_meal_quesadilla;
+    let kids_meal_soft = combos.kids_meal_soft;
+    let kids_meal_taco_burger = combos.kids_meal_taco_burger;
+    let meat_and_potato_breakfast_burrito_shell_item = burritos.meat_and_potato_breakfast_burrito_shell_item;
+    let meat_and_potato_burrito_shell_item = combos.meat_and_potato_burrito_shell_item;
+    let mexican_donut_bites = specialties.mexican_donut_bites;
+    let potato_griller_shell_item = breakfast.potato_griller_shell_item;
+    let potato_ole_side = sides.potato_ole_side;
+    let sausage_and_egg_burrito = breakfast.sausage_and_egg_burrito;
+    let six_pack_and_pound_combo_crispy = combos.six_pack_and_pound_combo_crispy;
+    let six_pack_and_pound_combo_mix = combos.six_pack_and_pound_combo_mix;
+    let six_pack_and_pound_combo_soft = combos.six_pack_and_pound_combo_soft;
+    let six_pack_shell_item = combos.six_pack_shell_item;
+    let softshell_shell_item = combos.softshell_shell_item;
+    let super_nachos_shell_item = combos.super_nachos_shell_item;
+    let taco_salad_shell_item = specialties.taco_salad_shell_item;
+    let taco_shell_item = combos.taco_shell_item;
+
+    // Discounts
+    let _10_percent = discount!(reg; name: "10% off", identifier: "1", amount: DiscountAmount::PercentOrder(10), single: true,  incombinable: true);
+    let _15_percent = discount!(reg; name: "15% off", identifier: "3", amount: DiscountAmount::PercentOrder(15), single: true,  incombinable: true);
+    let _20_percent = discount!(reg; name: "20% off", identifier: "5", amount: DiscountAmount::PercentOrder(20), single: true,  incombinable: true);
+    let _50_percent = discount!(reg; name: "50% off", identifier: "7", amount: DiscountAmount::PercentOrder(50), single: true,  incombinable: true, max_amount: 600);
+    let _50_percent_employee = discount!(reg; name: "50% Employee", identifier: "9", amount: DiscountAmount::PercentOrder(50), single: true,  incombinable: true);
+    let _50_percent_manager_assitant = discount!(reg; name: "50% Assistant Manager", identifier: "11", amount: DiscountAmount::PercentOrder(50), single: true,  incombinable: true);
+    let _100_percent_manager = discount!(reg; name: "100% Manager", identifier: "13", amount: DiscountAmount::PercentOrder(100), single: true,  incombinable: true);
+
+    let _two_dollars_survey = discount!(reg; name: "$2 off Survey", identifier: "29200", amount: DiscountAmount::Flat(200), single: true, incombinable: true);
+
+    let _five_dollars_off = discount!(reg; name: "74470 $5 off Purchase", identifier: "74470", amount: DiscountAmount::Flat(500), single: true, incombinable: true);
+
+    let _five_dollars_off_twenty_dollar_purchase = discount!(reg; name: "84706 $5 off $20 Purchase", identifier: "84706", amount: DiscountAmount::Flat(500), single: true, incombinable: true,
+    constraints: vec!(OrderConstraint::OrderTotal(OrderTotalConstraint{
+            minimum_amount: 2000,
+            maximum_amount: 10000
+    })));
+
+    let _five_dollars_off_fried_chicken = discount!(reg; name: "69400 $5 off Fried Chicken", identifier: "69400", amount: DiscountAmount::Flat(500), single: true, incombinable: true,
+    constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+        selection: Selection::AnyItem(&[fried_chicken_potato_snacker, citrus_chipotle_fried_chicken_softshell_taco]).into(),
+        minimum_quantity: 2,
+        maximum_quantity: 150
+    })));
+
+    let _one_dollar_ninetynine_cents_off_breakfast_burrito = discount!(reg; name: "71802 $1.99 off Breakfast Burrito", identifier: "71802", amount: DiscountAmount::Flat(199), single: true, incombinable: true,
+    constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+        selection: Selection::AnyItem(&[bacon_and_egg_burrito, sausage_and_egg_burrito]).into(),
+        minimum_quantity: 1,
+        maximum_quantity: 150
+    })));
+
+    let _free_small_soft_drink = discount!(reg; name: "72762 Free Small Soft Drink", identifier: "72762", amount: DiscountAmount::Flat(219), single: true, incombinable: true);
+
+    let _two_dollars_6pp = discount!(reg; name: "70402 $2 off Six Pack and a Pound", identifier: "70402", amount: DiscountAmount::Flat(200), single: true, incombinable: false,
+    constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+        selection: Selection::AnyItem(&[six_pack_and_pound_combo_mix, six_pack_and_pound_combo_crispy, six_pack_and_pound_combo_soft]).into(),
+        minimum_quantity: 1,
+        maximum_quantity: 150
+    })));
+
+    let _two_for_three = discount!(reg; name: "21820 2 Taco Bravo for $3", identifier: "21820", amount: DiscountAmount::Flat(258), single: true, incombinable: true, constraints: vec!(
+        OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::Item(beef_taco_bravo).into(),
+            minimum_quantity: 2,
+            maximum_quantity: 150
+        })
+    ));
+
+    let _discount_02 = discount!(reg; name: "620401 Breakfast Grillers/Cold Brew: $5 off", identifier: "62401", amount: DiscountAmount::Flat(500), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[potato_griller_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2021-01-28, end:2021-08-01
+
+    let _discount_07 = discount!(reg; name: "74350 $2 Off Beef or Chicken Taco Salad", identifier: "74350", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[beef_taco_salad, chicken_taco_salad]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-01-27, end:2021-08-29
+
+        let _discount_24303 = discount!(reg; name: "24303 $2 Off Taco Salad", identifier: "24303", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[taco_salad_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-01-27, end:2021-08-29
+
+    let _discount_12 = discount!(reg; name: "70502 Free Small Potato Oles", identifier: "70502", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[potato_ole_side]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-01-27, end:2021-08-29
+
+    // let _discount_13 = discount!(reg; name: "$1 Off Any Combo Meal", identifier: "71100", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+    //     constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+    //         selection: Selection::AnyItem(&[]).into(),
+    //         minimum_quantity: 1,
+    //         maximum_quantity: 150
+    //     }))); //start: 2018-03-01, end:2021-12-31
+
+    let _discount_16 = discount!(reg; name: "80602 $2 Off Taco Salad beef only", identifier: "80602", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[beef_taco_salad]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-04-20, end:2021-12-31
+
+    let _discount_23 = discount!(reg; name: "73361 $2 Off Boss Burrito or Bowl", identifier: "73361", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[boss_burrito_shell_item, boss_bowl_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-04-20, end:2021-12-31
+
+    // let _discount_24 = discount!(reg; name: "81400 $1 Off Any lunch Combo (before 10AM)", identifier: "81400", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+    //     constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+    //         selection: Selection::AnyItem(&[]).into(),
+    //         minimum_quantity: 1,
+    //         maximum_quantity: 150
+    //     }))); //start: 2016-08-22, end:2021-12-31
+
+    let _discount_25 = discount!(reg; name: "84300 $2 Off Any Super Nacho Beef Or Chicken Only", identifier: "84300", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[beef_super_nachos, chicken_super_nachos]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2019-07-02, end:2021-12-31
+
+    let _discount_29 = discount!(reg; name: "85700 Free Small Potato Oles", identifier: "85700", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[potato_ole_side]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2016-08-22, end:2021-12-31
+
+    let _discount_30 = discount!(reg; name: "87500 $2 Off SPAAP", identifier: "87500", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[six_pack_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2016-08-22, end:2021-12-31
+
+    let _discount_31 = discount!(reg; name: "89700 Free Crispy Beef Taco With Any Purchase", identifier: "89700", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[beef_crispy_taco]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2016-08-22, end:2021-12-31
+
+    let _discount_32 = discount!(reg; name: "89701 Free Beef Softshell Taco", identifier: "89701", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[beef_softshell_taco]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2018-06-28, end:2021-12-31
+
+    let _discount_33 = discount!(reg; name: "89711 Free Taco", identifier: "89711", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[taco_shell_item, softshell_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2018-06-28, end:2021-12-31
+
+    let _discount_34 = discount!(reg; name: "89712 (1) Free Fried Chicken Taco", identifier: "89712", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[fried_chicken_taco_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2016-08-22, end:2021-12-31
+
+    let _discount_35 = discount!(reg; name: "80500 $2 Off Next Purchase", identifier: "80500", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::OrderTotal(OrderTotalConstraint{
+            minimum_amount: 200,
+            maximum_amount: 10000
+    }))); //start: 2016-08-22, end:2021-12-31
+
+    let _discount_36 = discount!(reg; name: "81100 $1 Off Any M&P Breakfast Burrito", identifier: "81100", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[meat_and_potato_breakfast_burrito_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2019-08-01, end:2021-12-31
+
+    let _discount_38 = discount!(reg; name: "81700 Free Meat & Potato Burrito", identifier: "81700", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[meat_and_potato_burrito_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-07-10, end:2021-12-31
+
+    let _discount_39 = discount!(reg; name: "84101 $1 Off Super Nachos on Next Visit", identifier: "84101", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[super_nachos_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2016-08-22, end:2021-12-31
+
+    let _discount_40 = discount!(reg; name: "84701 Free Kids Meal", identifier: "84701", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[kids_meal, kids_meal_soft, kids_meal_burr, kids_meal_taco_burger, kids_meal_fried_chicken, kids_meal_quesadilla]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2016-08-22, end:2021-12-31
+
+    // let _discount_41 = discount!(reg; name: "84702 Free Combo Meal", identifier: "84702", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+    //     constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+    //         selection: Selection::AnyItem(&[]).into(),
+    //         minimum_quantity: 1,
+    //         maximum_quantity: 150
+    //     }))); //start: 2019-08-01, end:2021-12-31
+
+    // let _discount_42 = discount!(reg; name: "86700 Free Churro", identifier: "86700", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+    //     constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+    //         selection: Selection::AnyItem(&[]).into(),
+    //         minimum_quantity: 1,
+    //         maximum_quantity: 150
+    //     }))); //start: 2019-08-01, end:2021-12-31
+
+    let _discount_45 = discount!(reg; name: "89710 Free Crispy Beef Taco for Reading", identifier: "89710", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[beef_crispy_taco]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2016-08-22, end:2021-12-31
+
+    let _discount_46 = discount!(reg; name: "29200 $2 Off Purchase", identifier: "29200", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::OrderTotal(OrderTotalConstraint{
+            minimum_amount: 200,
+            maximum_amount: 10000
+    }))); //start: 2021-02-01, end:2021-12-31
+
+    let _discount_47 = discount!(reg; name: "74381 $2 Off Taco Salad", identifier: "74381", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[taco_salad_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2019-08-01, end:2021-12-31
+
+    let _discount_48 = discount!(reg; name: "74780 $2 Off SPAAP", identifier: "74780", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[six_pack_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2017-06-15, end:2021-12-31
+
+    let _discount_25301 = discount!(reg; name: "25301 $2 Off SPAAP", identifier: "25301", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[six_pack_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2017-06-15, end:2021-12-31
+
+    let _discount_50 = discount!(reg; name: "77781 Free Kids Meal", identifier: "77781", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[kids_meal, kids_meal_soft, kids_meal_burr, kids_meal_taco_burger, kids_meal_fried_chicken, kids_meal_quesadilla]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2019-08-01, end:2021-12-31
+
+    // let _discount_51 = discount!(reg; name: "74351 $2 Off Any Combo", identifier: "74351", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+    //     constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+    //         selection: Selection::AnyItem(&[]).into(),
+    //         minimum_quantity: 1,
+    //         maximum_quantity: 150
+    //     }))); //start: 2020-06-13, end:2021-12-31
+
+    let _discount_52 = discount!(reg; name: "74401 Taco Bucks: $5 Off Purchase", identifier: "74401", amount: DiscountAmount::Flat(500), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::OrderTotal(OrderTotalConstraint{
+            minimum_amount: 500,
+            maximum_amount: 10000
+    }))); //start: 2017-05-15, end:2021-12-31
+
+    let _discount_53 = discount!(reg; name: "74460 $2 Off Purchase", identifier: "74460", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::OrderTotal(OrderTotalConstraint{
+            minimum_amount: 200,
+            maximum_amount: 10000
+    }))); //start: 2021-04-09, end:2021-12-31
+
+    let _discount_55 = discount!(reg; name: "74530 $10 Off Purchase", identifier: "74530", amount: DiscountAmount::Flat(1000), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::OrderTotal(OrderTotalConstraint{
+            minimum_amount: 1000,
+            maximum_amount: 10000
+    }))); //start: 2020-06-08, end:2021-12-31
+
+    let _discount_57 = discount!(reg; name: "75792 Free Small Potato Oles", identifier: "75792", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[potato_ole_side]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2017-05-15, end:2021-12-31
+
+    let _discount_58 = discount!(reg; name: "76793 Free Dessert", identifier: "76793", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[cinnamon_sugar_tortilla_crisps, mexican_donut_bites]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2021-04-09, end:2021-12-31
+
+    /*let _discount_59 = discount!(reg; name: "76794 Free Churro bites with any purchase", identifier: "76794", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2019-09-13, end:2021-12-31*/
+
+    let _discount_60 = discount!(reg; name: "76795 Free Donut bites", identifier: "76795", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[mexican_donut_bites]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-06-08, end:2021-12-31
+
+    //how to specify drink constraint?
+    let _discount_62 = discount!(reg; name: "77752 Free Crispy Beef Taco, Small Potato Oles, Small Soft Drink (Medical Professionals, 1st Responders)", identifier: "77752", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[beef_crispy_taco, potato_ole_side]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2017-09-10, end:2021-12-31
+
+    let _discount_63 = discount!(reg; name: "77770 Free Kids Meal", identifier: "77770", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[kids_meal, kids_meal_soft, kids_meal_burr, kids_meal_taco_burger, kids_meal_fried_chicken, kids_meal_quesadilla]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2017-08-15, end:2021-12-31
+
+    let _discount_64 = discount!(reg; name: "77780 2 Free Bean BurritoS With SPAAP", identifier: "77780", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[six_pack_shell_item, bean_burrito]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-07-16, end:2021-12-31
+
+    // let _discount_65 = discount!(reg; name: "77790 Free Any Combo Meal", identifier: "77790", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+    //     constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+    //         selection: Selection::AnyItem(&[]).into(),
+    //         minimum_quantity: 1,
+    //         maximum_quantity: 150
+    //     }))); //start: 2021-04-09, end:2021-12-31
+
+    let _discount_68 = discount!(reg; name: "78770 Free Small Potato Oles", identifier: "78770", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[potato_ole_side]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2019-09-13, end:2021-12-31
+
+    let _discount_69 = discount!(reg; name: "79250 $1 Off SPAAP At Regular Price", identifier: "79250", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[six_pack_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2019-09-13, end:2021-12-31
+
+    let _discount_70 = discount!(reg; name: "79372 $2 Off SPAAP  (Limit 4)", identifier: "79372", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[six_pack_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 4
+        }))); //start: 2020-05-18, end:2021-12-31
+
+    let _discount_72 = discount!(reg; name: "79755 Free SPAAP", identifier: "79755", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[six_pack_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2020-11-03, end:2021-12-31
+
+    // let _discount_73 = discount!(reg; name: "79790 Free Taco with any Combo Purchase", identifier: "79790", amount: DiscountAmount::Flat(100), single: true, incombinable: true,
+    //     constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+    //         selection: Selection::AnyItem(&[]).into(),
+    //         minimum_quantity: 1,
+    //         maximum_quantity: 150
+    //     }))); //start: 2019-09-13, end:2021-12-31
+
+    let _discount_80 = discount!(reg; name: "79370 $2 Off SPAAP", identifier: "79370", amount: DiscountAmount::Flat(200), single: true, incombinable: true,
+        constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+            selection: Selection::AnyItem(&[six_pack_shell_item]).into(),
+            minimum_quantity: 1,
+            maximum_quantity: 150
+        }))); //start: 2019-09-13, end:2022-02-28
+
+    // let _discount_25900 = discount!(reg; name: "2 for $5", identifier: "25900", amount: DiscountAmount::Set(500), single: true, incombinable: false,
+    //     constraints: vec!(OrderConstraint::ItemQuantity(ItemQuantityConstraint {
+    //         selection: Selection::AnyItem(&[beef_stuffed_grilled_taco, stuffed_grilled_chipotle_chicken_taco, steak_stuffed_grilled_taco, bean_stuffed_grilled_taco, stuffed_grilled_chipotle_chicken_taco]).into(),
+    //         minimum_quantity: 2,
+    //         maximum_quantity: 150
+    //     }))); //start: 2017-06-15, end:2021-12-31
+}
@@ -0,0 +1,163 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle, PriceOverrideBuilder, Selection, SlotBuilder, VariationBuilder};
+use super::Registry;
+use super::ingredients::Ingredients;
+use adjunct::{Modification, SlotType};
+
+/// What later sections use from this one.
+pub struct Drinks {
+    pub chocolate_milk: ItemHandle,
+    pub coffee: ItemHandle,
+    pub drinks: SlotBuilder,
+    pub kids_beverages: SlotBuilder,
+    pub milk: ItemHandle,
+}
+
+pub fn register(reg: &mut Registry, ingredients: &Ingredients) -> Drinks {
+    let cane_syrup = ingredients.cane_syrup;
+    let cream = ingredients.cream;
+    let lemon = ingredients.lemon;
+    let mocha_pump = ingredients.mocha_pump;
+    let sugar = ingredients.sugar;
+    let sweetener = ingredients.sweetener;
+    let vanilla_pump = ingredients.vanilla_pump;
+
+    // Beverage Stuff
+    let beverage_variations = vec![
+        VariationBuilder::new("Kid", "64").prefix("KD").price(199).build(),
+        VariationBuilder::new("Small", "SM").prefix("SM").price(219).build(),
+        VariationBuilder::new("Medium", "MD").prefix("MD").price(265).build(),
+        VariationBuilder::new("Large", "LG").prefix("LG").price(295).build(),
+    ];
+    let kids_beverage_variations = [
+        VariationBuilder::new("Kid", "64").prefix("KD").price(199).build(),
+    ];
+
+    let tea_additions = SlotBuilder::new("Tea Additions", SlotType::Ingredient, Selection::AnyItem(&[lemon, sweetener]));
+
+    let ice = reg.add_item(ItemBuilder::new("86be1a53-6c51-4f14-bfe7-ad40dff1037b", "Ice", "Ice").price(0).plu("999029").modifiers(&[Modification::Extra, Modification::Light]));
+    let beverage_slot = SlotBuilder::new("Modifiers", SlotType::Ingredient, Selection::AnyItem(&[ice]));
+    let cold_drink_slots = SlotBuilder::new("Cold Drink Additions", SlotType::Ingredient, Selection::AnyItem(&[ice])).defaults(&[ice]);
+
+    let starbucks_flavors = vec![
+        VariationBuilder::new("Vanilla", "Vanilla").prefix("Vn").build(),
+        VariationBuilder::new("Mocha", "Mocha").prefix("Mc").build(),
+        VariationBuilder::new("Carmel", "Carmel").prefix("Cr").build(),
+    ];
+
+    let mountain_dew = reg.add_item(ItemBuilder::new("8725fe80-24d3-4581-941f-56c4e7ab218d", "Mountain Dew", "DEW").price(0).plu("7040").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let pepsi = reg.add_item(ItemBuilder::new("8804632e-79ec-4673-8df2-554c6dc4286f", "Pepsi", "P").price(0).plu("7000").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let diet_pepsi = reg.add_item(ItemBuilder::new("89ce819c-f619-40b6-84b1-be4b54a91ca1", "Diet Pepsi", "DTP").price(0).plu("7010").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let sierra_mist = reg.add_item(ItemBuilder::new("8a0ab3e8-b3c7-4ad1-9b1e-3f4c4d7a4120", "Sierra Mist", "MST").price(0).plu("7060").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let dr_pepper = reg.add_item(ItemBuilder::new("8b987ee4-0b5f-4204-b486-9d85963ca86b", "Dr Pepper", "DDR").price(0).plu("7020").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let diet_mountain_dew = reg.add_item(ItemBuilder::new("8bdd06db-5724-4be2-b15d-002f95b0cd46", "Diet Mountain Dew", "DT-DEW").price(0).plu("7050").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let diet_sierray_mist = reg.add_item(ItemBuilder::new("8c19134d-018d-44e8-8081-27cd3f32563b", "Diet Sierra Mist", "DT-MST").price(0).plu("7100").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let cherry_pepsi = reg.add_item(ItemBuilder::new("8eccd25f-1fdf-4c60-8a8c-58ef11236354", "Cherry Pepsi", "CHP").price(0).plu("7140").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let diet_dr_pepper = reg.add_item(ItemBuilder::new("8f67b322-ab71-4395-86d5-1439695d1afc", "Diet Dr Pepper", "DT-DDR").price(0).plu("7150").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+    let mug_root_beer = reg.add_item(ItemBuilder::new("938c6a6d-cd4f-49b6-952c-c99144aa37cb", "Mug Root Beer", "Root Beer").price(0).plu("7030").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).priority(2).slot(cold_drink_slots.clone()));
+
+    let _coffee = reg.add_item(
+        ItemBuilder::new("93e3e108-66ae-4cfe-bd5a-125a158271f5", "Coffee", "COFFEE")
+            .price(159).plu("7200")
+            .tags(&[tags::DRINKS, tags::INCLUDEDDRINK])
+            .slot(beverage_slot.clone())
+            .slot(
+                SlotBuilder::new("Additions", SlotType::Ingredient, Selection::AnyItem(&[cream, sugar, sweetener]))
+            )
+    );
+
+    let _decaf_coffee = reg.add_item(
+        ItemBuilder::new("93ece057-69c0-4945-9162-45a821cb8786", "Decaf Coffee", "COFFEE-DECAF")
+            .price(99).plu("6095")
+            .tags(&[tags::DRINKS, tags::PREMIUMDRINK, tags::INCLUDEDDRINK])
+            .slot(beverage_slot.clone())
+            .slot(
+                SlotBuilder::new("Additions", SlotType::Ingredient, Selection::AnyItem(&[cream, sugar, sweetener]))
+            )
+    );
+
+    // Bottles
+    let bottled_mtn_dew_code_red = reg.add_item(ItemBuilder::new("95429984-e0fa-4025-9e1e-da214c1027d4", "Mountain Dew code Red", "CODE-RED").price(199).plu("10540").priority(1).tags(&[tags::DRINKS, tags::PREMIUMDRINK]));
+    let starbucks_frappachino = reg.add_item(ItemBuilder::new("954909de-4824-4a5c-9f78-4e01dd72214b", "Frappacino", "FRAP").price(100).plu("10550").tags(&[tags::DRINKS, tags::PREMIUMDRINK]).priority(1).variations(&starbucks_flavors));
+    let starbucks_double_shot = reg.add_item(ItemBuilder::new("956c11bc-e9b4-4051-a15d-0d0c2b65dd0f", "Double Shot", "DS").price(100).plu("10700").tags(&[tags::DRINKS, tags::PREMIUMDRINK]).priority(1).variations(&starbucks_flavors));
+    let starbucks_triple_shot = reg.add_item(ItemBuilder::new("95749489-d214-4472-9b07-a833827d19c5", "Triple Shot", "TS").price(100).plu("10750").tags(&[tags::DRINKS, tags::PREMIUMDRINK]).priority(1).variations(&starbucks_flavors));
+
+    let orange_juice = reg.add_item(ItemBuilder::new("9a12bb72-ff50-4b58-94b8-1565dbf093a9", "Orange Juice", "OJ").price(279).plu("5820").priority(1).tags(&[tags::DRINKS, tags::PREMIUMDRINK]));
+    let bottle_water = reg.add_item(ItemBuilder::new("9a346931-d498-4baa-85a3-b794ec91f622", "Bottle Water", "BOTTLE").price(149).plu("5850").priority(1).tags(&[tags::DRINKS, tags::INCLUDEDDRINK]));
+    let pomegranate_sobe = reg.add_item(ItemBuilder::new("9ae57bed-ff6a-46df-ab24-cd56cc5bf659", "Pomegranate Sobe Yumberry", "SOBE").price(219).plu("6030").priority(1).tags(&[tags::DRINKS, tags::SOFTDRINK, tags::PREMIUMDRINK]).variations(&beverage_variations).slot(cold_drink_slots.clone()));
+    let lemonade = reg.add_item(ItemBuilder::new("9bcb7d5a-e29b-4049-9d77-1be3b60c4c3f", "Lemonade", "LEM").price(0).plu("7070").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).slot(cold_drink_slots.clone()));
+    let water_cup = reg.add_item(ItemBuilder::new("9e7e54d1-0151-4aee-ab76-bdfc4c05d77f", "Water Cup", "WAT").price(0).plu("5000").tags(&[tags::DRINKS, tags::PREMIUMDRINK]));
+    let iced_tea = reg.add_item(ItemBuilder::new("9f576bb7-ae87-47eb-bf78-ca64e3e95a87", "Iced Tea", "TEA").price(0).plu("7080").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).slot(cold_drink_slots.clone()).slot(tea_additions.clone()));
+    let peach_tea = reg.add_item(ItemBuilder::new("9f820416-5ab8-4ca9-a056-c8658c3c6d58", "Peach Tea", "TEA-P").price(0).plu("7180").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).slot(cold_drink_slots.clone()).slot(tea_additions.clone()));
+    let sweet_tea = reg.add_item(ItemBuilder::new("a07a9770-7b57-484c-bdb0-4fd28b313fab", "Sweet Tea", "STE").price(0).plu("7130").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).slot(cold_drink_slots.clone()).slot(tea_additions.clone()));
+    let fruit_punch = reg.add_item(ItemBuilder::new("a2a59244-5ce9-4c9a-b9f8-a6565403716a", "Fruit Punch", "PUN").price(0).plu("7160").tags(&[tags::DRINKS, tags::SOFTDRINK, tags::PREMIUMDRINK]).variations(&beverage_variations).slot(cold_drink_slots.clone()));
+    let raspberry_iced_tea = reg.add_item(ItemBuilder::new("a4f732c0-2c63-43af-9160-58ee226d798c", "Rasp. Iced Tea", "RSP").price(0).plu("7090").tags(&[tags::DRINKS, tags::SOFTDRINK]).variations(&beverage_variations).slot(cold_drink_slots.clone()).slot(tea_additions.clone()));
+    let milk = reg.add_item(ItemBuilder::new("a513aabd-d8b4-401b-bd4b-537d195cb05a", "Milk", "MILK").price(219).plu("5800").tags(&[tags::DRINKS, tags::PREMIUMDRINK]));
+    let chocolate_milk = reg.add_item(ItemBuilder::new("a656b3c2-3d7f-4669-b373-dd6c7e7835cc", "Chocolate Milk", "C-MILK").price(215).plu("7120").tags(&[tags::DRINKS, tags::PREMIUMDRINK]));
+
+    let cold_brew_slot = SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[cane_syrup, mocha_pump, vanilla_pump]));
+    let cold_brew_20oz = reg.add_item(
+        ItemBuilder::new("33620d5b-fbbd-4d41-8c42-36fd570b6a01", "Cold Brew 20oz", "SM CBC")
+            .price(200).plu("7270")
+            .tags(&[tags::DRINKS, tags::PREMIUMDRINK, tags::INCLUDEDDRINK])
+            .slot(cold_brew_slot.clone())
+            .slot(beverage_slot.clone())
+    );
+    let cold_brew_plain = reg.add_item(
+        ItemBuilder::new("450f8ce0-e099-44d0-ad4d-f88bf5aa193a", "Cold Brew Plain", "CBC-S")
+            .price(200).plu("7280")
+            .tags(&[tags::DRINKS, tags::PREMIUMDRINK, tags::INCLUDEDDRINK])
+            .slot(cold_brew_slot.clone())
+            .slot(beverage_slot.clone())
+    );
+    let cold_brew_vanilla = reg.add_item(
+        ItemBuilder::new("59b2c807-5f04-4175-aa00-62f6eec040eb", "Cold Brew Vanilla", "CBC-V")
+            .price(200).plu("7290")
+            .tags(&[tags::DRINKS, tags::PREMIUMDRINK, tags::INCLUDEDDRINK])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[vanilla_pump, cane_syrup, mocha_pump]))
+            )
+            .slot(beverage_slot.clone())
+    );
+    let cold_brew_mocha = reg.add_item(
+        ItemBuilder::new("13825a91-b0ce-47ad-bdd3-3db7dd8279e2", "Cold Brew Mocha", "CBC-M")
+            .price(200).plu("7300")
+            .tags(&[tags::DRINKS, tags::PREMIUMDRINK, tags::INCLUDEDDRINK])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[vanilla_pump, cane_syrup, mocha_pump]))
+            )
+            .slot(beverage_slot.clone())
+    );
+    let cold_brew_unsweet = reg.add_item(
+        ItemBuilder::new("414d5794-b9fd-4ba7-8ff6-f6c9f79b5f98", "Cold Brew Unsweet", "CBC-X")
+            .price(200).plu("7310")
+            .tags(&[tags::DRINKS, tags::PREMIUMDRINK, tags::INCLUDEDDRINK])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[vanilla_pump, cane_syrup, mocha_pump]))
+            )
+            .slot(beverage_slot.clone())
+    );
+
+    let kids_beverages = SlotBuilder::new("Drinks", SlotType::Replace, Selection::AnyTag(&[tags::DRINKS]))
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::SOFTDRINK]).variation("Kid"))
+        .default_quantity(1)
+        .minimum_quantity(1);
+
+    let drinks = SlotBuilder::new("Drinks", SlotType::Replace, Selection::AnyTag(&[tags::DRINKS]))
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::INCLUDEDDRINK]).price(0))
+        .price_override(PriceOverrideBuilder::new().items(&[orange_juice]).price(60))
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::SOFTDRINK]).variation("Small").price(0))
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::SOFTDRINK]).variation("Medium").price(80))
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::SOFTDRINK]).variation("Large").price(110))
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::PREMIUMDRINK]).price(110))
+        .hidden(Selection::AnyTag(&[tags::PREMIUMDRINK]))
+        .default_quantity(1)
+        .minimum_quantity(1);
+
+    Drinks {
+        chocolate_milk,
+        coffee: _coffee,
+        drinks,
+        kids_beverages,
+        milk,
+    }
+}
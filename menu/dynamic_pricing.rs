@@ -0,0 +1,109 @@
+use crate::builder::Selection;
+use super::Registry;
+use super::breakfast::Breakfast;
+use super::burritos::Burritos;
+use super::combos::Combos;
+use super::specialties::Specialties;
+use super::tacos::Tacos;
+use adjunct::{OrderConstraint, OrderTimeConstraint, PricingModificationStyle};
+
+pub fn register(
+    reg: &mut Registry,
+    specialties: &Specialties,
+    breakfast: &Breakfast,
+    burritos: &Burritos,
+    tacos: &Tacos,
+    combos: &Combos,
+) {
+    let bacon_and_egg_burrito = breakfast.bacon_and_egg_burrito;
+    let bacon_potato_breakfast_burrito = breakfast.bacon_potato_breakfast_burrito;
+    let bacon_scrambler_burrito = breakfast.bacon_scrambler_burrito;
+    let bean_burrito = burritos.bean_burrito;
+    let bean_taco_bravo = tacos.bean_taco_bravo;
+    let beef_crispy_taco = tacos.beef_crispy_taco;
+    let beef_potato_breakfast_burrito = breakfast.beef_potato_breakfast_burrito;
+    let beef_softshell_taco = tacos.beef_softshell_taco;
+    let beef_taco_bravo = tacos.beef_taco_bravo;
+    let beef_taco_salad = specialties.beef_taco_salad;
+    let chicken_taco_bravo = tacos.chicken_taco_bravo;
+    let chicken_taco_salad = specialties.chicken_taco_salad;
+    let saus_and_bacon_meat_potato_burrito = burritos.saus_and_bacon_meat_potato_burrito;
+    let sausage_and_egg_burrito = breakfast.sausage_and_egg_burrito;
+    let sausage_potato_breakfast_burrito = breakfast.sausage_potato_breakfast_burrito;
+    let sausage_scrambler_burrito = breakfast.sausage_scrambler_burrito;
+    let six_pack_and_pound_combo_crispy = combos.six_pack_and_pound_combo_crispy;
+    let six_pack_and_pound_combo_mix = combos.six_pack_and_pound_combo_mix;
+    let six_pack_and_pound_combo_soft = combos.six_pack_and_pound_combo_soft;
+    let steak_and_egg_burrito = breakfast.steak_and_egg_burrito;
+    let steak_potato_breakfast_burrito = breakfast.steak_potato_breakfast_burrito;
+    let steak_scrambler_burrito = breakfast.steak_scrambler_burrito;
+    let steak_taco_bravo = tacos.steak_taco_bravo;
+    let steak_taco_salad = specialties.steak_taco_salad;
+    let triple_meat_potato_breakfast_burrito = breakfast.triple_meat_potato_breakfast_burrito;
+
+    // Dynamic Pricing
+    let taco_price_mod = pricing_modification!(style: PricingModificationStyle::Flat, amount: -70);
+    let bean_burr_price_mod = pricing_modification!(style: PricingModificationStyle::Flat, amount: -40);
+    let taco_tuesday_price_rule = pricing_rule!(
+        selection: Selection::AnyItem(&[beef_crispy_taco, beef_softshell_taco]),
+        pricing_modification: taco_price_mod
+    );
+    let bean_burr_pricing_rule = pricing_rule!(
+        selection: Selection::AnyItem(&[bean_burrito]),
+        pricing_modification: bean_burr_price_mod
+    );
+    dynamic_price!(reg; auto_constraints: OrderConstraint::Time(OrderTimeConstraint {
+        day_of_week: vec![chrono::Weekday::Tue],
+        start_time: 0,
+        stop_time: 86400,
+    }), rules: vec![taco_tuesday_price_rule, bean_burr_pricing_rule], name: "Taco Tuesday");
+
+    let wake_up_wed_mod = pricing_modification!(style: PricingModificationStyle::Set, amount: 249);
+    let wake_up_wed_price_rule = pricing_rule!(
+        selection: Selection::AnyItem(&[beef_potato_breakfast_burrito, bacon_potato_breakfast_burrito, sausage_potato_breakfast_burrito, saus_and_bacon_meat_potato_burrito, bacon_scrambler_burrito, sausage_scrambler_burrito, bacon_and_egg_burrito, sausage_and_egg_burrito, triple_meat_potato_breakfast_burrito]),
+        pricing_modification: wake_up_wed_mod
+    );
+    let wake_up_wed_steak_mod = pricing_modification!(style: PricingModificationStyle::Set, amount: 309);
+    let wake_up_wed_steak_price_rule = pricing_rule!(
+        selection: Selection::AnyItem(&[steak_potato_breakfast_burrito, steak_scrambler_burrito, steak_and_egg_burrito]),
+        pricing_modification: wake_up_wed_steak_mod
+    );
+    dynamic_price!(reg; auto_constraints: OrderConstraint::Time(OrderTimeConstraint {
+        day_of_week: vec![chrono::Weekday::Wed],
+        start_time: 0,
+        stop_time: 86400,
+    }), rules: vec![wake_up_wed_price_rule, wake_up_wed_steak_price_rule], name: "Wake up Wednesday");
+
+    let taco_bravo_price_mod = pricing_modification!(style: PricingModificationStyle::Flat, amount: -120);
+    let taco_bravo_price_rule = pricing_rule!(
+        selection: Selection::AnyItem(&[bean_taco_bravo, beef_taco_bravo, chicken_taco_bravo, steak_taco_bravo]),
+        pricing_modification: taco_bravo_price_mod
+    );
+    dynamic_price!(reg; auto_constraints: OrderConstraint::Time(OrderTimeConstraint {
+        day_of_week: vec![chrono::Weekday::Thu],
+        start_time: 0,
+        stop_time: 86400,
+    }), rules: vec![taco_bravo_price_rule], name: "Taco Bravo Thursday");
+
+    let six_pack_sunday = pricing_modification!(style: PricingModificationStyle::Flat, amount: -150);
+    let six_pack_sunday_price_rule = pricing_rule!(
+        selection: Selection::AnyItem(&[six_pack_and_pound_combo_crispy, six_pack_and_pound_combo_mix, six_pack_and_pound_combo_soft]),
+        pricing_modification: six_pack_sunday
+    );
+    dynamic_price!(reg; auto_constraints: OrderConstraint::Time(OrderTimeConstraint {
+        day_of_week: vec![chrono::Weekday::Sun],
+        start_time: 0,
+        stop_time: 86400,
+    }), rules: vec![six_pack_sunday_price_rule], name: "Six Pack Sunday");
+
+    let taco_salad_monday = pricing_modification!(style: PricingModificationStyle::Flat, amount: -100);
+    let taco_salad_monday_price_rule = pricing_rule!(
+        selection: Selection::AnyItem(&[beef_taco_salad, chicken_taco_salad, steak_taco_salad]),
+        pricing_modification: taco_salad_monday
+    );
+    dynamic_price!(reg; auto_constraints: OrderConstraint::Time(OrderTimeConstraint {
+        day_of_week: vec![chrono::Weekday::Mon],
+        start_time: 0,
+        stop_time: 86400,
+    }), rules: vec![taco_salad_monday_price_rule], name: "Taco Salad Monday");
+}
@@ -0,0 +1,182 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle, PriceOverrideBuilder, Selection, SlotBuilder, VariationBuilder};
+use super::Registry;
+use super::proteins::Proteins;
+use adjunct::{Modification, SlotType, Variation};
+
+/// What later sections use from this one.
+pub struct Ingredients {
+    pub bacon_ranch: ItemHandle,
+    pub black_beans: ItemHandle,
+    pub blue_cheese: ItemHandle,
+    pub breakfast_proteins: SlotBuilder,
+    pub burger_bun: ItemHandle,
+    pub cane_syrup: ItemHandle,
+    pub cheddar_cheese: ItemHandle,
+    pub cheese: ItemHandle,
+    pub chipotle_lime: ItemHandle,
+    pub cilantro_lime_rice: ItemHandle,
+    pub cinnamon_sugar: ItemHandle,
+    pub corn_chips: ItemHandle,
+    pub corn_salsa: ItemHandle,
+    pub corn_tortilla: ItemHandle,
+    pub cream: ItemHandle,
+    pub cream_cheese_icing: ItemHandle,
+    pub hard_shell: ItemHandle,
+    pub jalapeno: ItemHandle,
+    pub jalapeno_ranch: ItemHandle,
+    pub large_tortilla: ItemHandle,
+    pub lemon: ItemHandle,
+    pub lettuce: ItemHandle,
+    pub lime: ItemHandle,
+    pub med_tortilla: ItemHandle,
+    pub mocha_pump: ItemHandle,
+    pub nacho_cheese: ItemHandle,
+    pub onions: ItemHandle,
+    pub pico_de_gallo: ItemHandle,
+    pub poblano_peppers: ItemHandle,
+    pub potato_ole: ItemHandle,
+    pub potato_ole_seasoning: ItemHandle,
+    pub queso_fresco: ItemHandle,
+    pub ranch: ItemHandle,
+    pub refried_beans: ItemHandle,
+    pub salsa: ItemHandle,
+    pub scrambled_eggs: ItemHandle,
+    pub side_ranch: ItemHandle,
+    pub small_tortilla: ItemHandle,
+    pub sugar: ItemHandle,
+    pub super_size_variations: Vec<Variation>,
+    pub sweetener: ItemHandle,
+    pub taco_bowl: ItemHandle,
+    pub taco_proteins: SlotBuilder,
+    pub tomato: ItemHandle,
+    pub vanilla_pump: ItemHandle,
+}
+
+pub fn register(reg: &mut Registry, proteins: &Proteins) -> Ingredients {
+    let chicken = proteins.chicken;
+    let ground_beef = proteins.ground_beef;
+    let steak = proteins.steak;
+
+    // Ingredients
+    let cheese = reg.add_item(ItemBuilder::new("af9128ce-8984-49c4-ae01-52ba72957cd2", "Cheese Blend", "CH BLD").modifiers(&[Modification::Extra, Modification::Light]).price(40).plu("999041").upcharge(Modification::Extra, 40));
+    let cheddar_cheese = reg.add_item(ItemBuilder::new("529726e2-8068-49ea-b6ad-8930c0ec59bf", "Cheese", "Cheese").modifiers(&[Modification::Extra, Modification::Light]).price(35).plu("999004").upcharge(Modification::Extra, 35));
+    let nacho_cheese = reg.add_item(ItemBuilder::new("b302bbfc-c6d5-4a76-a2ea-497373b333bc", "Nacho Cheese", "Nacho Cheese").tags(&[tags::SAUCE_ADDITION, tags::SAUCES]).modifiers(&[Modification::Extra, Modification::Light]).price(59).plu("999016").upcharge(Modification::Extra, 59));
+    let scrambled_eggs = reg.add_item(ItemBuilder::new("b3b714fa-5356-4746-ac11-df0cd3971c38", "Eggs", "Eggs").modifiers(&[Modification::Extra, Modification::Light]).price(89).plu("999008").upcharge(Modification::Extra, 89));
+    let lettuce = reg.add_item(ItemBuilder::new("b3d7660d-a77d-4eb6-baaf-b33435a91fb6", "Lettuce", "Lettuce").modifiers(&[Modification::Extra, Modification::Light]).price(29).plu("999013").upcharge(Modification::Extra, 29));
+    let tomato = reg.add_item(ItemBuilder::new("b4010255-11ec-45d3-9dbe-11f52f861893", "Tomato", "Tomato").modifiers(&[Modification::Extra, Modification::Light]).price(28).plu("999028").upcharge(Modification::Extra, 28));
+    let hard_shell = reg.add_item(ItemBuilder::new("b46f0def-750c-4d82-b7cf-81509b3e280a", "Crispy Shell", "Shell").price(0).plu("999052").tags(&[tags::SHELL]));
+    let small_tortilla = reg.add_item(ItemBuilder::new("b4a19204-72b6-4cab-a75f-b4efed17d471", "Tort 6", "Tort 6").price(0).plu("999053").modifiers(&[Modification::Custom(String::from("Grilled"))]).tags(&[tags::SHELL]));
+    let med_tortilla = reg.add_item(ItemBuilder::new("b4bd2f93-cbce-461c-a9dd-da8571866e64", "Tort 8", "Tort 8").price(0).plu("999156").modifiers(&[Modification::Custom(String::from("Grilled"))]));
+    let large_tortilla = reg.add_item(ItemBuilder::new("b55d3b8e-85f2-479e-ba5a-cea85b0fec5b", "Tort 10", "Tort 10").price(0).plu("999054").modifiers(&[Modification::Custom(String::from("Grilled"))]));
+    let corn_tortilla = reg.add_item(ItemBuilder::new("b7cfa800-3b66-4fdd-894a-48b71dfe042a", "CF Tort", "CF-TRT").price(0).plu("999056"));
+    let taco_bowl = reg.add_item(ItemBuilder::new("b7f8c4d7-3ae9-4299-8306-99e0394668e8", "Salad Bowl", "Shell").price(99).plu("999039").upcharge(Modification::Extra, 99));
+    let corn_chips = reg.add_item(ItemBuilder::new("b9256a46-c9ba-4370-b828-71a4be94505f", "Chips", "Chips").price(0).plu("999040"));
+    let onions = reg.add_item(ItemBuilder::new("b9c5cb73-57f2-4e36-baaa-1c137f732b7a", "Onion", "Onion").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("999019"));
+    let poblano_peppers = reg.add_item(ItemBuilder::new("bbccf37c-b49d-4470-a549-0acd2a56f64d", "Poblano Peppers", "PBLANO").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("999207"));
+    let jalapeno = reg.add_item(ItemBuilder::new("c0a7f7ec-3d4f-4b26-b671-b06c4327fb38", "Jalapenos", "JAL").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("999012"));
+    let pico_de_gallo = reg.add_item(ItemBuilder::new("c1fb6dbe-2117-472b-834b-2e0106cdb3c9", "Pico de Gallo", "PICO").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("999020"));
+    let black_beans = reg.add_item(ItemBuilder::new("c20ba542-a949-453f-bea8-f5e9198f1d6c", "Black Beans", "B BN").modifiers(&[Modification::Extra, Modification::Light]).price(49).plu("999119").upcharge(Modification::Extra, 49));
+    let refried_beans = reg.add_item(ItemBuilder::new("c2457ed3-7500-4e95-b26e-81912d4d055f", "Beans", "Beans").modifiers(&[Modification::Extra, Modification::Light]).price(28).plu("999001").tags(&[tags::PROTEINS, tags::COMBOSIDES]).upcharge(Modification::Extra, 28));
+    let potato_ole_seasoning = reg.add_item(ItemBuilder::new("c3ee78d6-a2c4-41c6-9107-83ce79a5ddb0", "Seasoning", "Seasoning").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("999024"));
+    let potato_ole = reg.add_item(ItemBuilder::new("c4d318dd-0b38-4f5c-9816-bdb2aeafeeda", "Potato Oles", "Oles").modifiers(&[Modification::Extra, Modification::Light]).price(33).plu("999017").upcharge(Modification::Extra, 33));
+    let burger_bun = reg.add_item(ItemBuilder::new("c5a24bff-8a77-449c-b69f-8884696e3231", "Bun", "BUN").price(0).plu("999999"));
+    let salsa = reg.add_item(ItemBuilder::new("c669cbbc-158d-44ad-b1e1-6c9ab97fb5fd", "Salsa", "SAL").price(0).plu("999023").modifiers(&[Modification::Extra, Modification::Light]));
+    let cilantro_lime_rice = reg.add_item(ItemBuilder::new("c78e89c8-88e5-46f7-8f1f-66ac04569908", "Cil Lime Rice", "CLRICE").price(75).plu("999062").modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 75));
+    let queso_fresco = reg.add_item(ItemBuilder::new("c7d6eb20-746c-4425-bb2b-e3d9538fb3ea", "Queso Blend Chz", "Q B CH").price(29).plu("999210").tags(&[tags::SAUCES]).modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 29));
+    let lime = reg.add_item(ItemBuilder::new("c8124feb-76f4-44d9-ba05-e468e8ab6510", "Lime", "LIME").price(0).plu("999151").modifiers(&[Modification::Extra, Modification::Light]));
+    let ranch = reg.add_item(ItemBuilder::new("d28c6bf0-475e-4af2-9a97-6c55a8fc50a8", "Ranch", "Ranch").price(49).plu("999021").modifiers(&[Modification::Extra, Modification::Light]));
+    let corn_salsa = reg.add_item(ItemBuilder::new("e322859d-a96c-4992-bbc1-7b90589b9a93", "Corn Salsa", "Corn Salsa").price(0).plu("9996610").modifiers(&[Modification::Extra, Modification::Light]));
+
+    // Dessert ingredients
+    let cream_cheese_icing = reg.add_item(ItemBuilder::new("c897434d-8054-45d9-a966-8b7545dc3d48", "Glaze Icing", "GLZ").price(30).plu("999400").modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 30));
+    let cinnamon_sugar = reg.add_item(ItemBuilder::new("c94e22a2-0205-4c61-945e-461e8e7cc12f", "Cinn Sugar", "CINSGR").price(0).plu("999032").modifiers(&[Modification::Extra, Modification::Light]));
+
+    // Drink Ingredients
+    let sugar = reg.add_item(ItemBuilder::new("ca9dd236-b3e3-4e2a-8c36-dc6c45614efc", "Sugar", "SGR").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("3021"));
+    let cream = reg.add_item(ItemBuilder::new("cae7115d-54fc-4a32-84b3-eeb10a2f5fae", "Cream", "CRM").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("3022"));
+    let sweetener = reg.add_item(ItemBuilder::new("cb6b8711-13cf-46b2-9624-288f158b641a", "Sweetener", "SWT").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("3023"));
+    let lemon = reg.add_item(ItemBuilder::new("cbca854c-6bdc-4de4-813f-6019a947df7b", "Lemon", "LMN").modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("6160"));
+    let cane_syrup = reg.add_item(ItemBuilder::new("af7642bc-bc49-4561-815a-c6a8748e4447", "Cane Syrup", "Cane").modifiers(&[Modification::Extra, Modification::Light]).price(25).plu("999220").upcharge(Modification::Extra, 25));
+    let mocha_pump = reg.add_item(ItemBuilder::new("a33d3fa9-0953-4622-85f4-ede7b743d487", "Mocha Pump", "Mocha").modifiers(&[Modification::Extra, Modification::Light]).price(25).plu("999221").upcharge(Modification::Extra, 25));
+    let vanilla_pump = reg.add_item(ItemBuilder::new("33fc2876-8e76-4189-ba54-8f430e818376", "Vanilla Pump", "Vanilla").modifiers(&[Modification::Extra, Modification::Light]).price(25).plu("999222").upcharge(Modification::Extra, 25));
+
+    // Dressing
+    let bacon_ranch = reg.add_item(ItemBuilder::new("d967141e-6d09-4e33-a41d-a31c56cb50fe", "Bacon Ranch", "B RD").tags(&[tags::DRESSING]).price(89).plu("8370"));
+    let blue_cheese = reg.add_item(ItemBuilder::new("d9f98c16-0d73-4555-ae5e-3df5bae71075", "Blue Cheese", "BLU CH").tags(&[tags::DRESSING]).price(89).plu("8380"));
+    let fat_free_ranch = reg.add_item(ItemBuilder::new("db0abe4c-56a4-467d-b45d-f2e935212dbf", "Fat Free Ranch", "FF Ranch").tags(&[tags::DRESSING]).price(89).plu("8430"));
+    let thousand_island = reg.add_item(ItemBuilder::new("db0b323c-0861-44e4-8c28-75289df0efc3", "1000", "1000").tags(&[tags::DRESSING]).price(55).plu("8500"));
+    let fat_free_thousand_island = reg.add_item(ItemBuilder::new("de1ec281-d213-46ee-adc9-e7d9690d116c", "FF 1000", "FF 1000").tags(&[tags::DRESSING]).price(55).plu("8510"));
+    let italian = reg.add_item(ItemBuilder::new("df7675a6-719e-4610-825a-e51428e6b6e9", "Italian", "ITAL").tags(&[tags::DRESSING]).price(55).plu("8520"));
+    let light_italian = reg.add_item(ItemBuilder::new("df95d5c9-4273-4b4f-bd7d-0cdc4b35e78d", "Light Italian", "LT ITAL").tags(&[tags::DRESSING]).price(55).plu("8530"));
+    let fat_free_french = reg.add_item(ItemBuilder::new("e09660ea-a6bc-4cc0-909c-79c5f7f434b2", "FF Frn", "FF FRN").tags(&[tags::DRESSING]).price(55).plu("8540"));
+    let french = reg.add_item(ItemBuilder::new("e11a0ecd-c401-4f75-bf67-5588649995d2", "French", "French").tags(&[tags::DRESSING]).price(55).plu("8570"));
+    let house = reg.add_item(ItemBuilder::new("e258fb85-a588-4e10-a3a1-2d97365c01c8", "Side House", "HD").tags(&[tags::DRESSING]).price(99).plu("8440"));
+    let side_ranch = reg.add_item(ItemBuilder::new("e387bbb1-ab6c-435b-b7d3-780e86f6b661", "Side Ranch", "RAN").tags(&[tags::DRESSING]).price(99).plu("8390"));
+    let jalapeno_ranch = reg.add_item(ItemBuilder::new("e4573965-7f06-487a-8fbd-393619402a34", "Jalapeno Ranch", "SD JR").tags(&[tags::DRESSING]).price(49).plu("12600"));
+    let chipotle_lime = reg.add_item(ItemBuilder::new("e6eb880c-a232-4966-beb7-2b9cd3fb96e2", "Chipotle Lime", "SD CLS").tags(&[tags::DRESSING]).price(49).plu("12610"));
+
+    let super_size_variations = vec![
+        VariationBuilder::new("Small", "128").prefix("SM").build(),
+        VariationBuilder::new("Medium", "256").prefix("MD").build(),
+    ];
+
+    let taco_proteins = SlotBuilder::new("Protein", SlotType::Ingredient, Selection::Tag(tags::PROTEINS))
+        .defaults(&[ground_beef]);
+    let grilled_burrito_proteins = SlotBuilder::new("Protein", SlotType::Ingredient, Selection::Tag(tags::GRILLED_BURRITO_PROTEINS))
+        .defaults(&[chicken])
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::GRILLED_BURRITO_PROTEINS]).price(0));
+    let street_taco_proteins = SlotBuilder::new("Protein", SlotType::Ingredient, Selection::Tag(tags::STREET_TACO_PROTEINS))
+        .defaults(&[steak])
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::STREET_TACO_PROTEINS]).price(0));
+
+    let breakfast_proteins = SlotBuilder::new("Protein", SlotType::Replace, Selection::Tag(tags::BREAKFAST_PROTEINS))
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::BREAKFAST_PROTEINS]).price(0));
+
+    Ingredients {
+        bacon_ranch,
+        black_beans,
+        blue_cheese,
+        breakfast_proteins,
+        burger_bun,
+        cane_syrup,
+        cheddar_cheese,
+        cheese,
+        chipotle_lime,
+        cilantro_lime_rice,
+        cinnamon_sugar,
+        corn_chips,
+        corn_salsa,
+        corn_tortilla,
+        cream,
+        cream_cheese_icing,
+        hard_shell,
+        jalapeno,
+        jalapeno_ranch,
+        large_tortilla,
+        lemon,
+        lettuce,
+        lime,
+        med_tortilla,
+        mocha_pump,
+        nacho_cheese,
+        onions,
+        pico_de_gallo,
+        poblano_peppers,
+        potato_ole,
+        potato_ole_seasoning,
+        queso_fresco,
+        ranch,
+        refried_beans,
+        salsa,
+        scrambled_eggs,
+        side_ranch,
+        small_tortilla,
+        sugar,
+        super_size_variations,
+        sweetener,
+        taco_bowl,
+        taco_proteins,
+        tomato,
+        vanilla_pump,
+    }
+}
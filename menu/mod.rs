@@ -0,0 +1,280 @@
+//! The menu definition, one module per section. Sections run in order and
+//! add their items to a [`Registry`]. Each section returns the handles, shared
+//! slots and variations later sections use, and those take it as an argument,
+//! so a section can only refer to items that an earlier one actually added.
+
+use crate::builder::{CategoryBuilder, ItemBuilder, ItemHandle, Selection, SlotBuilder};
+use crate::dayparts::{Daypart, DaypartSchedule};
+use crate::dietary::{Dietary, DietaryFlags};
+use crate::document::MenuDocument;
+use crate::hash;
+use crate::selection;
+use crate::tax::{TaxAssignments, TaxCategory};
+use adjunct::{ItemSelection, Menu, SlotType, ID};
+use std::collections::BTreeMap;
+
+macro_rules! discount {
+    ($reg: ident; $($element: ident: $val: expr), *) => {
+        {
+            let discount = adjunct::DiscountDefinition { $($element: $val.into()), *, ..adjunct::DiscountDefinition::default() };
+            $reg.menu.discounts.push(discount.clone());
+            discount
+        }
+    }
+}
+
+macro_rules! dynamic_price {
+    ($reg: ident; $($element: ident: $val: expr), *) => {
+        {
+            let dp = adjunct::PricingRuleSet { $($element: $val.into()), *, ..adjunct::PricingRuleSet::default() };
+            $reg.menu.dynamic_pricing.push(dp.clone());
+            dp
+        }
+    }
+}
+
+macro_rules! pricing_rule {
+    ($($element: ident: $val: expr), *) => {
+        {
+            adjunct::PricingRule { $($element: $val.into()), *, ..adjunct::PricingRule::default() }
+        }
+    }
+}
+
+macro_rules! pricing_modification {
+    ($($element: ident: $val: expr), *) => {
+        {
+            adjunct::PricingModification { $($element: $val.into()), *, ..adjunct::PricingModification::default() }
+        }
+    }
+}
+
+mod allergens;
+mod breakfast;
+mod burritos;
+mod categories;
+mod combos;
+mod dayparts;
+mod discounts;
+mod drinks;
+mod dynamic_pricing;
+mod ingredients;
+mod proteins;
+mod sauces;
+mod sides;
+mod specialties;
+mod tacos;
+mod tax;
+
+/// Builds the full menu, section by section.
+pub fn build() -> MenuDocument {
+    let mut reg = Registry::default();
+    let proteins = proteins::register(&mut reg);
+    let sauces = sauces::register(&mut reg);
+    let ingredients = ingredients::register(&mut reg, &proteins);
+    let sides = sides::register(&mut reg, &proteins, &sauces, &ingredients);
+    let drinks = drinks::register(&mut reg, &ingredients);
+    let specialties = specialties::register(&mut reg, &proteins, &sauces, &ingredients, &sides);
+    let breakfast = breakfast::register(&mut reg, &proteins, &sauces, &ingredients, &sides);
+    let burritos = burritos::register(
+        &mut reg,
+        &proteins,
+        &sauces,
+        &ingredients,
+        &sides,
+        &breakfast,
+    );
+    let tacos = tacos::register(&mut reg, &proteins, &sauces, &ingredients, &sides);
+    let combos = combos::register(
+        &mut reg,
+        &proteins,
+        &sauces,
+        &ingredients,
+        &sides,
+        &drinks,
+        &specialties,
+        &breakfast,
+        &burritos,
+        &tacos,
+    );
+    discounts::register(
+        &mut reg,
+        &sides,
+        &specialties,
+        &breakfast,
+        &burritos,
+        &tacos,
+        &combos,
+    );
+    dynamic_pricing::register(
+        &mut reg,
+        &specialties,
+        &breakfast,
+        &burritos,
+        &tacos,
+        &combos,
+    );
+    categories::register(&mut reg);
+    dayparts::register(&mut reg);
+    allergens::register(&mut reg, &proteins, &sauces, &ingredients, &drinks);
+    tax::register(&mut reg);
+    reg.finish()
+}
+
+/// The menu under construction plus what sections attach to its items.
+#[derive(Default)]
+pub struct Registry {
+    pub menu: Menu,
+    dayparts: Vec<Daypart>,
+    daypart_items: Vec<(String, ItemSelection)>,
+    daypart_categories: BTreeMap<String, Vec<String>>,
+    ingredient_flags: BTreeMap<ID, DietaryFlags>,
+    tax_categories: Vec<(ItemSelection, TaxCategory)>,
+    tax_splits: Vec<ItemSelection>,
+}
+
+impl Registry {
+    pub fn add_item(&mut self, item: ItemBuilder) -> ItemHandle {
+        item.add_to(&mut self.menu)
+    }
+
+    pub fn category(&mut self, category: CategoryBuilder) {
+        self.menu.categories.push(category.build());
+    }
+
+    pub fn daypart(&mut self, daypart: Daypart) {
+        self.dayparts.push(daypart);
+    }
+
+    /// Limits the selected items to `daypart`. Items limited to several
+    /// dayparts sell during any of them.
+    pub fn limit_items_to_daypart(&mut self, daypart: &str, selection: Selection) {
+        self.daypart_items
+            .push((daypart.to_string(), selection.into()));
+    }
+
+    pub fn limit_category_to_daypart(&mut self, category: &str, daypart: &str) {
+        self.daypart_categories
+            .entry(category.to_string())
+            .or_default()
+            .push(daypart.to_string());
+    }
+
+    /// Allergen and dietary flags of `ingredient`.
+    pub fn ingredient_flags(&mut self, ingredient: ItemHandle, flags: DietaryFlags) {
+        if self
+            .ingredient_flags
+            .insert(ingredient.id(), flags)
+            .is_some()
+        {
+            let item = self
+                .menu
+                .items
+                .iter()
+                .find(|item| item.id == ingredient.id())
+                .expect("Couldnt find a registered ingredient");
+            panic!("allergen flags for {} set twice", item.long_name);
+        }
+    }
+
+    /// Taxes the selected items as `category` instead of food.
+    pub fn tax_category(&mut self, selection: Selection, category: TaxCategory) {
+        self.tax_categories.push((selection.into(), category));
+    }
+
+    /// Splits the selected combos' own price over their components for tax.
+    pub fn split_tax(&mut self, selection: Selection) {
+        self.tax_splits.push(selection.into());
+    }
+
+    fn finish(mut self) -> MenuDocument {
+        // Variation lists are shared between items, so each item gets its own
+        // variation ids, derived from its id and the list's.
+        for item in &mut self.menu.items {
+            for variation in &mut item.variations {
+                let id = hash::uuid(&format!("{}/{}", item.id, variation.id));
+                if item.default_variation == variation.id {
+                    item.default_variation = id;
+                }
+                variation.id = id;
+            }
+        }
+
+        let known = |name: &String| self.dayparts.iter().any(|daypart| &daypart.name == name);
+        let mut items: BTreeMap<ID, Vec<String>> = BTreeMap::new();
+        for (daypart, selection) in &self.daypart_items {
+            if !known(daypart) {
+                panic!("items limited to undefined daypart {}", daypart);
+            }
+            for item in self
+                .menu
+                .items
+                .iter()
+                .filter(|item| selection::matches(selection, item))
+            {
+                items.entry(item.id).or_default().push(daypart.clone());
+            }
+        }
+        for (category, dayparts) in &self.daypart_categories {
+            if !self.menu.categories.iter().any(|c| &c.name == category) {
+                panic!("daypart assigned to unknown category {}", category);
+            }
+            if let Some(daypart) = dayparts.iter().find(|daypart| !known(daypart)) {
+                panic!(
+                    "category {} limited to undefined daypart {}",
+                    category, daypart
+                );
+            }
+        }
+
+        let mut tax = TaxAssignments::default();
+        for item in &self.menu.items {
+            for (selection, category) in &self.tax_categories {
+                if selection::matches(selection, item) {
+                    if let Some(previous) = tax.items.insert(item.id, *category) {
+                        if previous != *category {
+                            panic!(
+                                "{} assigned tax categories {:?} and {:?}",
+                                item.long_name, previous, category
+                            );
+                        }
+                    }
+                }
+            }
+            if self
+                .tax_splits
+                .iter()
+                .any(|selection| selection::matches(selection, item))
+            {
+                tax.split.insert(item.id);
+            }
+        }
+
+        MenuDocument {
+            tax,
+            dietary: Dietary::from_menu(&self.menu, self.ingredient_flags),
+            dayparts: DaypartSchedule {
+                dayparts: self.dayparts,
+                items,
+                categories: self.daypart_categories,
+            },
+            menu: self.menu,
+            version: None,
+        }
+    }
+}
+
+fn dynamic_add_ons(ingredients: &[ItemHandle], add_ons: &[ItemHandle]) -> SlotBuilder {
+    let final_add_ons: Vec<ItemHandle> = add_ons
+        .iter()
+        .filter(|item| !ingredients.contains(item))
+        .copied()
+        .collect();
+    SlotBuilder::new(
+        "Add Ons",
+        SlotType::Ingredient,
+        Selection::AnyItem(&final_add_ons),
+    )
+    .collapsed(false)
+    .minimum_quantity(0)
+}
@@ -0,0 +1,38 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle};
+use super::Registry;
+use adjunct::Modification;
+
+/// What later sections use from this one.
+pub struct Proteins {
+    pub bacon: ItemHandle,
+    pub chicken: ItemHandle,
+    pub fish: ItemHandle,
+    pub fried_chicken: ItemHandle,
+    pub ground_beef: ItemHandle,
+    pub sausage: ItemHandle,
+    pub shrimp: ItemHandle,
+    pub steak: ItemHandle,
+}
+
+pub fn register(reg: &mut Registry) -> Proteins {
+    // Proteins
+    let sausage = reg.add_item(ItemBuilder::new("aa9f634c-3547-4e28-9405-f760927f77f3", "Chorizo Sausage", "Saus").price(109).plu("999003").tags(&[tags::BREAKFAST_PROTEINS]).modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 109));
+    let bacon = reg.add_item(ItemBuilder::new("abed8ab2-3d94-4bcf-b2fd-34c698659adc", "Bacon", "BA").price(109).plu("999002").tags(&[tags::BREAKFAST_PROTEINS]).modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 109));
+    let ground_beef = reg.add_item(ItemBuilder::new("ac99e1be-e648-4b97-b878-b8e1ca558530", "Beef", "BF").price(69).plu("999014").tags(&[tags::PROTEINS, tags::BREAKFAST_PROTEINS, tags::GRILLED_BURRITO_PROTEINS]).modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 69));
+    let chicken = reg.add_item(ItemBuilder::new("acea615b-8b2c-47ae-b7b7-84eeb8d2ec15", "Chicken", "CK").price(125).plu("999005").tags(&[tags::PROTEINS, tags::STREET_TACO_PROTEINS, tags::GRILLED_BURRITO_PROTEINS]).modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 125));
+    let steak = reg.add_item(ItemBuilder::new("ae3a6089-33c9-43f8-8052-e5b2d54fe410", "Sirloin Steak", "STK").price(159).plu("6950").tags(&[tags::PROTEINS, tags::BREAKFAST_PROTEINS, tags::STREET_TACO_PROTEINS, tags::GRILLED_BURRITO_PROTEINS]).modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 159));
+    let fried_chicken = reg.add_item(ItemBuilder::new("ae87394f-d805-4034-8870-7ae11905dac6", "Fried Chicken", "FC").price(119).plu("999153").tags(&[tags::PROTEINS, tags::GRILLED_BURRITO_PROTEINS]).modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 119));
+    let shrimp = reg.add_item(ItemBuilder::new("aef23599-1f33-412e-aae0-50fda9cc6c38", "Shrimp", "SHP").price(99).plu("999200").tags(&[tags::STREET_TACO_PROTEINS]).modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 99));
+    let fish = reg.add_item(ItemBuilder::new("af04bef4-8e1b-417e-b02d-e282a5676f65", "Fish", "FSH").price(99).plu("999058").modifiers(&[Modification::Extra, Modification::Light]).upcharge(Modification::Extra, 99));
+
+    Proteins {
+        bacon,
+        chicken,
+        fish,
+        fried_chicken,
+        ground_beef,
+        sausage,
+        shrimp,
+        steak,
+    }
+}
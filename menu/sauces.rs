@@ -0,0 +1,35 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle};
+use super::Registry;
+use adjunct::Modification;
+
+/// What later sections use from this one.
+pub struct Sauces {
+    pub chimichurri: ItemHandle,
+    pub creamy_chipotle: ItemHandle,
+    pub guacamole: ItemHandle,
+    pub house_salsa: ItemHandle,
+    pub mild_sauce: ItemHandle,
+    pub sour_cream: ItemHandle,
+    pub super_hot: ItemHandle,
+}
+
+pub fn register(reg: &mut Registry) -> Sauces {
+    // Sauces
+    let mild_sauce = reg.add_item(ItemBuilder::new("cde0371e-6e80-4e06-8988-d8084cd52e52", "Mild Sauce", "MS").tags(&[tags::SAUCES]).modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("999015"));
+    let house_salsa = reg.add_item(ItemBuilder::new("ce864edc-60f4-414e-adf9-edc291e55e1d", "SD House", "SD HSE").tags(&[tags::SAUCES]).modifiers(&[Modification::Extra, Modification::Light]).price(0).plu("999092"));
+    let chimichurri = reg.add_item(ItemBuilder::new("d5acba3e-2e53-4b2b-8b61-1dbbc8356663", "Chimichurri", "CSC").tags(&[tags::SAUCES]).modifiers(&[Modification::Extra, Modification::Light]).price(59).plu("999176").upcharge(Modification::Extra, 59));
+    let creamy_chipotle = reg.add_item(ItemBuilder::new("d5e359e9-a5c8-4243-8d39-6f19fecd64ae", "Creamy Chipotle", "CCS").tags(&[tags::SAUCES]).modifiers(&[Modification::Extra, Modification::Light]).price(29).plu("8690").upcharge(Modification::Extra, 29));
+    let super_hot = reg.add_item(ItemBuilder::new("c82e86c8-2962-40cb-96af-456bcdebab33", "Super Hot", "SH").modifiers(&[Modification::Extra, Modification::Light]).plu("999027"));
+    let sour_cream = reg.add_item(ItemBuilder::new("d8f7a827-94c2-4eac-959d-aa2d260ced28", "Sour Cream", "SC").tags(&[tags::SAUCES, tags::SAUCE_ADDITION]).modifiers(&[Modification::Extra, Modification::Light]).price(39).plu("999025").upcharge(Modification::Extra, 39));
+    let guacamole = reg.add_item(ItemBuilder::new("d93bbcb5-e3b4-4b3a-b638-f87a50d25b5c", "Guacamole", "GUAC").tags(&[tags::SAUCES, tags::SAUCE_ADDITION]).modifiers(&[Modification::Extra, Modification::Light]).price(69).plu("999009").upcharge(Modification::Extra, 69));
+
+    Sauces {
+        chimichurri,
+        creamy_chipotle,
+        guacamole,
+        house_salsa,
+        mild_sauce,
+        sour_cream,
+        super_hot,
+    }
+}
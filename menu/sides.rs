@@ -0,0 +1,398 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle, PriceOverrideBuilder, Selection, SlotBuilder, VariationBuilder};
+use super::{dynamic_add_ons, Registry};
+use super::ingredients::Ingredients;
+use super::proteins::Proteins;
+use super::sauces::Sauces;
+use adjunct::{Modification, SlotType};
+
+/// What later sections use from this one.
+pub struct Sides {
+    pub add_ons_vec: Vec<ItemHandle>,
+    pub dressings: SlotBuilder,
+    pub potato_ole_side: ItemHandle,
+    pub sides: SlotBuilder,
+}
+
+pub fn register(
+    reg: &mut Registry,
+    proteins: &Proteins,
+    sauces: &Sauces,
+    ingredients: &Ingredients,
+) -> Sides {
+    let bacon = proteins.bacon;
+    let cheddar_cheese = ingredients.cheddar_cheese;
+    let chicken = proteins.chicken;
+    let chipotle_lime = ingredients.chipotle_lime;
+    let corn_chips = ingredients.corn_chips;
+    let fried_chicken = proteins.fried_chicken;
+    let ground_beef = proteins.ground_beef;
+    let guacamole = sauces.guacamole;
+    let house_salsa = sauces.house_salsa;
+    let jalapeno = ingredients.jalapeno;
+    let jalapeno_ranch = ingredients.jalapeno_ranch;
+    let lettuce = ingredients.lettuce;
+    let mild_sauce = sauces.mild_sauce;
+    let nacho_cheese = ingredients.nacho_cheese;
+    let onions = ingredients.onions;
+    let pico_de_gallo = ingredients.pico_de_gallo;
+    let poblano_peppers = ingredients.poblano_peppers;
+    let potato_ole = ingredients.potato_ole;
+    let potato_ole_seasoning = ingredients.potato_ole_seasoning;
+    let queso_fresco = ingredients.queso_fresco;
+    let ranch = ingredients.ranch;
+    let refried_beans = ingredients.refried_beans;
+    let sausage = proteins.sausage;
+    let side_ranch = ingredients.side_ranch;
+    let sour_cream = sauces.sour_cream;
+    let steak = proteins.steak;
+    let super_hot = sauces.super_hot;
+    let tomato = ingredients.tomato;
+
+    // Sides
+
+    let side_variations = vec![
+        VariationBuilder::new("Kid", "64").prefix("KD").price(100).build(),
+        VariationBuilder::new("Small", "128").prefix("SM").price(230).build(),
+        VariationBuilder::new("Medium", "256").prefix("MD").price(289).build(),
+        VariationBuilder::new("Large", "512").prefix("LG").price(359).build(),
+    ];
+
+    let sauces = SlotBuilder::new("Sauces", SlotType::Items, Selection::Tag(tags::SAUCES))
+        .collapsed(false)
+        .price_override(PriceOverrideBuilder::new().tags(&[tags::SAUCES]).price(0))
+        .defaults(&[mild_sauce]);
+
+    let potato_ole_side = reg.add_item(
+        ItemBuilder::new("051aba55-b9f0-418b-a6e4-623f7992b28f", "Potato Oles", "PO")
+            .price(0).plu("1830")
+            .tags(&[tags::SIDES, tags::COMBOSIDES])
+            .variations(&side_variations)
+            .priority(2)
+            .modifiers(&[Modification::Custom("Well Done".to_string())])
+            .slot(
+                SlotBuilder::new("Seasoning", SlotType::Ingredient, Selection::Item(potato_ole_seasoning))
+                    .defaults(&[potato_ole_seasoning])
+            )
+            .slot(
+                SlotBuilder::new("Sauce", SlotType::Items, Selection::AnyItem(&[nacho_cheese, guacamole, queso_fresco, sour_cream]))
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::SAUCES]).price(99))
+            )
+    );
+
+    let black_beans_and_rice = reg.add_item(
+        ItemBuilder::new("076d422e-8b3e-42dc-8844-5c92caf6a9a1", "Black Beans and Rice", "SD-BBNR")
+            .price(189).plu("7413")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_shrimp = reg.add_item(
+        ItemBuilder::new("0794119d-0762-403e-bb96-8ca5efdbbed3", "Shrimp", "SD-SHRIMP")
+            .price(129).plu("7943")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_cilantro_lime_rice = reg.add_item(
+        ItemBuilder::new("07eb05af-bb77-48e1-b4ec-5346e651a1e0", "Cilantro Lime Rice", "SD-CLR")
+            .price(189).plu("7973")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_black_beans = reg.add_item(
+        ItemBuilder::new("0acc1e85-1361-42a5-950e-458bacd479de", "Black Beans", "SD-BBN")
+            .price(189).plu("7983")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_refried_beans = reg.add_item(
+        ItemBuilder::new("0b1cec50-0c07-4eea-8238-5c0468ee6744", "Refried Beans", "SD-BN")
+            .price(265).plu("8003")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_breakfast_bacon = reg.add_item(
+        ItemBuilder::new("0b3741c2-7015-4a8b-b589-20efb8003ac0", "Bacon", "SD-BC")
+            .price(110).plu("8013")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_breakfast_sausage = reg.add_item(
+        ItemBuilder::new("0bb44494-3a40-45f8-a33a-8cba00972296", "Sausage", "SD-SAU")
+            .price(110).plu("8023")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_cheddar_cheese = reg.add_item(
+        ItemBuilder::new("0e1dab08-0198-418e-a66b-2bd9fb73a36e", "Cheddar Cheese", "SD-CH")
+            .price(99).plu("8033")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_chicken = reg.add_item(
+        ItemBuilder::new("0ec822f5-407a-4e95-857f-227cadece6e1", "Chicken", "SD-CK")
+            .price(149).plu("8043")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_guacamole = reg.add_item(
+        ItemBuilder::new("0fac58aa-3191-4de1-815e-d3b3836cbcac", "Guacamole", "SD-GUA")
+            .price(129).plu("8093")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_house_dressing = reg.add_item(
+        ItemBuilder::new("0fb7cc17-fbf5-4675-9cb1-108689399b9a", "House Dressing", "SD-HD")
+            .price(99).plu("8113")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_jalapeno = reg.add_item(
+        ItemBuilder::new("136933de-3624-44ab-b858-7f1c752030d5", "Jalapeno", "SD-JAL")
+            .price(0).plu("8123")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_lettuce = reg.add_item(
+        ItemBuilder::new("1482b7c4-cd74-4dd8-b961-32121d32d390", "Lettuce", "SD-LET")
+            .price(85).plu("8133")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_beef = reg.add_item(
+        ItemBuilder::new("153075ce-6254-4e94-bb51-d2b9f261bac5", "Beef", "SD-BF")
+            .price(139).plu("8143")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_mild_sauce = reg.add_item(
+        ItemBuilder::new("15701ac3-a4c7-4e31-bc98-5cad8e1c3a17", "Mild Sauce", "SD-MS")
+            .price(0).plu("8153")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_nacho_cheese = reg.add_item(
+        ItemBuilder::new("1662608f-6849-4afc-a542-cc69aaf3c776", "Nacho Cheese", "SD-NAC-CH")
+            .price(99).plu("8163")
+            .tags(&[tags::SIDE_OF, tags::QUICK])
+    );
+
+    let side_of_onions = reg.add_item(
+        ItemBuilder::new("168c1c1d-8bbb-4cea-88bc-670e790c2bcf", "Onions", "SD-CRN-ON")
+            .price(29).plu("8193")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_pico_de_gallo = reg.add_item(
+        ItemBuilder::new("169bc136-28fb-451e-9d59-4f2137c60285", "Pico De Gallo", "SD-PICO")
+            .price(0).plu("8203")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_ranch = reg.add_item(
+        ItemBuilder::new("175ed97f-3251-4f6b-9183-f6c14ad22d28", "Ranch", "SD-RAN")
+            .price(99).plu("8213")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_queso_fresco = reg.add_item(
+        ItemBuilder::new("190363ad-3dbd-4e74-af1b-a4a4b355b4ec", "Queso Fresco", "SD-QFR")
+            .price(99).plu("8223")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_salsa = reg.add_item(
+        ItemBuilder::new("1967cdc9-4ee2-4f58-96be-66b55e08cc1f", "Salsa", "SD-SAL")
+            .price(0).plu("8233")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_potato_ole_seasoning = reg.add_item(
+        ItemBuilder::new("1a10c946-5848-418c-a581-6a63891d9176", "Ole Seasoning", "SD-SEA")
+            .price(99).plu("8243")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_sour_cream = reg.add_item(
+        ItemBuilder::new("1a5561cc-58a9-4790-a3cf-a8a35e13a156", "Sour Cream", "SD-SC")
+            .price(99).plu("8253")
+            .tags(&[tags::SIDE_OF, tags::QUICK])
+    );
+
+    let side_of_super_hot = reg.add_item(
+        ItemBuilder::new("1b44e6f1-0e2c-492e-aaa0-35fbbcadd9bc", "Super Hot", "SD-SH")
+            .price(0).plu("8273")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_tomato = reg.add_item(
+        ItemBuilder::new("1c8d7917-ddf0-4ee1-b7ae-883d82719b24", "Tomato", "SD-TOM")
+            .price(50).plu("8283")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_poblano_peppers = reg.add_item(
+        ItemBuilder::new("1d11c955-e4c0-424a-bc1e-d72a8b64778d", "Poblano Peppers", "SD-PBLANO")
+            .price(99).plu("6543")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_fat_free_ranch = reg.add_item(
+        ItemBuilder::new("1d37ce75-ceff-447c-8f71-1e213189e0d3", "Fat Free Ranch", "SD-FFRAN")
+            .price(0).plu("8413")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_thousand_island = reg.add_item(
+        ItemBuilder::new("1e10ed60-42ff-43d4-af05-604978db1a76", "Thousand Island", "SD-TI")
+            .price(0).plu("8416")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_italian = reg.add_item(
+        ItemBuilder::new("1e654159-5e05-4b02-a7d2-688a0bf11226", "Italian", "SD-ITAL")
+            .price(0).plu("8445")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_fat_free_french = reg.add_item(
+        ItemBuilder::new("1ed6510f-1bd2-42a1-9754-7ace75f303c2", "Fat Free French", "SD-FFFREN")
+            .price(0).plu("8421")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_chimichurri = reg.add_item(
+        ItemBuilder::new("1f9fba0d-8989-483f-bfcb-e25e64d3c5e5", "Chimichurri", "SD-CSC")
+            .price(79).plu("8673")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_pepperjack = reg.add_item(
+        ItemBuilder::new("1fd44683-692b-460b-aed5-2f3ae0dbd29d", "Pepper Jack", "SD-PPR-JK")
+            .price(79).plu("8683")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_creamy_chipotle = reg.add_item(
+        ItemBuilder::new("20cc6d66-a1fd-4dbd-a8c6-581250344cdc", "Creamy Chipotle", "SD-CCS")
+            .price(79).plu("8693")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_ketchup = reg.add_item(
+        ItemBuilder::new("20e666fa-7580-4b11-8d61-50a233f15d73", "Ketchup", "SD-KETCH")
+            .price(0).plu("8720")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_lime_sour_cream = reg.add_item(
+        ItemBuilder::new("2128b647-35e6-4080-9c94-4bc16a4d8eb8", "Lime Sour Cream", "SD-SC-LIM")
+            .price(99).plu("6553")
+            .tags(&[tags::SIDE_OF])
+    );
+
+    let side_of_fried_chicken = reg.add_item(
+        ItemBuilder::new("216379be-e32d-46cb-a567-69e3935ae187", "Fried Chicken", "SD-FC")
+            .price(589).plu("12550")
+            .tags(&[tags::SIDE_OF])
+            .slot(
+                SlotBuilder::new("Sauces", SlotType::Replace, Selection::AnyItem(&[jalapeno_ranch, chipotle_lime, ranch]))
+            )
+    );
+
+    let cheesy_bacon_nachos = reg.add_item(
+        ItemBuilder::new("25f65a0f-e351-4c68-be1e-573b9e425c81", "Cheesy Bacon Nachos", "CH-BC-NACH")
+            .price(200).plu("12260")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[bacon, jalapeno, nacho_cheese, corn_chips, side_ranch]))
+                    .collapsed(false)
+                    .defaults(&[bacon, jalapeno, nacho_cheese, corn_chips, side_ranch])
+            )
+    );
+
+    let cheesy_bacon_ranch_loaded_potatos = reg.add_item(
+        ItemBuilder::new("26b97fb6-0f51-40a4-85cc-9ce6707092e4", "Cheesy Bacon Ranch Loaded Potatoes", "CH-BC-LOP")
+            .price(300).plu("12270")
+            .tags(&[tags::VALUE, tags::SECONDARYSIDE])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[bacon, nacho_cheese, potato_ole, side_ranch, potato_ole_seasoning]))
+                    .defaults(&[bacon, nacho_cheese, potato_ole, side_ranch, potato_ole_seasoning])
+            )
+    );
+
+    let dressings = SlotBuilder::new("Dressing", SlotType::Items, Selection::Tag(tags::DRESSING))
+        .collapsed(false)
+        .minimum_quantity(0)
+        .free_quantity(1);
+
+    let add_ons = SlotBuilder::new("Add Ons", SlotType::Ingredient, Selection::AnyItem(&[jalapeno, onions, guacamole, refried_beans, pico_de_gallo, house_salsa, lettuce, tomato, sour_cream, poblano_peppers, bacon, potato_ole])).collapsed(false).minimum_quantity(0);
+    let add_ons_vec = vec![
+        sour_cream,
+        guacamole,
+        super_hot,
+        jalapeno,
+        onions,
+        refried_beans,
+        pico_de_gallo,
+        house_salsa,
+        lettuce,
+        tomato,
+        poblano_peppers,
+        bacon,
+        ranch,
+        ground_beef,
+        chicken,
+        fried_chicken,
+        sausage,
+        steak,
+        potato_ole
+    ];
+
+    let side_salad = reg.add_item(
+        ItemBuilder::new("2a58c07b-e1ca-49e3-be13-f3d650bcc816", "Side Salad", "SAL")
+            .tags(&[tags::SIDES, tags::COMBOSIDES])
+            .price(265).plu("3530")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[lettuce, tomato, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[lettuce, tomato, cheddar_cheese])
+            )
+            .slot(dressings.clone())
+            .slot(dynamic_add_ons(&[cheddar_cheese, lettuce, tomato], &add_ons_vec))
+    );
+
+    let chips_and_nacho_cheese = reg.add_item(
+        ItemBuilder::new("2a753fab-e2a0-4b88-aa90-1453c751fb9b", "Nachos", "Nachos")
+            .price(249).plu("2290")
+            .tags(&[tags::SIDES, tags::COMBOSIDES])
+            .slot(
+                SlotBuilder::new("Sauce", SlotType::Items, Selection::AnyItem(&[nacho_cheese, guacamole, queso_fresco, sour_cream]))
+                    .minimum_quantity(1)
+                    .free_quantity(1)
+            )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[corn_chips]))
+                    .collapsed(false)
+                    .defaults(&[corn_chips])
+            )
+            .slot(dynamic_add_ons(&[], &add_ons_vec))
+    );
+
+    let sides = SlotBuilder::new("Sides", SlotType::Replace, Selection::Tag(tags::COMBOSIDES))
+        .collapsed(false)
+        .hidden(Selection::Tag(tags::SECONDARYSIDE))
+        .price_override(PriceOverrideBuilder::new().items(&[potato_ole_side]).price(0).variation("Small"))
+        .price_override(PriceOverrideBuilder::new().items(&[potato_ole_side]).price(89).variation("Medium"))
+        .price_override(PriceOverrideBuilder::new().items(&[potato_ole_side]).price(129).variation("Large"))
+        .price_override(PriceOverrideBuilder::new().items(&[chips_and_nacho_cheese]).price(0))
+        .price_override(PriceOverrideBuilder::new().items(&[side_salad]).price(0))
+        .price_override(PriceOverrideBuilder::new().items(&[refried_beans]).price(0))
+        .defaults(&[potato_ole_side])
+        .default_quantity(1)
+        .minimum_quantity(1);
+
+    Sides {
+        add_ons_vec,
+        dressings,
+        potato_ole_side,
+        sides,
+    }
+}
@@ -0,0 +1,434 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle, PriceOverrideBuilder, Selection, SlotBuilder};
+use super::{dynamic_add_ons, Registry};
+use super::ingredients::Ingredients;
+use super::proteins::Proteins;
+use super::sauces::Sauces;
+use super::sides::Sides;
+use adjunct::SlotType;
+
+/// What later sections use from this one.
+pub struct Specialties {
+    pub beef_quesadilla: ItemHandle,
+    pub beef_super_nachos: ItemHandle,
+    pub beef_taco_salad: ItemHandle,
+    pub chicken_quesadilla: ItemHandle,
+    pub chicken_super_nachos: ItemHandle,
+    pub chicken_taco_salad: ItemHandle,
+    pub cinnamon_sugar_tortilla_crisps: ItemHandle,
+    pub fried_chicken_potato_snacker: ItemHandle,
+    pub mexican_donut_bites: ItemHandle,
+    pub quesadilla: ItemHandle,
+    pub snack_cheese_quesadilla: ItemHandle,
+    pub steak_quesadilla: ItemHandle,
+    pub steak_super_nachos: ItemHandle,
+    pub steak_taco_salad: ItemHandle,
+    pub taco_salad_shell_item: ItemHandle,
+}
+
+pub fn register(
+    reg: &mut Registry,
+    proteins: &Proteins,
+    sauces: &Sauces,
+    ingredients: &Ingredients,
+    sides: &Sides,
+) -> Specialties {
+    let add_ons_vec = sides.add_ons_vec.clone();
+    let bacon = proteins.bacon;
+    let cheddar_cheese = ingredients.cheddar_cheese;
+    let cheese = ingredients.cheese;
+    let chicken = proteins.chicken;
+    let chipotle_lime = ingredients.chipotle_lime;
+    let cinnamon_sugar = ingredients.cinnamon_sugar;
+    let corn_chips = ingredients.corn_chips;
+    let cream_cheese_icing = ingredients.cream_cheese_icing;
+    let dressings = sides.dressings.clone();
+    let fried_chicken = proteins.fried_chicken;
+    let ground_beef = proteins.ground_beef;
+    let guacamole = sauces.guacamole;
+    let house_salsa = sauces.house_salsa;
+    let jalapeno_ranch = ingredients.jalapeno_ranch;
+    let large_tortilla = ingredients.large_tortilla;
+    let lettuce = ingredients.lettuce;
+    let nacho_cheese = ingredients.nacho_cheese;
+    let pico_de_gallo = ingredients.pico_de_gallo;
+    let poblano_peppers = ingredients.poblano_peppers;
+    let potato_ole = ingredients.potato_ole;
+    let potato_ole_seasoning = ingredients.potato_ole_seasoning;
+    let ranch = ingredients.ranch;
+    let refried_beans = ingredients.refried_beans;
+    let side_ranch = ingredients.side_ranch;
+    let small_tortilla = ingredients.small_tortilla;
+    let sour_cream = sauces.sour_cream;
+    let steak = proteins.steak;
+    let super_size_variations = ingredients.super_size_variations.clone();
+    let taco_bowl = ingredients.taco_bowl;
+    let taco_proteins = ingredients.taco_proteins.clone();
+    let tomato = ingredients.tomato;
+
+    let dessert_add_ons = SlotBuilder::new("Add Ons", SlotType::Ingredient, Selection::AnyItem(&[cream_cheese_icing, cinnamon_sugar]))
+        .collapsed(false)
+        .minimum_quantity(0)
+        .price_override(PriceOverrideBuilder::new().price(25).items(&[cream_cheese_icing, cinnamon_sugar]));
+
+    let snack_chicken_quesadilla = reg.add_item(
+        ItemBuilder::new("26e2dc82-1b09-4483-b63f-62a480a9000c", "Snack Chicken Quesadilla", "SNK-QUES-CK")
+            .price(100).plu("12300")
+            .tags(&[tags::VALUE])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[cheddar_cheese, small_tortilla, chicken]))
+                    .collapsed(false)
+                    .defaults(&[cheddar_cheese, small_tortilla, chicken])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese], &add_ons_vec))
+    );
+
+    let chicken_bacon_guac_street_taco = reg.add_item(
+        ItemBuilder::new("c36a55db-e2bb-4ddb-95d6-f2c7f462e5b8", "Chkn Bcn Guac Taco", "ST-CBG")
+            .price(200).plu("12280")
+            .tags(&[tags::QUICK, tags::VALUE])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, bacon, guacamole, cheddar_cheese, lettuce, small_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[chicken, bacon, guacamole, cheddar_cheese, lettuce, small_tortilla])
+            )
+            .slot(dynamic_add_ons(&[chicken, bacon, guacamole, cheddar_cheese, lettuce, small_tortilla], &add_ons_vec))
+    );
+
+    let fried_chicken_potato_snacker = reg.add_item(
+        ItemBuilder::new("27fe823f-1d98-43bd-bf1a-8606c45c5d78", "Fried Chicken Potato Ole Snacker", "SNK-FC-PO")
+            .price(449).plu("12540")
+            .tags(&[tags::VALUE])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[potato_ole, fried_chicken]))
+                    .collapsed(false)
+                    .defaults(&[potato_ole, fried_chicken])
+            )
+            .slot(
+                SlotBuilder::new("Sauce", SlotType::Replace, Selection::AnyItem(&[jalapeno_ranch, chipotle_lime, ranch]))
+                    .collapsed(false)
+                    .free_quantity(1)
+                    .minimum_quantity(1)
+                    .maximum_quantity(1)
+            )
+            .slot(dynamic_add_ons(&[potato_ole, fried_chicken, ranch], &add_ons_vec))
+    );
+
+    let snack_cheese_quesadilla = reg.add_item(
+        ItemBuilder::new("290da8ba-7956-49c4-8b4d-8ded9d12e442", "Snack Cheese Quesadilla", "SNK-QUES-CH")
+            .price(199).plu("1960")
+            .tags(&[tags::SIDES])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[cheddar_cheese, small_tortilla]))
+                    .collapsed(false)
+                    .defaults(&[cheddar_cheese, small_tortilla])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, small_tortilla], &add_ons_vec))
+    );
+
+    let _cheesy_rollup = reg.add_item(
+        ItemBuilder::new("2ec0ece1-b573-4e42-8174-af927af398ae", "Cheese Rollup", "ROLL-CHZ")
+            .price(199).plu("3910")
+            .tags(&[tags::SIDES])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[small_tortilla, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[small_tortilla, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, small_tortilla], &add_ons_vec))
+    );
+
+    let _cinnamon_sugar_tortilla_crisps = reg.add_item(
+        ItemBuilder::new("307902b3-6853-49e0-9034-478fda7d543b", "Cinnamon Sugar Tortilla Crisps", "CIN-CHIPS")
+            .price(100).plu("12290")
+            .tags(&[tags::DESSERT, tags::VALUE])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[cinnamon_sugar]))
+                    .collapsed(false)
+                    .defaults(&[cinnamon_sugar])
+            )
+    );
+
+    let _mexican_donut_bites = reg.add_item(
+        ItemBuilder::new("30acc589-5d81-4f30-8e9a-55e69542c144", "Mexican Donut Bites", "D-BITES")
+            .price(239).plu("2860")
+            .tags(&[tags::DESSERT])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[cream_cheese_icing, cinnamon_sugar]))
+                    .collapsed(false)
+                    .defaults(&[cream_cheese_icing, cinnamon_sugar])
+            )
+            .slot(dessert_add_ons.clone())
+    );
+
+    let quesadilla = reg.add_item(
+        ItemBuilder::new("33e07d2a-7b5d-4b92-8437-f2d25a46072c", "Cheese Quesadilla", "QUES-CH")
+            .price(399).plu("2500")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::SPECIALTIES])
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, chicken, fried_chicken, cheese, steak]))
+                    .defaults(&[cheese])
+            )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[nacho_cheese, large_tortilla, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[nacho_cheese, large_tortilla, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[nacho_cheese, cheddar_cheese, large_tortilla], &add_ons_vec))
+    );
+
+    let steak_quesadilla = reg.add_item(
+        ItemBuilder::new("3572b3f2-1b26-42d4-a690-072c66ce43f0", "Steak Quesadilla", "QUES-STK")
+            .price(589).plu("2520")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, large_tortilla, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[steak, large_tortilla, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, large_tortilla], &add_ons_vec))
+    );
+
+    let beef_quesadilla = reg.add_item(
+        ItemBuilder::new("365940ac-950e-451b-ba09-0f3ef5640c36", "Beef Quesadilla", "QUES-BF")
+            .price(499).plu("2501")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, large_tortilla, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, large_tortilla, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, large_tortilla], &add_ons_vec))
+    );
+
+    let chicken_quesadilla = reg.add_item(
+        ItemBuilder::new("37104689-b96a-4777-a9f1-8396f615e087", "Chicken Quesadilla", "QUES-CK")
+            .price(545).plu("2510")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, large_tortilla, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[chicken, large_tortilla, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, large_tortilla], &add_ons_vec))
+    );
+
+    let _cheesy_quesadilla = reg.add_item(
+        ItemBuilder::new("3739089c-5838-419a-80a6-9e8b5652c6ef", "Cheesy Quesadilla", "Chsy Ques")
+            .price(259).plu("9220")
+            .tags(&[tags::DINNER, tags::LUNCH])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[large_tortilla, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[large_tortilla, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, large_tortilla], &add_ons_vec))
+    );
+
+    let beef_super_potato_ole = reg.add_item(
+        ItemBuilder::new("375fd04f-b156-4f79-a5b5-1153764e817f", "Super Potato Oles", "SPO")
+            .price(719).plu("2200")
+            .slot(taco_proteins.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning], &add_ons_vec))
+    );
+
+    let chicken_super_potato_ole = reg.add_item(
+        ItemBuilder::new("4f10fe83-a1d9-4d05-b936-5174d4f1f9a2", "Chicken Super Potato Oles", "SPO CHK")
+            .price(729).plu("2210")
+            .slot(taco_proteins.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning]))
+                    .collapsed(false)
+                    .defaults(&[chicken, potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning], &add_ons_vec))
+    );
+
+    let steak_super_potato_ole = reg.add_item(
+        ItemBuilder::new("754f285e-89e7-4a8e-9c45-94b752b9d533", "Steak Super Potato Oles", "SPO STK")
+            .price(789).plu("2220")
+            .slot(taco_proteins.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning]))
+                    .collapsed(false)
+                    .defaults(&[steak, potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning], &add_ons_vec))
+    );
+
+    let bean_super_potato_ole = reg.add_item(
+        ItemBuilder::new("972d7a92-4d43-41a1-9726-80fde50ef960", "Bean Super Potato Oles", "SPO BN")
+            .price(719).plu("2240")
+            .slot(taco_proteins.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[refried_beans, potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning]))
+                    .collapsed(false)
+                    .defaults(&[refried_beans, potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning])
+            )
+            .slot(dynamic_add_ons(&[potato_ole, nacho_cheese, cheddar_cheese, tomato, guacamole, sour_cream, potato_ole_seasoning], &add_ons_vec))
+    );
+
+    let super_potato_ole_shell_item = reg.add_item(
+        ItemBuilder::new("980d7c13-55ee-4ed1-9cd1-77dd857eb628", "Super Potato Ole", "SPOL")
+            .tags(&[tags::SPECIALTIES, tags::QUICK])
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Super Potato Ole", SlotType::ItemShell, Selection::AnyItem(&[beef_super_potato_ole, chicken_super_potato_ole, steak_super_potato_ole, bean_super_potato_ole]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_super_potato_ole])
+            )
+    );
+
+    let _super_nachos = reg.add_item(
+        ItemBuilder::new("3b01b549-d23d-44eb-9468-f28c31e76629", "Super Nachos", "SN")
+            .price(719).plu("2300")
+            .tags(&[tags::DINNER, tags::LUNCH])
+            .variations(&super_size_variations)
+            .default_variation(&super_size_variations[0])
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Ingredient, Selection::Tag(tags::PROTEINS))
+                    .defaults(&[ground_beef, refried_beans])
+            )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[cheddar_cheese, nacho_cheese, corn_chips, sour_cream, tomato]))
+                    .collapsed(false)
+                    .defaults(&[cheddar_cheese, nacho_cheese, corn_chips, sour_cream, tomato])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, nacho_cheese, corn_chips, sour_cream, tomato], &add_ons_vec))
+    );
+
+    let beef_super_nachos = reg.add_item(
+        ItemBuilder::new("3c096c23-a8c5-4f77-bdae-a0a7ef8b2e6b", "Super Nachos", "SN")
+            .price(719).plu("2300")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato])
+            )
+            .slot(dynamic_add_ons(&[ground_beef, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato], &add_ons_vec))
+    );
+
+    let chicken_super_nachos = reg.add_item(
+        ItemBuilder::new("3c4eb7e4-7961-4763-bf86-6f81d5662821", "Chicken Super Nachos", "NACH-SUP-CK")
+            .price(765).plu("2310")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[chicken, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato])
+            )
+            .slot(dynamic_add_ons(&[chicken, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato], &add_ons_vec))
+    );
+
+    let steak_super_nachos = reg.add_item(
+        ItemBuilder::new("3dab11ac-7f04-4dac-b19e-ca0fc09bb4f9", "Steak Super Nachos", "NACH-SUP-STK")
+            .price(779).plu("2320")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato]))
+                    .collapsed(false)
+                    .defaults(&[steak, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato])
+            )
+            .slot(dynamic_add_ons(&[steak, cheddar_cheese, corn_chips, sour_cream, nacho_cheese, pico_de_gallo, refried_beans, guacamole, tomato], &add_ons_vec))
+    );
+
+    let beef_taco_salad = reg.add_item(
+        ItemBuilder::new("3e2a4f92-ab59-428c-b4c9-a389aa2b360f", "Taco Salad", "TSAL-BF")
+            .price(729).plu("2600")
+            .slot(dressings.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream])
+            )
+            .slot(dynamic_add_ons(&[house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream], &add_ons_vec))
+    );
+
+    let chicken_taco_salad = reg.add_item(
+        ItemBuilder::new("51ff7da4-ccc5-4c5b-85a5-0d3dedf99e2c", "Taco Salad CK", "TSAL-CK")
+            .price(799).plu("2610")
+            .slot(dressings.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream]))
+                    .collapsed(false)
+                    .defaults(&[chicken, house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream])
+            )
+            .slot(dynamic_add_ons(&[house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream], &add_ons_vec))
+    );
+
+    let steak_taco_salad = reg.add_item(
+        ItemBuilder::new("9b4f3071-ad55-4c4d-9f68-c18fec3bcf93", "Taco Salad Stk", "TSAL-STK")
+            .price(799).plu("2620")
+            .slot(dressings.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream]))
+                    .collapsed(false)
+                    .defaults(&[steak, house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream])
+            )
+            .slot(dynamic_add_ons(&[house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream], &add_ons_vec))
+    );
+
+    let bean_taco_salad = reg.add_item(
+        ItemBuilder::new("bfe4fbd8-347f-4b0f-963d-544fb7772598", "Taco Salad Bn", "TSAL-BN")
+            .price(729).plu("2640")
+            .slot(dressings.clone())
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[refried_beans, house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream]))
+                    .collapsed(false)
+                    .defaults(&[refried_beans, house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream])
+            )
+            .slot(dynamic_add_ons(&[house_salsa, taco_bowl, lettuce, tomato, cheddar_cheese, sour_cream], &add_ons_vec))
+    );
+
+    let taco_salad_shell_item = reg.add_item(
+        ItemBuilder::new("98ac2b3e-7fb6-47cc-8577-5c52bec50fb8", "Taco Salad", "Taco")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::SPECIALTIES])
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Taco Salad", SlotType::ItemShell, Selection::AnyItem(&[beef_taco_salad, chicken_taco_salad, steak_taco_salad, bean_taco_salad]))
+                    .minimum_quantity(1)
+                    .defaults(&[beef_taco_salad])
+            )
+    );
+
+    let _loaded_nachos = reg.add_item(
+        ItemBuilder::new("3e3cbef1-8aa5-42bf-b8ac-3547ed656340", "Loaded Nachos", "LN")
+            .price(0).plu("6330")
+            .tags(&[tags::DINNER, tags::LUNCH])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, corn_chips, sour_cream, poblano_peppers, pico_de_gallo]))
+                    .collapsed(false)
+                    .defaults(&[steak, corn_chips, sour_cream, poblano_peppers, pico_de_gallo])
+            )
+            .slot(dynamic_add_ons(&[steak, corn_chips, sour_cream, poblano_peppers, pico_de_gallo], &add_ons_vec))
+    );
+
+    let _fried_chicken_five_piece = reg.add_item(
+        ItemBuilder::new("7d44a69e-fb06-4a90-a083-ea16b8e77039", "Fried Chicken 5 Piece", "FC")
+            .price(0).plu("12720")
+            .tags(&[tags::DINNER, tags::LUNCH])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, side_ranch]))
+                    .collapsed(false)
+                    .defaults(&[chicken, side_ranch])
+            )
+            .slot(dynamic_add_ons(&[chicken, side_ranch], &add_ons_vec))
+    );
+
+    Specialties {
+        beef_quesadilla,
+        beef_super_nachos,
+        beef_taco_salad,
+        chicken_quesadilla,
+        chicken_super_nachos,
+        chicken_taco_salad,
+        cinnamon_sugar_tortilla_crisps: _cinnamon_sugar_tortilla_crisps,
+        fried_chicken_potato_snacker,
+        mexican_donut_bites: _mexican_donut_bites,
+        quesadilla,
+        snack_cheese_quesadilla,
+        steak_quesadilla,
+        steak_super_nachos,
+        steak_taco_salad,
+        taco_salad_shell_item,
+    }
+}
@@ -0,0 +1,452 @@
+use crate::builder::{tags, ItemBuilder, ItemHandle, PriceOverrideBuilder, Selection, SlotBuilder};
+use super::{dynamic_add_ons, Registry};
+use super::ingredients::Ingredients;
+use super::proteins::Proteins;
+use super::sauces::Sauces;
+use super::sides::Sides;
+use adjunct::SlotType;
+
+/// What later sections use from this one.
+pub struct Tacos {
+    pub bean_crispy_taco: ItemHandle,
+    pub bean_softshell_taco: ItemHandle,
+    pub bean_stuffed_grilled_taco: ItemHandle,
+    pub bean_taco_bravo: ItemHandle,
+    pub beef_crispy_taco: ItemHandle,
+    pub beef_softshell_taco: ItemHandle,
+    pub beef_stuffed_grilled_taco: ItemHandle,
+    pub beef_taco_bravo: ItemHandle,
+    pub beef_taco_burger: ItemHandle,
+    pub chicken_crispy_taco: ItemHandle,
+    pub chicken_softshell_taco: ItemHandle,
+    pub chicken_street_taco: ItemHandle,
+    pub chicken_taco_bravo: ItemHandle,
+    pub chicken_taco_burger: ItemHandle,
+    pub citrus_chipotle_fried_chicken_softshell_taco: ItemHandle,
+    pub spicy_jalapeno_ranch_fried_chicken_taco: ItemHandle,
+    pub steak_crispy_taco: ItemHandle,
+    pub steak_softshell_taco: ItemHandle,
+    pub steak_street_taco: ItemHandle,
+    pub steak_stuffed_grilled_taco: ItemHandle,
+    pub steak_taco_bravo: ItemHandle,
+    pub steak_taco_burger: ItemHandle,
+    pub stuffed_grilled_chipotle_chicken_taco: ItemHandle,
+}
+
+pub fn register(
+    reg: &mut Registry,
+    proteins: &Proteins,
+    sauces: &Sauces,
+    ingredients: &Ingredients,
+    sides: &Sides,
+) -> Tacos {
+    let add_ons_vec = sides.add_ons_vec.clone();
+    let burger_bun = ingredients.burger_bun;
+    let cheddar_cheese = ingredients.cheddar_cheese;
+    let chicken = proteins.chicken;
+    let chimichurri = sauces.chimichurri;
+    let corn_tortilla = ingredients.corn_tortilla;
+    let creamy_chipotle = sauces.creamy_chipotle;
+    let fried_chicken = proteins.fried_chicken;
+    let ground_beef = proteins.ground_beef;
+    let guacamole = sauces.guacamole;
+    let hard_shell = ingredients.hard_shell;
+    let lettuce = ingredients.lettuce;
+    let lime = ingredients.lime;
+    let mild_sauce = sauces.mild_sauce;
+    let nacho_cheese = ingredients.nacho_cheese;
+    let pico_de_gallo = ingredients.pico_de_gallo;
+    let queso_fresco = ingredients.queso_fresco;
+    let refried_beans = ingredients.refried_beans;
+    let small_tortilla = ingredients.small_tortilla;
+    let sour_cream = sauces.sour_cream;
+    let steak = proteins.steak;
+    let tomato = ingredients.tomato;
+
+    let chicken_crispy_taco = reg.add_item(
+        ItemBuilder::new("5ff7793a-a134-45f4-be87-672d0f8eb083", "Crispy Taco Chicken", "TACO-CK")
+            .price(223).plu("110")
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, cheddar_cheese, hard_shell, lettuce, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[chicken, cheddar_cheese, hard_shell, lettuce, mild_sauce])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, hard_shell, lettuce, mild_sauce], &add_ons_vec))
+    );
+
+    let steak_crispy_taco = reg.add_item(
+        ItemBuilder::new("61edad29-b481-4f68-9ce9-905fb13d80e6", "Crispy Taco Steak", "TACO-STK")
+            .price(269).plu("120")
+            .priority(3)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, cheddar_cheese, hard_shell, lettuce, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[steak, cheddar_cheese, hard_shell, lettuce, mild_sauce])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, hard_shell, lettuce, mild_sauce], &add_ons_vec))
+    );
+
+    let beef_crispy_taco = reg.add_item(
+        ItemBuilder::new("62e6963c-8cbe-4d11-9b90-92441fad50d6", "Crispy Taco", "TACO")
+            .price(169).plu("100")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, cheddar_cheese, hard_shell, lettuce, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, cheddar_cheese, hard_shell, lettuce, mild_sauce])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, hard_shell, lettuce, mild_sauce], &add_ons_vec))
+    );
+
+    let bean_crispy_taco = reg.add_item(
+        ItemBuilder::new("6400e70c-0bce-4543-8be3-5cddd312a22f", "Crispy Taco Bean", "TACO-BN")
+            .price(169).plu("140")
+            .priority(4)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[refried_beans, cheddar_cheese, hard_shell, lettuce, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[refried_beans, cheddar_cheese, hard_shell, lettuce, mild_sauce])
+            )
+            .slot(dynamic_add_ons(&[cheddar_cheese, hard_shell, lettuce, mild_sauce], &add_ons_vec))
+    );
+
+    let chipotle_chicken_taco_bravo = reg.add_item(
+        ItemBuilder::new("648ef2fa-4f6c-448a-a286-e064bd3cb2b9", "Chipotle Chicken Taco Bravo", "T-BRV-CHP-CK")
+            .price(315).plu("57200")
+            .priority(1)
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS])
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Items, Selection::Tag(tags::PROTEINS))
+                    .defaults(&[chicken])
+            )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[hard_shell, small_tortilla, refried_beans, creamy_chipotle, cheddar_cheese, lettuce, tomato]))
+                    .collapsed(false)
+                    .defaults(&[creamy_chipotle, lettuce, hard_shell, small_tortilla, refried_beans, cheddar_cheese, tomato])
+            )
+            .slot(dynamic_add_ons(&[creamy_chipotle, lettuce, hard_shell, small_tortilla, refried_beans, cheddar_cheese, tomato], &add_ons_vec))
+    );
+
+    let steak_taco_bravo = reg.add_item(
+        ItemBuilder::new("65edba48-dc71-4f30-8e6c-2cd27b0f126d", "Steak Taco Bravo", "T-BRV-STK")
+            .price(379).plu("320")
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato]))
+                    .collapsed(false)
+                    .defaults(&[steak, hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato])
+            )
+            .slot(dynamic_add_ons(&[hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato], &add_ons_vec))
+    );
+
+    let beef_taco_bravo = reg.add_item(
+        ItemBuilder::new("67fe0cd3-dde9-44aa-92d9-5c0aefbc411f", "Beef Taco Bravo", "T-BRV-BF")
+            .price(299).plu("300")
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato])
+            )
+            .slot(dynamic_add_ons(&[hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato], &add_ons_vec))
+    );
+
+    let chicken_taco_bravo = reg.add_item(
+        ItemBuilder::new("686633cb-c89a-4672-be98-c1c536833632", "Chicken Taco Bravo", "T-BRV-CK")
+            .price(315).plu("310")
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato]))
+                    .collapsed(false)
+                    .defaults(&[chicken, hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato])
+            )
+            .slot(dynamic_add_ons(&[hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato], &add_ons_vec))
+    );
+
+    let bean_taco_bravo = reg.add_item(
+        ItemBuilder::new("686a3caf-ad46-4216-92aa-90c5fa1fe046", "Bean Taco Bravo", "T-BRV-BN")
+            .price(299).plu("340")
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato]))
+                    .collapsed(false)
+                    .defaults(&[hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato])
+            )
+            .slot(dynamic_add_ons(&[hard_shell, small_tortilla, refried_beans, mild_sauce, cheddar_cheese, lettuce, tomato], &add_ons_vec))
+    );
+
+    let steak_taco_burger = reg.add_item(
+        ItemBuilder::new("69fb9c82-92a6-4be9-adb1-41fec1d1724c", "Steak Taco Burger", "T-BURG-STK")
+            .price(359).plu("370")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, burger_bun, cheddar_cheese, lettuce, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[steak, burger_bun, cheddar_cheese, lettuce, mild_sauce])
+            )
+            .slot(dynamic_add_ons(&[burger_bun, cheddar_cheese, lettuce, mild_sauce], &add_ons_vec))
+    );
+
+    let beef_taco_burger = reg.add_item(
+        ItemBuilder::new("6a0c0d61-fcdb-4953-857e-e80402fd4eea", "Beef Taco Burger", "T-BURG")
+            .price(315).plu("350")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, burger_bun, cheddar_cheese, lettuce, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, burger_bun, cheddar_cheese, lettuce, mild_sauce])
+            )
+            .slot(dynamic_add_ons(&[burger_bun, cheddar_cheese, lettuce, mild_sauce], &add_ons_vec))
+    );
+
+    let chicken_taco_burger = reg.add_item(
+        ItemBuilder::new("6a8dcfc5-ebfa-45b7-a22e-35dd47a78189", "Chicken Taco Burger", "T-BURG-CK")
+            .price(299).plu("360")
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, burger_bun, cheddar_cheese, lettuce, mild_sauce]))
+                    .collapsed(false)
+                    .defaults(&[chicken, burger_bun, cheddar_cheese, lettuce, mild_sauce])
+            )
+            .slot(dynamic_add_ons(&[chicken, cheddar_cheese, lettuce, mild_sauce], &add_ons_vec))
+    );
+
+    let steak_stuffed_grilled_taco = reg.add_item(
+        ItemBuilder::new("6af8bc53-af24-4e99-a8a5-c1b0b6ef1806", "Steak Stuffed Grilled Taco", "SGT-STK")
+            .price(482).plu("3000")
+            .tags(&[tags::GRILLED])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[steak, small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese], &add_ons_vec))
+    );
+
+    let beef_stuffed_grilled_taco = reg.add_item(
+        ItemBuilder::new("6b9e2f36-0ae5-4583-9dba-45e4d3caf370", "Beef Stuffed Grilled Taco", "SGT-BF")
+            .price(370).plu("2950")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS, tags::GRILLED])
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese], &add_ons_vec))
+    );
+
+    let chicken_stuffed_grilled_taco = reg.add_item(
+        ItemBuilder::new("6cd3c609-697d-4c31-9e34-e5b9d79784e4", "Chicken Stuffed Grilled Taco", "SGT-CK")
+            .price(409).plu("57210")
+            .tags(&[tags::GRILLED])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[chicken, small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese], &add_ons_vec))
+    );
+
+    let bean_stuffed_grilled_taco = reg.add_item(
+        ItemBuilder::new("a367ad43-8ceb-45bc-8896-19f59c019485", "Bean Stuffed Grilled Taco", "SGT-BN")
+            .price(349).plu("3030")
+            .tags(&[tags::GRILLED])
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[refried_beans, small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[refried_beans, small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, hard_shell, mild_sauce, sour_cream, nacho_cheese, cheddar_cheese], &add_ons_vec))
+    );
+
+    let stuffed_grilled_chipotle_chicken_taco = reg.add_item(
+        ItemBuilder::new("6d00115b-e6f5-499a-aca9-9a31dce24915", "Stuffed Grilled Chipotle Chicken Taco", "STG-CHCK")
+            .price(409).plu("2960")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS, tags::GRILLED])
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Items, Selection::Item(chicken))
+                    .defaults(&[chicken])
+            )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[small_tortilla, hard_shell, creamy_chipotle, sour_cream, nacho_cheese, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[creamy_chipotle, small_tortilla, hard_shell, sour_cream, nacho_cheese, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[creamy_chipotle, small_tortilla, hard_shell, sour_cream, nacho_cheese, cheddar_cheese], &add_ons_vec))
+    );
+
+    let citrus_chipotle_fried_chicken_softshell_taco = reg.add_item(
+        ItemBuilder::new("6df6156d-c637-4485-925a-707e659e5c06", "CL FC Taco", "TACO-CL-FC")
+            .price(249).plu("12500")
+            .priority(3)
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS, tags::QUICK])
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Ingredient, Selection::Item(fried_chicken))
+                    .defaults(&[fried_chicken])
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::PROTEINS]).price(0))
+            )
+            // .slot(
+            //     SlotBuilder::new("Sauce", SlotType::Replace, Selection::AnyItem(&[chipotle_lime]))
+            //         .collapsed(false)
+            //         .minimum_quantity(1)
+            //         .maximum_quantity(1)
+            // )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese], &add_ons_vec))
+    );
+
+    let spicy_jalapeno_ranch_fried_chicken_taco = reg.add_item(
+        ItemBuilder::new("6e938317-0554-4212-9219-00add2016802", "SJR FC Taco", "TACO-JRFC")
+            .price(249).plu("12510")
+            .priority(2)
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS, tags::QUICK])
+            .slot(
+                SlotBuilder::new("Protein", SlotType::Ingredient, Selection::Item(fried_chicken))
+                    .defaults(&[fried_chicken])
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::PROTEINS]).price(0))
+            )
+            // .slot(
+            //     SlotBuilder::new("Sauce", SlotType::Replace, Selection::AnyItem(&[jalapeno_ranch]))
+            //         .collapsed(false)
+            //         .minimum_quantity(1)
+            //         .maximum_quantity(1)
+            // )
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese], &add_ons_vec))
+    );
+
+    let steak_softshell_taco = reg.add_item(
+        ItemBuilder::new("71b15f13-a65e-44e6-adc1-fe57c2830add", "Steak Softshell Taco", "SS-STK")
+            .price(317).plu("220")
+            .priority(3)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, small_tortilla, mild_sauce, lettuce, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[steak, small_tortilla, mild_sauce, lettuce, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese], &add_ons_vec))
+    );
+
+    let beef_softshell_taco = reg.add_item(
+        ItemBuilder::new("72843bf4-9c44-427b-8015-ea5ee49e0dd1", "Beef Softshell Taco", "SS-BF")
+            .price(199).plu("200")
+            .priority(1)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[ground_beef, small_tortilla, mild_sauce, lettuce, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[ground_beef, small_tortilla, mild_sauce, lettuce, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese], &add_ons_vec))
+    );
+
+    let bean_softshell_taco = reg.add_item(
+        ItemBuilder::new("7293c757-d3d5-4046-acbb-5967ea10ad50", "Bean Softshell Taco", "SS-BN")
+            .price(199).plu("240")
+            .priority(4)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[refried_beans, small_tortilla, mild_sauce, lettuce, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[refried_beans, small_tortilla, mild_sauce, lettuce, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese], &add_ons_vec))
+    );
+
+    let chicken_softshell_taco = reg.add_item(
+        ItemBuilder::new("74d36b2d-5edf-4c92-a8b2-a7ad92c184a5", "Chicken Softshell Taco", "SS-CK")
+            .price(262).plu("210")
+            .priority(2)
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, small_tortilla, mild_sauce, lettuce, cheddar_cheese]))
+                    .collapsed(false)
+                    .defaults(&[chicken, small_tortilla, mild_sauce, lettuce, cheddar_cheese])
+            )
+            .slot(dynamic_add_ons(&[small_tortilla, mild_sauce, lettuce, cheddar_cheese], &add_ons_vec))
+    );
+
+    let steak_street_taco = reg.add_item(
+        ItemBuilder::new("75785681-6cc7-4f1d-b023-c2e1048f45eb", "Steak Street Taco", "ST-STK")
+            .price(299).plu("6900")
+            .priority(1)
+            .tags(&[tags::STREET_TACOS, tags::VALUE])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[steak, lettuce, pico_de_gallo, queso_fresco, chimichurri, corn_tortilla, lime]))
+                    .collapsed(false)
+                    .defaults(&[steak, lettuce, pico_de_gallo, queso_fresco, chimichurri, corn_tortilla, lime])
+            )
+            .slot(dynamic_add_ons(&[lettuce, pico_de_gallo, queso_fresco, chimichurri, corn_tortilla, lime], &add_ons_vec))
+    );
+
+    let chicken_street_taco = reg.add_item(
+        ItemBuilder::new("76c52e23-a0cc-4a18-b016-5d1b637067be", "Chicken Street Taco", "ST-CK")
+            .price(240).plu("7590")
+            .tags(&[tags::STREET_TACOS, tags::VALUE])
+            .slot(
+                SlotBuilder::new("Ingredients", SlotType::Ingredient, Selection::AnyItem(&[chicken, lettuce, guacamole, pico_de_gallo, queso_fresco, chimichurri, corn_tortilla, lime]))
+                    .collapsed(false)
+                    .defaults(&[chicken, lettuce, guacamole, pico_de_gallo, queso_fresco, chimichurri, corn_tortilla, lime])
+            )
+            .slot(dynamic_add_ons(&[lettuce, pico_de_gallo, queso_fresco, chimichurri, corn_tortilla, lime], &add_ons_vec))
+    );
+
+    let street_taco_trio = reg.add_item(
+        ItemBuilder::new("777c5f3e-6c5f-43b6-b768-fa2491956864", "Street Taco Trio", "ST-TRI")
+            .price(859).plu("6910")
+            .tags(&[tags::DINNER, tags::LUNCH, tags::TACOS])
+            .slot(
+                SlotBuilder::new("Tacos", SlotType::Items, Selection::AnyItem(&[steak_street_taco, chicken_street_taco]))
+                    .minimum_quantity(3)
+                    .maximum_quantity(3)
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::STREET_TACOS]).price(0))
+            )
+    );
+
+    let cinco_softshell = reg.add_item(
+        ItemBuilder::new("785cd853-b1eb-4649-8e7f-664451def465", "Cinco Softshell", "CINCO-SS")
+            .price(555).plu("7500")
+            .tags(&[tags::DINNER, tags::LUNCH])
+            .slot(
+                SlotBuilder::new("Tacos", SlotType::Ingredient, Selection::AnyItem(&[beef_softshell_taco, beef_softshell_taco, beef_softshell_taco, beef_softshell_taco, beef_softshell_taco]))
+                    .minimum_quantity(5)
+                    .maximum_quantity(5)
+                    .default_quantity(5)
+                    .price_override(PriceOverrideBuilder::new().tags(&[tags::TACOS]).price(0))
+                    .defaults(&[beef_softshell_taco; 5])
+            )
+    );
+
+    Tacos {
+        bean_crispy_taco,
+        bean_softshell_taco,
+        bean_stuffed_grilled_taco,
+        bean_taco_bravo,
+        beef_crispy_taco,
+        beef_softshell_taco,
+        beef_stuffed_grilled_taco,
+        beef_taco_bravo,
+        beef_taco_burger,
+        chicken_crispy_taco,
+        chicken_softshell_taco,
+        chicken_street_taco,
+        chicken_taco_bravo,
+        chicken_taco_burger,
+        citrus_chipotle_fried_chicken_softshell_taco,
+        spicy_jalapeno_ranch_fried_chicken_taco,
+        steak_crispy_taco,
+        steak_softshell_taco,
+        steak_street_taco,
+        steak_stuffed_grilled_taco,
+        steak_taco_bravo,
+        steak_taco_burger,
+        stuffed_grilled_chipotle_chicken_taco,
+    }
+}
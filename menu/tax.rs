@@ -0,0 +1,16 @@
+use super::Registry;
+use crate::builder::{tags, Selection};
+use crate::tax::TaxCategory;
+
+pub fn register(reg: &mut Registry) {
+    // Everything else is taxed as food
+    reg.tax_category(Selection::Tag(tags::DRINKS), TaxCategory::Beverage);
+
+    // Combos carry their price on the combo item, so split it over the entree, side and drink
+    reg.split_tax(Selection::AnyTag(&[
+        tags::COMBOS,
+        tags::BREAKFAST_COMBO,
+        tags::BUNDLE,
+        tags::KIDS_MEAL,
+    ]));
+}
@@ -0,0 +1,230 @@
+//! Calories and macros for configured items.
+//!
+//! Facts come from `nutrition.json`, keyed by PLU, and are summed through the
+//! slot graph the same way the simulator walks it for pricing: the order's
+//! slot choices where given, slot defaults otherwise.
+//!
+//! A variation's portion multiplier scales the item's own facts and its
+//! ingredient slots. Combo components are separate items, so they are not
+//! scaled by the combo's size; instead they take the combo's variation when
+//! they have one of the same name (a Large combo comes with a Large drink).
+
+use crate::simulator::{default_lines, OrderLine};
+use adjunct::{ItemDefinition, Menu, Modification, SlotType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::ops::{Add, Mul};
+
+pub const NUTRITION_FILE: &str = "nutrition.json";
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct NutritionFacts {
+    pub calories: f64,
+    /// Grams.
+    pub fat: f64,
+    /// Grams.
+    pub carbohydrates: f64,
+    /// Grams.
+    pub protein: f64,
+}
+
+impl Add for NutritionFacts {
+    type Output = NutritionFacts;
+
+    fn add(self, other: NutritionFacts) -> NutritionFacts {
+        NutritionFacts {
+            calories: self.calories + other.calories,
+            fat: self.fat + other.fat,
+            carbohydrates: self.carbohydrates + other.carbohydrates,
+            protein: self.protein + other.protein,
+        }
+    }
+}
+
+impl Mul<f64> for NutritionFacts {
+    type Output = NutritionFacts;
+
+    fn mul(self, factor: f64) -> NutritionFacts {
+        NutritionFacts {
+            calories: self.calories * factor,
+            fat: self.fat * factor,
+            carbohydrates: self.carbohydrates * factor,
+            protein: self.protein * factor,
+        }
+    }
+}
+
+impl fmt::Display for NutritionFacts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:.0} cal, {:.0}g fat, {:.0}g carbs, {:.0}g protein",
+            self.calories, self.fat, self.carbohydrates, self.protein
+        )
+    }
+}
+
+fn extra() -> f64 {
+    2.0
+}
+
+fn light() -> f64 {
+    0.5
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct NutritionTable {
+    /// Facts for one standard portion, by PLU.
+    #[serde(default)]
+    pub items: BTreeMap<String, NutritionFacts>,
+    /// Portion multiplier by variation name, e.g. `"Large": 1.5`.
+    #[serde(default)]
+    pub portions: BTreeMap<String, f64>,
+    /// Per-PLU portion multipliers that replace `portions` for that item.
+    #[serde(default)]
+    pub item_portions: BTreeMap<String, BTreeMap<String, f64>>,
+    /// Multiplier for `Modification::Extra`.
+    #[serde(default = "extra")]
+    pub extra: f64,
+    /// Multiplier for `Modification::Light`.
+    #[serde(default = "light")]
+    pub light: f64,
+}
+
+impl Default for NutritionTable {
+    fn default() -> Self {
+        NutritionTable {
+            items: BTreeMap::new(),
+            portions: BTreeMap::new(),
+            item_portions: BTreeMap::new(),
+            extra: extra(),
+            light: light(),
+        }
+    }
+}
+
+/// Facts for a configured item, plus the items that had no facts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Nutrition {
+    pub facts: NutritionFacts,
+    pub missing: Vec<String>,
+}
+
+impl Nutrition {
+    pub fn add(&mut self, other: Nutrition) {
+        self.facts = self.facts + other.facts;
+        for name in other.missing {
+            if !self.missing.contains(&name) {
+                self.missing.push(name);
+            }
+        }
+    }
+}
+
+impl fmt::Display for Nutrition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.facts)?;
+        if !self.missing.is_empty() {
+            write!(f, " (no data for {})", self.missing.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl NutritionTable {
+    /// The table in `nutrition.json`, or an empty one when there is no such file.
+    pub fn load() -> Self {
+        match fs::read_to_string(NUTRITION_FILE) {
+            Ok(json) => serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("Couldnt parse {}: {}", NUTRITION_FILE, e)),
+            Err(_) => NutritionTable::default(),
+        }
+    }
+
+    fn portion(&self, item: &ItemDefinition, variation: Option<&str>) -> f64 {
+        let variation = match variation {
+            Some(variation) => variation,
+            None => return 1.0,
+        };
+        self.item_portions
+            .get(&item.plu)
+            .and_then(|portions| portions.get(variation))
+            .or_else(|| self.portions.get(variation))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    fn modifier(&self, modifiers: &[Modification]) -> f64 {
+        modifiers
+            .iter()
+            .map(|modifier| match modifier {
+                Modification::Extra => self.extra,
+                Modification::Light => self.light,
+                _ => 1.0,
+            })
+            .product()
+    }
+
+    /// Facts for one unit of `item` as ordered in `line`, at `scale` times a
+    /// standard portion. `parent_variation` is the variation of the combo or
+    /// item this one sits in.
+    pub fn for_line(
+        &self,
+        menu: &Menu,
+        item: &ItemDefinition,
+        line: &OrderLine,
+        parent_variation: Option<&str>,
+        scale: f64,
+    ) -> Nutrition {
+        let variation = line.variation.as_deref().or_else(|| {
+            parent_variation.filter(|name| {
+                item.variations
+                    .iter()
+                    .any(|variation| variation.name == *name)
+            })
+        });
+        let portion = scale * self.portion(item, variation) * self.modifier(&line.modifiers);
+
+        let mut nutrition = match self.items.get(&item.plu) {
+            Some(facts) => Nutrition {
+                facts: *facts * portion,
+                missing: vec![],
+            },
+            None if item.slots.is_empty() => Nutrition {
+                facts: NutritionFacts::default(),
+                missing: vec![item.long_name.clone()],
+            },
+            None => Nutrition::default(),
+        };
+
+        for slot in &item.slots {
+            let contents: Vec<OrderLine> =
+                match line.slots.iter().find(|choice| choice.slot == slot.name) {
+                    Some(choice) => choice.items.clone(),
+                    None => default_lines(menu, slot),
+                };
+            let slot_scale = match slot.slot_type {
+                SlotType::Ingredient => portion,
+                _ => scale,
+            };
+            for content_line in &contents {
+                if let Some(content) = menu
+                    .items
+                    .iter()
+                    .find(|content| content.plu == content_line.plu)
+                {
+                    nutrition.add(self.for_line(
+                        menu,
+                        content,
+                        content_line,
+                        variation,
+                        slot_scale * content_line.quantity as f64,
+                    ));
+                }
+            }
+        }
+        nutrition
+    }
+}
@@ -0,0 +1,27 @@
+//! Per-unit (store) overlay applied on top of the published menu: what the
+//! store is out of and what it charges in tax.
+
+use crate::tax::TaxCategory;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UnitOverlay {
+    /// Unit id, used to name per-store output files.
+    pub unit: String,
+    /// PLUs the store has run out of.
+    #[serde(default)]
+    pub out_of_stock: Vec<String>,
+    /// Tax rate in basis points by category, e.g. `"food": 825` for 8.25%.
+    #[serde(default)]
+    pub tax_rates: BTreeMap<TaxCategory, i64>,
+}
+
+impl UnitOverlay {
+    pub fn load(path: &str) -> Self {
+        let json =
+            fs::read_to_string(path).unwrap_or_else(|e| panic!("Couldnt read {}: {}", path, e));
+        serde_json::from_str(&json).unwrap_or_else(|e| panic!("Couldnt parse {}: {}", path, e))
+    }
+}
@@ -0,0 +1,727 @@
+//! Price change sets: the list of old and new prices a bulk edit would make.
+//!
+//! Changes go through two steps. `propose` writes a change set with the
+//! prices, their impact on margins and hashes of the menu and the menu
+//! sources it was worked out against. Once someone has reviewed it,
+//! `approve <file> <name>` signs off the changes as they are and
+//! `apply [file]` patches the menu source, refusing if the set is not
+//! approved, was edited after the approval, or the menu or its sources have
+//! changed since it was proposed. Only item prices and upcharges can be
+//! patched in the source; anything else is refused.
+
+use crate::audit::{self, AuditEntry, ChangeSource};
+use crate::document::MenuDocument;
+use crate::food_cost::{CostTable, MarginReport};
+use crate::hash;
+use crate::publish;
+use crate::rounding::RoundingPolicy;
+use crate::simulator::{cents, Simulator};
+use adjunct::{ItemDefinition, Menu, ID};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+pub const CHANGES_FILE: &str = "changes.json";
+/// The price requests `watch` proposes a change set from.
+pub const PRICE_REQUESTS_FILE: &str = "price_requests.json";
+pub const MENU_SOURCE_DIR: &str = "menu";
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PriceChange {
+    pub id: ID,
+    pub plu: String,
+    pub item: String,
+    /// Which price on the item, e.g. `price`, `variations.Large.price`,
+    /// `modifier_upcharge.Extra` or `slots.Drinks.price_overrides.0.price`.
+    pub field: String,
+    pub old: i64,
+    pub new: i64,
+}
+
+impl PriceChange {
+    pub fn percent(&self) -> f64 {
+        if self.old == 0 {
+            return 0.0;
+        }
+        (self.new - self.old) as f64 * 100.0 / self.old as f64
+    }
+
+    /// Sets this change's price on `item`.
+    fn set(&self, item: &mut ItemDefinition) -> Result<(), String> {
+        let parts: Vec<&str> = self.field.split('.').collect();
+        let price = match parts.as_slice() {
+            ["price"] => Some(&mut item.price),
+            ["variations", name, "price"] => item
+                .variations
+                .iter_mut()
+                .find(|variation| variation.name == *name)
+                .and_then(|variation| variation.price.as_mut()),
+            ["modifier_upcharge", modifier] => item
+                .modifier_upcharge
+                .iter_mut()
+                .find(|(key, _)| format!("{:?}", key) == *modifier)
+                .map(|(_, price)| price),
+            ["slots", slot, "price_overrides", i, "price"] => item
+                .slots
+                .iter_mut()
+                .find(|candidate| candidate.name == *slot)
+                .and_then(|slot| slot.price_overrides.get_mut(i.parse::<usize>().ok()?))
+                .map(|price_override| &mut price_override.price),
+            _ => None,
+        };
+        match price {
+            Some(price) => {
+                *price = self.new as _;
+                Ok(())
+            }
+            None => Err(format!(
+                "{} ({}) has no {}",
+                self.item, self.plu, self.field
+            )),
+        }
+    }
+}
+
+/// Every price on `item` with its field path, as in [`PriceChange::field`].
+pub fn item_prices(item: &ItemDefinition) -> Vec<(String, i64)> {
+    let mut prices = vec![("price".to_string(), item.price as i64)];
+    for variation in &item.variations {
+        if let Some(price) = variation.price {
+            prices.push((format!("variations.{}.price", variation.name), price as i64));
+        }
+    }
+    for (modifier, price) in &item.modifier_upcharge {
+        prices.push((format!("modifier_upcharge.{:?}", modifier), *price as i64));
+    }
+    for slot in &item.slots {
+        for (i, price_override) in slot.price_overrides.iter().enumerate() {
+            prices.push((
+                format!("slots.{}.price_overrides.{}.price", slot.name, i),
+                price_override.price as i64,
+            ));
+        }
+    }
+    prices
+}
+
+/// What a change set does to prices and margins overall.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Impact {
+    pub items: usize,
+    /// Mean percentage change over the changed prices.
+    pub average_percent: f64,
+    /// Margin warnings the change set adds.
+    pub margin_warnings: Vec<String>,
+    /// Margin warnings the change set clears.
+    pub margin_fixes: Vec<String>,
+}
+
+impl Impact {
+    pub fn analyze(document: &MenuDocument, changes: &[PriceChange]) -> Result<Self, String> {
+        let mut changed = document.clone();
+        apply_to_menu(&mut changed.menu, changes)?;
+
+        let costs = CostTable::load();
+        let warnings = |document: &MenuDocument| {
+            let simulator = Simulator::new(document).rounding(RoundingPolicy::load());
+            MarginReport::from_menu(&simulator, &document.menu, &costs).warnings()
+        };
+        let before = warnings(document);
+        let after = warnings(&changed);
+
+        let mut items: Vec<ID> = changes.iter().map(|change| change.id).collect();
+        items.sort();
+        items.dedup();
+        let average_percent = if changes.is_empty() {
+            0.0
+        } else {
+            changes.iter().map(PriceChange::percent).sum::<f64>() / changes.len() as f64
+        };
+        Ok(Impact {
+            items: items.len(),
+            average_percent,
+            margin_warnings: after
+                .iter()
+                .filter(|warning| !before.contains(warning))
+                .cloned()
+                .collect(),
+            margin_fixes: before
+                .iter()
+                .filter(|warning| !after.contains(warning))
+                .cloned()
+                .collect(),
+        })
+    }
+}
+
+/// Sets every change's new price on `menu`.
+pub fn apply_to_menu(menu: &mut Menu, changes: &[PriceChange]) -> Result<(), String> {
+    for change in changes {
+        let item = menu
+            .items
+            .iter_mut()
+            .find(|item| item.id == change.id)
+            .ok_or_else(|| format!("{} ({}) is not on the menu", change.item, change.plu))?;
+        change.set(item)?;
+    }
+    Ok(())
+}
+
+/// Hash of the menu sources in `dir`.
+pub fn source_hash(dir: &Path) -> String {
+    let sources: Vec<(String, String)> = read_sources(dir)
+        .into_iter()
+        .map(|(path, source)| (path.display().to_string(), source))
+        .collect();
+    publish::content_hash(&sources)
+}
+
+/// Hash of the changes an approval covers.
+fn changes_hash(changes: &[PriceChange]) -> String {
+    hash::hex(
+        serde_json::to_string(changes)
+            .expect("Couldnt serialize price changes")
+            .as_bytes(),
+    )
+}
+
+/// Hash of the menu a change set is proposed against.
+pub fn menu_hash(menu: &Menu) -> String {
+    hash::hex(
+        serde_json::to_string(menu)
+            .expect("Couldnt serialize menu")
+            .as_bytes(),
+    )
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ChangeSet {
+    /// What was asked for, e.g. `tag=combos +10`.
+    pub description: String,
+    pub source: ChangeSource,
+    #[serde(default)]
+    pub unit: Option<String>,
+    pub menu_hash: String,
+    /// Hash of the menu sources `apply` patches, as they were when the set
+    /// was proposed.
+    #[serde(default)]
+    pub source_hash: String,
+    pub changes: Vec<PriceChange>,
+    pub impact: Impact,
+    /// Who reviewed and approved the set; `apply` refuses it until then.
+    #[serde(default)]
+    pub approved_by: Option<String>,
+    /// Hash of `changes` as they were approved, so editing them afterwards
+    /// voids the approval.
+    #[serde(default)]
+    pub approved_hash: Option<String>,
+}
+
+impl ChangeSet {
+    pub fn propose(
+        document: &MenuDocument,
+        description: &str,
+        source: ChangeSource,
+        unit: Option<String>,
+        changes: Vec<PriceChange>,
+        sources: &Path,
+    ) -> Result<Self, String> {
+        if let Some(change) = changes.iter().find(|change| !patchable(&change.field)) {
+            return Err(unpatchable(change));
+        }
+        Ok(ChangeSet {
+            description: description.to_string(),
+            source,
+            unit,
+            menu_hash: menu_hash(&document.menu),
+            source_hash: source_hash(sources),
+            impact: Impact::analyze(document, &changes)?,
+            changes,
+            approved_by: None,
+            approved_hash: None,
+        })
+    }
+
+    pub fn load(path: &str) -> Self {
+        let json =
+            fs::read_to_string(path).unwrap_or_else(|e| panic!("Couldnt read {}: {}", path, e));
+        serde_json::from_str(&json).unwrap_or_else(|e| panic!("Couldnt parse {}: {}", path, e))
+    }
+
+    pub fn write(&self, path: &str) {
+        let json = serde_json::to_string_pretty(self).expect("Couldnt serialize change set");
+        fs::write(path, json).expect("Couldnt write change set");
+    }
+
+    /// Approves the changes as they are now.
+    pub fn approve(&mut self, name: &str) {
+        self.approved_by = Some(name.to_string());
+        self.approved_hash = Some(changes_hash(&self.changes));
+    }
+
+    /// Why `apply` would refuse the set against the menu sources in `dir`, if
+    /// it would.
+    pub fn check(&self, menu: &Menu, dir: &Path) -> Result<(), String> {
+        if self.approved_by.is_none() {
+            return Err("change set is not approved".into());
+        }
+        if self.approved_hash.as_deref() != Some(changes_hash(&self.changes).as_str()) {
+            return Err("the changes were edited after they were approved".into());
+        }
+        if source_hash(dir) != self.source_hash {
+            return Err(format!(
+                "the menu sources in {} have changed since this change set was proposed",
+                dir.display()
+            ));
+        }
+        if menu_hash(menu) != self.menu_hash {
+            return Err("the menu has changed since this change set was proposed".into());
+        }
+        Ok(())
+    }
+
+    /// Patches the menu source in `dir` and appends the changes to the audit
+    /// log. Nothing is written unless every change can be applied.
+    pub fn apply(&self, menu: &Menu, dir: &Path) -> Result<(), String> {
+        self.check(menu, dir)?;
+
+        let mut sources = read_sources(dir);
+        for change in &self.changes {
+            patch_source(&mut sources, change)?;
+        }
+        for (path, source) in &sources {
+            fs::write(path, source)
+                .unwrap_or_else(|e| panic!("Couldnt write {}: {}", path.display(), e));
+        }
+
+        let timestamp = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        let entries: Vec<AuditEntry> = self
+            .changes
+            .iter()
+            .map(|change| AuditEntry {
+                plu: change.plu.clone(),
+                field: change.field.clone(),
+                old: change.old,
+                new: change.new,
+                source: self.source,
+                unit: self.unit.clone(),
+                approved_by: self.approved_by.clone(),
+                timestamp: timestamp.clone(),
+            })
+            .collect();
+        audit::append(audit::AUDIT_FILE, &entries);
+        Ok(())
+    }
+}
+
+fn read_sources(dir: &Path) -> BTreeMap<PathBuf, String> {
+    let entries =
+        fs::read_dir(dir).unwrap_or_else(|e| panic!("Couldnt read {}: {}", dir.display(), e));
+    let mut sources = BTreeMap::new();
+    for entry in entries {
+        let path = entry.expect("Couldnt read menu source directory").path();
+        if path.extension().and_then(|ext| ext.to_str()) == Some("rs") {
+            let source = fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("Couldnt read {}: {}", path.display(), e));
+            sources.insert(path, source);
+        }
+    }
+    sources
+}
+
+/// Whether `patch_source` can rewrite the price at `field`.
+fn patchable(field: &str) -> bool {
+    field == "price" || field.starts_with("modifier_upcharge.")
+}
+
+fn unpatchable(change: &PriceChange) -> String {
+    format!(
+        "{} of {} ({}) cant be patched in the menu source, change it by hand",
+        change.field, change.item, change.plu
+    )
+}
+
+/// Rewrites one price in the item that has the change's PLU, the same way
+/// the Go patcher does for database prices.
+fn patch_source(
+    sources: &mut BTreeMap<PathBuf, String>,
+    change: &PriceChange,
+) -> Result<(), String> {
+    let plu = format!(".plu(\"{}\")", change.plu);
+    let mut found: Vec<(PathBuf, usize)> = sources
+        .iter()
+        .flat_map(|(path, source)| {
+            source
+                .match_indices(&plu)
+                .map(move |(at, _)| (path.clone(), at))
+        })
+        .collect();
+    let (path, at) = match found.len() {
+        1 => found.remove(0),
+        0 => return Err(format!("PLU {} is not in the menu source", change.plu)),
+        _ => {
+            return Err(format!(
+                "PLU {} is in the menu source more than once",
+                change.plu
+            ))
+        }
+    };
+
+    // The call the price is the argument of, and what must come after it: the
+    // item's own price is the one right before its PLU, not a slot override's.
+    let (call, followed_by) = match change.field.split_once('.') {
+        None if change.field == "price" => (".price(".to_string(), format!("){}", plu)),
+        Some(("modifier_upcharge", modifier)) => (
+            format!(".upcharge(Modification::{}, ", modifier),
+            ")".to_string(),
+        ),
+        _ => return Err(unpatchable(change)),
+    };
+
+    let source = sources.get_mut(&path).expect("Couldnt find menu source");
+    let item = item_call(source, at)
+        .ok_or_else(|| format!("PLU {} is not inside an add_item call", change.plu))?;
+    let statement = &source[item.clone()];
+    let found: Vec<Range<usize>> = statement
+        .match_indices(&call)
+        .filter_map(|(i, _)| {
+            let digits = i + call.len();
+            let number_end = statement[digits..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(statement.len(), |len| digits + len);
+            (statement[digits..number_end] == change.old.to_string()
+                && statement[number_end..].starts_with(&followed_by))
+            .then_some(item.start + digits..item.start + number_end)
+        })
+        .collect();
+    match found.as_slice() {
+        [range] => {
+            source.replace_range(range.clone(), &change.new.to_string());
+            Ok(())
+        }
+        _ => Err(format!(
+            "couldnt find {}{} for {} ({}) in {}",
+            call,
+            change.old,
+            change.item,
+            change.plu,
+            path.display()
+        )),
+    }
+}
+
+/// The `add_item(...)` call around `at`, up to its closing parenthesis.
+fn item_call(source: &str, at: usize) -> Option<Range<usize>> {
+    let start = source[..at].rfind("add_item(")?;
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in source[start..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    let end = start + i + 1;
+                    return (end > at).then_some(start..end);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+impl fmt::Display for ChangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unit = match &self.unit {
+            Some(unit) => format!(", unit {}", unit),
+            None => String::new(),
+        };
+        writeln!(
+            f,
+            "{} ({:?}{}, {} changes):",
+            self.description,
+            self.source,
+            unit,
+            self.changes.len()
+        )?;
+        for change in &self.changes {
+            writeln!(
+                f,
+                "  {:<40} {:<32} {:>8} -> {:>8} {:>+6.1}%",
+                format!("{} ({})", change.item, change.plu),
+                change.field,
+                cents(change.old),
+                cents(change.new),
+                change.percent()
+            )?;
+        }
+        writeln!(
+            f,
+            "{} items, average change {:+.1}%",
+            self.impact.items, self.impact.average_percent
+        )?;
+        for fix in &self.impact.margin_fixes {
+            writeln!(f, "fixed: {}", fix)?;
+        }
+        for warning in &self.impact.margin_warnings {
+            writeln!(f, "warning: {}", warning)?;
+        }
+        match &self.approved_by {
+            Some(name) => writeln!(f, "Approved by {}", name),
+            None => writeln!(f, "Not approved"),
+        }
+    }
+}
+
+/// New item prices by PLU from outside the menu, e.g. `getPriceChanges` via
+/// the Go patcher's `-propose` mode, or a CSV export.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PriceRequests {
+    pub source: ChangeSource,
+    #[serde(default)]
+    pub unit: Option<String>,
+    pub prices: Vec<PriceRequest>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PriceRequest {
+    pub plu: String,
+    pub price: i64,
+}
+
+impl PriceRequests {
+    /// A `.csv` file is read with `from_csv`, anything else as JSON.
+    pub fn load(path: &str) -> Self {
+        let text =
+            fs::read_to_string(path).unwrap_or_else(|e| panic!("Couldnt read {}: {}", path, e));
+        if path.ends_with(".csv") {
+            PriceRequests::from_csv(&text)
+        } else {
+            serde_json::from_str(&text).map_err(|e| e.to_string())
+        }
+        .unwrap_or_else(|e| panic!("Couldnt parse {}: {}", path, e))
+    }
+
+    /// A CSV export with a `plu,price` header and one price in cents per
+    /// line, for the base menu.
+    pub fn from_csv(text: &str) -> Result<Self, String> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        match lines.next() {
+            Some((_, header)) if header.trim().eq_ignore_ascii_case("plu,price") => {}
+            _ => return Err("expected a plu,price header".into()),
+        }
+        let mut prices = Vec::new();
+        for (i, line) in lines {
+            let (plu, price) = line
+                .split_once(',')
+                .ok_or_else(|| format!("line {}: expected plu,price", i + 1))?;
+            let price = price.trim().parse().map_err(|_| {
+                format!(
+                    "line {}: price {} is not a whole number of cents",
+                    i + 1,
+                    price.trim()
+                )
+            })?;
+            prices.push(PriceRequest {
+                plu: plu.trim().to_string(),
+                price,
+            });
+        }
+        Ok(PriceRequests {
+            source: ChangeSource::Csv,
+            unit: None,
+            prices,
+        })
+    }
+
+    pub fn changes(&self, menu: &Menu) -> Result<Vec<PriceChange>, String> {
+        let mut changes = Vec::new();
+        for request in &self.prices {
+            let item = menu
+                .items
+                .iter()
+                .find(|item| item.plu == request.plu)
+                .ok_or_else(|| format!("PLU {} is not on the menu", request.plu))?;
+            if item.price as i64 != request.price {
+                changes.push(PriceChange {
+                    id: item.id,
+                    plu: item.plu.clone(),
+                    item: item.long_name.clone(),
+                    field: "price".to_string(),
+                    old: item.price as i64,
+                    new: request.price,
+                });
+            }
+        }
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu;
+
+    const SOURCE: &str = r#"
+    let burrito = reg.add_item(
+        ItemBuilder::new("7f1b0b9e-5f0e-4b7e-9a47-2b8d1f6f0d11", "Beef Burrito", "Burrito")
+            .price(335).plu("480")
+            .upcharge(Modification::Extra, 50)
+            .slot(
+                SlotBuilder::new("Sauce", SlotType::Ingredient, Selection::AnyItem(&[sauce; 2]))
+                    .price_override(PriceOverrideBuilder::new().items(&[sauce]).price(335)),
+            ),
+    );
+    let taco = reg.add_item(ItemBuilder::new("7f1b0b9e-5f0e-4b7e-9a47-2b8d1f6f0d12", "Taco", "Taco").price(335).plu("100"));
+"#;
+
+    fn change(plu: &str, field: &str, old: i64, new: i64) -> PriceChange {
+        PriceChange {
+            id: ID::nil(),
+            plu: plu.to_string(),
+            item: "Beef Burrito".to_string(),
+            field: field.to_string(),
+            old,
+            new,
+        }
+    }
+
+    fn sources() -> BTreeMap<PathBuf, String> {
+        vec![(PathBuf::from("menu/burritos.rs"), SOURCE.to_string())]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn patch_source_rewrites_only_the_price_it_was_asked_to() {
+        let mut patched = sources();
+        patch_source(&mut patched, &change("480", "price", 335, 349)).unwrap();
+        patch_source(
+            &mut patched,
+            &change("480", "modifier_upcharge.Extra", 50, 60),
+        )
+        .unwrap();
+        let expected = SOURCE
+            .replace(".price(335).plu(\"480\")", ".price(349).plu(\"480\")")
+            .replace("Modification::Extra, 50", "Modification::Extra, 60");
+        assert_eq!(patched[Path::new("menu/burritos.rs")], expected);
+    }
+
+    #[test]
+    fn patch_source_refuses_what_it_cannot_find() {
+        let refused = |change: PriceChange| {
+            let mut patched = sources();
+            let error = patch_source(&mut patched, &change).unwrap_err();
+            assert_eq!(patched, sources(), "{}", error);
+            error
+        };
+        assert_eq!(
+            refused(change("999", "price", 335, 349)),
+            "PLU 999 is not in the menu source"
+        );
+        assert!(refused(change("480", "price", 300, 349)).starts_with("couldnt find .price(300"));
+        assert!(refused(change("480", "modifier_upcharge.Side", 50, 60))
+            .starts_with("couldnt find .upcharge(Modification::Side, 50"));
+        assert!(
+            refused(change("480", "slots.Sauce.price_overrides.0.price", 335, 0))
+                .contains("cant be patched")
+        );
+
+        let mut twice = sources();
+        twice.insert(PathBuf::from("menu/copy.rs"), SOURCE.to_string());
+        assert_eq!(
+            patch_source(&mut twice, &change("480", "price", 335, 349)).unwrap_err(),
+            "PLU 480 is in the menu source more than once"
+        );
+    }
+
+    #[test]
+    fn check_refuses_edited_changes_and_changed_sources() {
+        let dir = std::env::temp_dir().join(format!("menu-changes-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("burritos.rs"), SOURCE).unwrap();
+
+        let menu = menu::build().menu;
+        let mut changes = ChangeSet {
+            description: "plu=480 +14".to_string(),
+            source: ChangeSource::BulkAdjust,
+            unit: None,
+            menu_hash: menu_hash(&menu),
+            source_hash: source_hash(&dir),
+            changes: vec![change("480", "price", 335, 349)],
+            impact: Impact::default(),
+            approved_by: None,
+            approved_hash: None,
+        };
+        let not_approved = changes.check(&menu, &dir);
+        changes.approve("Terry");
+        let approved = changes.check(&menu, &dir);
+        changes.changes[0].new = 1;
+        let edited = changes.check(&menu, &dir);
+        changes.approve("Terry");
+        fs::write(dir.join("burritos.rs"), SOURCE.replace("335", "349")).unwrap();
+        let drifted = changes.check(&menu, &dir);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(not_approved, Err("change set is not approved".to_string()));
+        assert_eq!(approved, Ok(()));
+        assert_eq!(
+            edited,
+            Err("the changes were edited after they were approved".to_string())
+        );
+        assert_eq!(
+            drifted,
+            Err(format!(
+                "the menu sources in {} have changed since this change set was proposed",
+                dir.display()
+            ))
+        );
+    }
+
+    #[test]
+    fn from_csv_reads_prices_in_cents() {
+        let requests = PriceRequests::from_csv("PLU,Price\n480, 349\n\n100,199\n").unwrap();
+        assert_eq!(requests.source, ChangeSource::Csv);
+        assert_eq!(requests.unit, None);
+        assert_eq!(
+            requests.prices,
+            vec![
+                PriceRequest {
+                    plu: "480".to_string(),
+                    price: 349
+                },
+                PriceRequest {
+                    plu: "100".to_string(),
+                    price: 199
+                },
+            ]
+        );
+        assert_eq!(
+            PriceRequests::from_csv("480,349\n").unwrap_err(),
+            "expected a plu,price header"
+        );
+        assert_eq!(
+            PriceRequests::from_csv("plu,price\n480,3.49\n").unwrap_err(),
+            "line 2: price 3.49 is not a whole number of cents"
+        );
+    }
+}
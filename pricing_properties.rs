@@ -0,0 +1,469 @@
+//! Generative tests of the order simulator over the real menu.
+//!
+//! Each case builds a random order from a seed: sellable items with random
+//! quantities, variations, modifiers and slot choices within the slot
+//! limits, at a random time in one week, with up to two discounts. Some
+//! orders get a quantity below one or list a single discount twice, and
+//! those must be refused. Orders the menu refuses for reasons the generator
+//! does not model (out of daypart, discount constraints not met,
+//! incombinable discounts) are skipped. A failure names the seed;
+//! `PRICING_SEED=<seed> cargo test pricing_properties` reruns just that case.
+
+use crate::document::MenuDocument;
+use crate::menu;
+use crate::selection;
+use crate::simulator::{Order, OrderLine, Receipt, SimulationError, Simulator, SlotChoice};
+use adjunct::{DiscountDefinition, ItemDefinition, Menu, OrderConstraint, SlotDefinition};
+
+const CASES: u64 = 500;
+
+/// xorshift64*, enough to spread orders over the menu.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Zero is a fixed point of xorshift.
+        Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `0..n`; `n` must not be zero.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low + 1) as usize) as i64
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.next() % 100 < percent
+    }
+
+    fn pick<'a, T>(&mut self, values: &'a [T]) -> Option<&'a T> {
+        if values.is_empty() {
+            None
+        } else {
+            Some(&values[self.below(values.len())])
+        }
+    }
+}
+
+/// Whether an order can name `item`: shells have no PLU, and a few PLUs are
+/// shared by two items.
+fn orderable(menu: &Menu, item: &ItemDefinition) -> bool {
+    !item.plu.is_empty()
+        && menu
+            .items
+            .iter()
+            .filter(|other| other.plu == item.plu)
+            .count()
+            == 1
+}
+
+fn plain_line(item: &ItemDefinition, quantity: i64) -> OrderLine {
+    OrderLine {
+        plu: item.plu.clone(),
+        quantity,
+        variation: None,
+        modifiers: vec![],
+        slots: vec![],
+    }
+}
+
+/// Items for `slot`, as many as it allows; none when nothing fits it.
+fn slot_choice(rng: &mut Rng, menu: &Menu, slot: &SlotDefinition) -> Option<SlotChoice> {
+    let candidates: Vec<&ItemDefinition> = menu
+        .items
+        .iter()
+        .filter(|item| selection::matches(&slot.selection, item) && orderable(menu, item))
+        .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+    let minimum = slot.minimum_quantity as i64;
+    let maximum = match slot.maximum_quantity as i64 {
+        0 => minimum + 3,
+        maximum => maximum,
+    };
+    let mut remaining = rng.range(minimum, maximum.max(minimum));
+    let mut items = Vec::new();
+    while remaining > 0 {
+        let quantity = rng.range(1, remaining.min(2));
+        items.push(plain_line(rng.pick(&candidates)?, quantity));
+        remaining -= quantity;
+    }
+    Some(SlotChoice {
+        slot: slot.name.clone(),
+        items,
+    })
+}
+
+fn order_line(rng: &mut Rng, menu: &Menu, item: &ItemDefinition) -> OrderLine {
+    let mut line = plain_line(item, rng.range(1, 3));
+    if rng.chance(50) {
+        line.variation = rng
+            .pick(&item.variations)
+            .map(|variation| variation.name.clone());
+    }
+    line.modifiers = item
+        .modifiers
+        .iter()
+        .filter(|_| rng.chance(30))
+        .cloned()
+        .collect();
+    for slot in &item.slots {
+        if rng.chance(50) {
+            line.slots.extend(slot_choice(rng, menu, slot));
+        }
+    }
+    line
+}
+
+fn random_order(rng: &mut Rng, menu: &Menu) -> Order {
+    let sellable: Vec<&ItemDefinition> = selection::sellable(menu)
+        .into_iter()
+        .filter(|item| orderable(menu, item))
+        .collect();
+    let mut lines = Vec::new();
+    for _ in 0..rng.range(1, 4) {
+        if let Some(item) = rng.pick(&sellable) {
+            lines.push(order_line(rng, menu, item));
+        }
+    }
+    if rng.chance(10) {
+        spoil_quantity(rng, &mut lines);
+    }
+    // Most discounts are incombinable, so two are rarely asked for.
+    let count = match rng.below(10) {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    };
+    let mut discounts: Vec<String> = (0..count)
+        .filter_map(|_| rng.pick(&menu.discounts))
+        .map(|discount| discount.identifier.clone())
+        .collect();
+    if rng.chance(10) {
+        discounts.extend(discounts.first().cloned());
+    }
+    // 2026-10-19 is a Monday. Any hour, so windows past midnight are hit.
+    let time = format!(
+        "2026-10-{}T{:02}:{:02}:00",
+        19 + rng.below(7),
+        rng.range(0, 23),
+        rng.below(60)
+    );
+    Order {
+        time,
+        lines,
+        discounts,
+    }
+}
+
+/// Sets the quantity of a random line, or of an item chosen in one of its
+/// slots, to zero or less.
+fn spoil_quantity(rng: &mut Rng, lines: &mut [OrderLine]) {
+    let bad = rng.range(-2, 0);
+    let i = rng.below(lines.len().max(1));
+    let line = match lines.get_mut(i) {
+        Some(line) => line,
+        None => return,
+    };
+    let chosen: Vec<(usize, usize)> = line
+        .slots
+        .iter()
+        .enumerate()
+        .flat_map(|(c, choice)| (0..choice.items.len()).map(move |j| (c, j)))
+        .collect();
+    match rng.pick(&chosen) {
+        Some(&(c, j)) if rng.chance(50) => line.slots[c].items[j].quantity = bad,
+        _ => line.quantity = bad,
+    }
+}
+
+/// Why the simulator has to refuse `order`, if it has to.
+fn must_refuse(menu: &Menu, order: &Order) -> Option<String> {
+    for line in &order.lines {
+        let chosen = line.slots.iter().flat_map(|choice| &choice.items);
+        if let Some(bad) = std::iter::once(line)
+            .chain(chosen)
+            .find(|line| line.quantity < 1)
+        {
+            return Some(format!("PLU {} has quantity {}", bad.plu, bad.quantity));
+        }
+    }
+    for (i, identifier) in order.discounts.iter().enumerate() {
+        let single = menu
+            .discounts
+            .iter()
+            .any(|discount| &discount.identifier == identifier && discount.single);
+        if single && order.discounts[..i].contains(identifier) {
+            return Some(format!("single discount {} is listed twice", identifier));
+        }
+    }
+    None
+}
+
+/// Refusals a valid order can still get.
+fn skipped(error: &SimulationError) -> bool {
+    matches!(
+        error,
+        SimulationError::OutOfDaypart { .. }
+            | SimulationError::DiscountNotApplicable(_)
+            | SimulationError::Incombinable(_)
+    )
+}
+
+fn check_receipt(menu: &Menu, order: &Order, receipt: &Receipt) -> Result<(), String> {
+    // Components can be negative: some combos credit a cheaper entree.
+    for line in &receipt.lines {
+        if line.quantity < 1 || line.total < 0 {
+            return Err(format!(
+                "{} x {} totals {}",
+                line.quantity, line.name, line.total
+            ));
+        }
+        if line.total != line.unit_price * line.quantity {
+            return Err(format!(
+                "{} x {} at {} totals {}",
+                line.quantity, line.name, line.unit_price, line.total
+            ));
+        }
+    }
+    let lines: i64 = receipt.lines.iter().map(|line| line.total).sum();
+    if receipt.subtotal != lines || receipt.subtotal < 0 {
+        return Err(format!(
+            "subtotal is {}, the lines add up to {}",
+            receipt.subtotal, lines
+        ));
+    }
+    let discounted: i64 = receipt
+        .discounts
+        .iter()
+        .map(|discount| discount.amount)
+        .sum();
+    if discounted > receipt.subtotal {
+        return Err(format!(
+            "discounts of {} exceed the subtotal of {}",
+            discounted, receipt.subtotal
+        ));
+    }
+    // Compared before the simulator clamps the total at zero.
+    if receipt.total != receipt.subtotal - discounted {
+        return Err(format!(
+            "total is {}, expected {} less {}",
+            receipt.total, receipt.subtotal, discounted
+        ));
+    }
+    for applied in &receipt.discounts {
+        let discount = menu
+            .discounts
+            .iter()
+            .find(|discount| discount.identifier == applied.identifier)
+            .ok_or_else(|| format!("applied unknown discount {}", applied.identifier))?;
+        if applied.amount < 0 {
+            return Err(format!("{} added {}", discount.name, -applied.amount));
+        }
+        if let Some(max_amount) = discount.max_amount {
+            if applied.amount > max_amount as i64 {
+                return Err(format!(
+                    "{} took {} off, more than its cap of {}",
+                    discount.name, applied.amount, max_amount
+                ));
+            }
+        }
+        if discount.incombinable && receipt.discounts.len() > 1 {
+            return Err(format!("incombinable {} was stacked", discount.name));
+        }
+    }
+    if receipt.discounts.len() != order.discounts.len() {
+        return Err("some requested discounts were silently dropped".to_string());
+    }
+    Ok(())
+}
+
+/// Orders with one paid add-on or upcharged modifier taken off a line.
+fn without_add_ons(order: &Order, receipt: &Receipt, menu: &Menu) -> Vec<Order> {
+    let mut smaller = Vec::new();
+    for (i, (line, priced)) in order.lines.iter().zip(&receipt.lines).enumerate() {
+        let item = match menu.items.iter().find(|item| item.plu == line.plu) {
+            Some(item) => item,
+            None => continue,
+        };
+        for (m, modifier) in line.modifiers.iter().enumerate() {
+            if item
+                .modifier_upcharge
+                .get(modifier)
+                .is_some_and(|price| *price as i64 > 0)
+            {
+                let mut order = order.clone();
+                order.lines[i].modifiers.remove(m);
+                smaller.push(order);
+            }
+        }
+        for (c, choice) in line.slots.iter().enumerate() {
+            let slot = match item.slots.iter().find(|slot| slot.name == choice.slot) {
+                Some(slot) => slot,
+                None => continue,
+            };
+            let quantity: i64 = choice.items.iter().map(|line| line.quantity).sum();
+            if quantity <= slot.minimum_quantity as i64 {
+                continue;
+            }
+            for (j, chosen) in choice.items.iter().enumerate() {
+                let paid = priced
+                    .components
+                    .iter()
+                    .any(|component| component.plu == chosen.plu && component.total > 0);
+                if paid {
+                    let mut order = order.clone();
+                    let items = &mut order.lines[i].slots[c].items;
+                    items[j].quantity -= 1;
+                    if items[j].quantity == 0 {
+                        items.remove(j);
+                    }
+                    smaller.push(order);
+                }
+            }
+        }
+    }
+    smaller
+}
+
+fn check_case(document: &MenuDocument, seed: u64) -> Result<bool, String> {
+    let menu = &document.menu;
+    let simulator = Simulator::new(document);
+    let order = random_order(&mut Rng::new(seed), menu);
+    if let Some(reason) = must_refuse(menu, &order) {
+        return match simulator.price(&order) {
+            Err(SimulationError::InvalidQuantity { .. })
+            | Err(SimulationError::RepeatedDiscount(_)) => Ok(true),
+            Err(error) if skipped(&error) => Ok(false),
+            other => Err(format!(
+                "{} but got {:?}\norder: {:?}",
+                reason, other, order
+            )),
+        };
+    }
+    let receipt = match simulator.price(&order) {
+        Ok(receipt) => receipt,
+        Err(error) if skipped(&error) => return Ok(false),
+        Err(error) => return Err(format!("{}\norder: {:?}", error, order)),
+    };
+    check_receipt(menu, &order, &receipt).map_err(|e| format!("{}\norder: {:?}", e, order))?;
+
+    for smaller in without_add_ons(&order, &receipt, menu) {
+        let priced = match simulator.price(&smaller) {
+            Ok(priced) => priced,
+            Err(error) if skipped(&error) => continue,
+            Err(error) => return Err(format!("{}\norder: {:?}", error, smaller)),
+        };
+        if priced.subtotal > receipt.subtotal || priced.total > receipt.total {
+            return Err(format!(
+                "taking off an add-on raised the price from {}/{} to {}/{}\norder: {:?}\nwithout: {:?}",
+                receipt.subtotal, receipt.total, priced.subtotal, priced.total, order, smaller
+            ));
+        }
+    }
+    Ok(true)
+}
+
+#[test]
+fn pricing_invariants_hold_for_random_orders() {
+    let document = menu::build();
+    let seeds: Vec<u64> = match std::env::var("PRICING_SEED") {
+        Ok(seed) => vec![seed.parse().expect("Couldnt parse PRICING_SEED")],
+        Err(_) => (1..=CASES).collect(),
+    };
+    let mut checked = 0;
+    for seed in &seeds {
+        match check_case(&document, *seed) {
+            Ok(true) => checked += 1,
+            Ok(false) => {}
+            Err(e) => panic!("seed {}: {}", seed, e),
+        }
+    }
+    assert!(
+        checked * 2 >= seeds.len(),
+        "only {} of {} random orders could be checked",
+        checked,
+        seeds.len()
+    );
+}
+
+#[test]
+fn incombinable_discounts_are_refused_together() {
+    let document = menu::build();
+    let menu = &document.menu;
+    let incombinable: Vec<&str> = menu
+        .discounts
+        .iter()
+        .filter(|discount| discount.incombinable)
+        .map(|discount| discount.identifier.as_str())
+        .collect();
+    let item = selection::sellable(menu)
+        .into_iter()
+        .find(|item| orderable(menu, item))
+        .expect("Couldnt find an orderable item");
+    for (a, b) in incombinable.iter().zip(incombinable.iter().skip(1)) {
+        let order = Order {
+            time: "2026-10-19T12:00:00".to_string(),
+            lines: vec![plain_line(item, 1)],
+            discounts: vec![a.to_string(), b.to_string()],
+        };
+        match Simulator::new(&document).price(&order) {
+            Err(SimulationError::Incombinable(_)) => {}
+            other => panic!("discounts {} and {} stacked: {:?}", a, b, other),
+        }
+    }
+}
+
+/// Lines that meet the item constraints of `discount`.
+fn qualifying_lines(menu: &Menu, discount: &DiscountDefinition) -> Vec<OrderLine> {
+    discount
+        .constraints
+        .iter()
+        .filter_map(|constraint| match constraint {
+            OrderConstraint::ItemQuantity(constraint) => selection::sellable(menu)
+                .into_iter()
+                .find(|item| {
+                    orderable(menu, item) && selection::matches(&constraint.selection, item)
+                })
+                .map(|item| plain_line(item, (constraint.minimum_quantity as i64).max(1))),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn single_discounts_are_refused_twice() {
+    let document = menu::build();
+    let menu = &document.menu;
+    let simulator = Simulator::new(&document);
+    for discount in menu
+        .discounts
+        .iter()
+        .filter(|discount| discount.single && !discount.incombinable)
+    {
+        let mut order = Order {
+            time: "2026-10-19T12:00:00".to_string(),
+            lines: qualifying_lines(menu, discount),
+            discounts: vec![discount.identifier.clone()],
+        };
+        if let Err(error) = simulator.price(&order) {
+            panic!("{} does not apply once: {}", discount.name, error);
+        }
+        order.discounts.push(discount.identifier.clone());
+        match simulator.price(&order) {
+            Err(SimulationError::RepeatedDiscount(_)) => {}
+            other => panic!("{} applied twice: {:?}", discount.name, other),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Versioned publishing.
+//!
+//! Every build that changes what is published gets the next version number.
+//! Files are published under a root directory, the working directory for the
+//! commands. They are copied to `archive/v<version>/` under it, the release
+//! is added to `archive/index.json`, and the version and content hash are
+//! embedded in each published menu so POS clients can report what they run.
+//! A build that changes nothing keeps the current version.
+//! `rollback <version>` puts an archived release's files back, removes files
+//! it did not have, and records that in the index.
+
+use crate::hash;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where the commands publish.
+pub const PUBLISH_DIR: &str = ".";
+/// The archive, under the publish root.
+pub const ARCHIVE_DIR: &str = "archive";
+/// The release index, in the archive.
+pub const INDEX_FILE: &str = "index.json";
+
+/// The version embedded in a published menu.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct MenuVersion {
+    pub number: u32,
+    /// Hash of everything published with this version, before the version
+    /// was embedded.
+    pub hash: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Release {
+    pub version: u32,
+    pub hash: String,
+    pub published: String,
+    pub files: Vec<String>,
+    /// Whether this re-published an earlier version.
+    #[serde(default)]
+    pub rollback: bool,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct PublishIndex {
+    pub releases: Vec<Release>,
+}
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+fn write_file(path: &Path, contents: &str) {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .unwrap_or_else(|e| panic!("Couldnt create {}: {}", dir.display(), e));
+    }
+    fs::write(path, contents).unwrap_or_else(|e| panic!("Couldnt write {}: {}", path.display(), e));
+}
+
+/// Where `file` of `version` is archived under `root`.
+pub fn archive_path(root: &Path, version: u32, file: &str) -> PathBuf {
+    root.join(ARCHIVE_DIR)
+        .join(format!("v{}", version))
+        .join(file)
+}
+
+fn index_path(root: &Path) -> PathBuf {
+    root.join(ARCHIVE_DIR).join(INDEX_FILE)
+}
+
+/// Hash over every file's path and contents.
+pub fn content_hash(files: &[(String, String)]) -> String {
+    let mut files: Vec<&(String, String)> = files.iter().collect();
+    files.sort();
+    let mut bytes = Vec::new();
+    for (path, contents) in files {
+        bytes.extend_from_slice(path.as_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(contents.as_bytes());
+        bytes.push(0);
+    }
+    hash::hex(&bytes)
+}
+
+impl PublishIndex {
+    /// The index in `archive/index.json` under `root`, or an empty one
+    /// before the first publish.
+    pub fn load(root: &Path) -> Self {
+        let path = index_path(root);
+        match fs::read_to_string(&path) {
+            Ok(json) => serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("Couldnt parse {}: {}", path.display(), e)),
+            Err(_) => PublishIndex::default(),
+        }
+    }
+
+    fn write(&self, root: &Path) {
+        let json = serde_json::to_string_pretty(self).expect("Couldnt serialize publish index");
+        write_file(&index_path(root), &json);
+    }
+
+    /// What is published now.
+    pub fn current(&self) -> Option<&Release> {
+        self.releases.last()
+    }
+
+    fn latest_version(&self) -> u32 {
+        self.releases
+            .iter()
+            .map(|release| release.version)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Publishes `files`, built without a version, under `root`. `stamp` builds
+/// them again with the version embedded. Returns the release and whether it
+/// is new.
+pub fn publish(
+    root: &Path,
+    files: &[(String, String)],
+    stamp: impl FnOnce(MenuVersion) -> Vec<(String, String)>,
+) -> (Release, bool) {
+    let mut index = PublishIndex::load(root);
+    let hash = content_hash(files);
+    let current = index
+        .current()
+        .filter(|release| release.hash == hash)
+        .cloned();
+    let number = match &current {
+        Some(release) => release.version,
+        None => index.latest_version() + 1,
+    };
+
+    let stamped = stamp(MenuVersion {
+        number,
+        hash: hash.clone(),
+    });
+    for (path, contents) in &stamped {
+        write_file(&root.join(path), contents);
+    }
+    if let Some(release) = current {
+        return (release, false);
+    }
+
+    for (path, contents) in &stamped {
+        write_file(&archive_path(root, number, path), contents);
+    }
+    let release = Release {
+        version: number,
+        hash,
+        published: now(),
+        files: stamped.into_iter().map(|(path, _)| path).collect(),
+        rollback: false,
+    };
+    index.releases.push(release.clone());
+    index.write(root);
+    (release, true)
+}
+
+/// Re-publishes the archived files of `version` under `root`.
+pub fn rollback(root: &Path, version: u32) -> Result<Release, String> {
+    let mut index = PublishIndex::load(root);
+    let archived = index
+        .releases
+        .iter()
+        .find(|release| release.version == version && !release.rollback)
+        .cloned()
+        .ok_or_else(|| format!("no published version {}", version))?;
+
+    let mut contents = Vec::new();
+    for path in &archived.files {
+        let archived = fs::read_to_string(archive_path(root, version, path))
+            .map_err(|e| format!("couldnt read archived {}: {}", path, e))?;
+        contents.push((path, archived));
+    }
+    if let Some(current) = index.current() {
+        for path in current
+            .files
+            .iter()
+            .filter(|path| !archived.files.contains(path))
+        {
+            let path = root.join(path);
+            match fs::remove_file(&path) {
+                // Already removed by hand.
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                result => {
+                    result.unwrap_or_else(|e| panic!("Couldnt remove {}: {}", path.display(), e))
+                }
+            }
+        }
+    }
+    for (path, contents) in contents {
+        write_file(&root.join(path), &contents);
+    }
+    let release = Release {
+        published: now(),
+        rollback: true,
+        ..archived
+    };
+    index.releases.push(release.clone());
+    index.write(root);
+    Ok(release)
+}
+
+impl fmt::Display for Release {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "v{:<4} {}  {}  {} files",
+            self.version,
+            self.published,
+            self.hash,
+            self.files.len()
+        )?;
+        if self.rollback {
+            write!(f, "  (rollback)")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PublishIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Published versions ({}):", self.releases.len())?;
+        for release in &self.releases {
+            writeln!(f, "  {}", release)?;
+        }
+        if let Some(current) = self.current() {
+            writeln!(f, "Current: v{}", current.version)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(files: &[(&str, &str)]) -> Vec<(String, String)> {
+        files
+            .iter()
+            .map(|(path, contents)| (path.to_string(), contents.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn rollback_restores_an_earlier_release() {
+        let root = std::env::temp_dir().join(format!("menu-publish-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+
+        let first = files(&[("menu.json", "one"), ("menu.es.json", "uno")]);
+        let (release, new) = publish(&root, &first, |_| first.clone());
+        assert_eq!((release.version, new), (1, true));
+        let second = files(&[("menu.json", "two"), ("units/94/menu.json", "two")]);
+        publish(&root, &second, |_| second.clone());
+        fs::remove_file(root.join("menu.es.json")).unwrap();
+        // A file only the current release has, already removed by hand.
+        fs::remove_file(root.join("units/94/menu.json")).unwrap();
+
+        let restored = rollback(&root, 1);
+        let read = |path: &str| fs::read_to_string(root.join(path)).ok();
+        let contents = (read("menu.json"), read("menu.es.json"));
+        let index = PublishIndex::load(&root);
+        let _ = fs::remove_dir_all(&root);
+
+        let restored = restored.unwrap();
+        assert_eq!((restored.version, restored.rollback), (1, true));
+        assert_eq!(contents, (Some("one".to_string()), Some("uno".to_string())));
+        assert_eq!(index.releases.len(), 3);
+        assert_eq!(index.current(), Some(&restored));
+    }
+}
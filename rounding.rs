@@ -0,0 +1,296 @@
+//! Price rounding (charm pricing) policy.
+//!
+//! `rounding.json` picks how computed prices are rounded, e.g.
+//! `{"style": "end_in", "digit": 9}` or `{"style": "nearest", "cents": 5}`.
+//! Authored prices that the policy would change are flagged. Free (zero)
+//! prices always pass, and a paid price never rounds down to free.
+
+use crate::selection;
+use adjunct::Menu;
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+
+pub const ROUNDING_FILE: &str = "rounding.json";
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    #[default]
+    Nearest,
+    Up,
+    Down,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(tag = "style", rename_all = "snake_case")]
+pub enum RoundingPolicy {
+    /// Leave prices as they are.
+    #[default]
+    None,
+    /// Prices end in `digit` cents, e.g. 9 for $3.49.
+    EndIn {
+        digit: i64,
+        #[serde(default)]
+        direction: Direction,
+    },
+    /// Prices are a multiple of `cents`, e.g. 5 or 10.
+    Nearest {
+        cents: i64,
+        #[serde(default)]
+        direction: Direction,
+    },
+}
+
+/// The closest of `below` and `above` to `price` in `direction`, ties going up.
+fn pick(price: i64, below: i64, above: i64, direction: Direction) -> i64 {
+    match direction {
+        Direction::Up => above,
+        Direction::Down => below,
+        Direction::Nearest if price - below < above - price => below,
+        Direction::Nearest => above,
+    }
+}
+
+impl RoundingPolicy {
+    /// The policy in `rounding.json`, or no rounding when there is no such file.
+    pub fn load() -> Self {
+        match fs::read_to_string(ROUNDING_FILE) {
+            Ok(json) => serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("Couldnt parse {}: {}", ROUNDING_FILE, e)),
+            Err(_) => RoundingPolicy::None,
+        }
+    }
+
+    pub fn round(&self, price: i64) -> i64 {
+        if price <= 0 {
+            return price;
+        }
+        let rounded = match *self {
+            RoundingPolicy::None => price,
+            RoundingPolicy::EndIn { digit, direction } => {
+                let below = price - (price - digit).rem_euclid(10);
+                pick(
+                    price,
+                    below,
+                    below + if below == price { 0 } else { 10 },
+                    direction,
+                )
+            }
+            RoundingPolicy::Nearest { cents, direction } if cents > 1 => {
+                let below = price - price.rem_euclid(cents);
+                pick(
+                    price,
+                    below,
+                    below + if below == price { 0 } else { cents },
+                    direction,
+                )
+            }
+            RoundingPolicy::Nearest { .. } => price,
+        };
+        // Rounding down must not make a paid item free.
+        if rounded > 0 {
+            rounded
+        } else {
+            self.smallest()
+        }
+    }
+
+    /// The smallest positive price the policy allows.
+    fn smallest(&self) -> i64 {
+        match *self {
+            RoundingPolicy::EndIn { digit, .. } if digit.rem_euclid(10) == 0 => 10,
+            RoundingPolicy::EndIn { digit, .. } => digit.rem_euclid(10),
+            RoundingPolicy::Nearest { cents, .. } => cents.max(1),
+            RoundingPolicy::None => 1,
+        }
+    }
+
+    pub fn allows(&self, price: i64) -> bool {
+        price == 0 || self.round(price) == price
+    }
+}
+
+pub struct RoundingReport {
+    pub policy: RoundingPolicy,
+    /// Authored prices the policy would change.
+    pub violations: Vec<String>,
+    /// Dynamic pricing results the policy would change.
+    pub promo_violations: Vec<String>,
+}
+
+impl RoundingReport {
+    pub fn check(menu: &Menu, policy: RoundingPolicy) -> Self {
+        let mut violations = Vec::new();
+        let mut flag = |what: String, price: i64| {
+            if !policy.allows(price) {
+                violations.push(format!(
+                    "{} is {}, expected {}",
+                    what,
+                    price,
+                    policy.round(price)
+                ));
+            }
+        };
+
+        for item in &menu.items {
+            flag(
+                format!("{} ({}) price", item.long_name, item.plu),
+                item.price as i64,
+            );
+            for variation in &item.variations {
+                if let Some(price) = variation.price {
+                    flag(
+                        format!("{} ({}) {} price", item.long_name, item.plu, variation.name),
+                        price as i64,
+                    );
+                }
+            }
+            for (modifier, price) in &item.modifier_upcharge {
+                flag(
+                    format!("{} ({}) {:?} upcharge", item.long_name, item.plu, modifier),
+                    *price as i64,
+                );
+            }
+        }
+        for (path, slot) in selection::all_slots(menu) {
+            for price_override in &slot.price_overrides {
+                flag(
+                    format!("{} price override", path),
+                    price_override.price as i64,
+                );
+            }
+        }
+
+        let mut promo_violations = Vec::new();
+        for rule_set in &menu.dynamic_pricing {
+            for rule in &rule_set.rules {
+                for item in menu
+                    .items
+                    .iter()
+                    .filter(|item| selection::matches(&rule.selection, item))
+                {
+                    let price = crate::simulator::apply_modification(
+                        &rule.pricing_modification,
+                        item.price as i64,
+                    );
+                    if !policy.allows(price) {
+                        promo_violations.push(format!(
+                            "{} makes {} ({}) {}, which rounds to {}",
+                            rule_set.name,
+                            item.long_name,
+                            item.plu,
+                            price,
+                            policy.round(price)
+                        ));
+                    }
+                }
+            }
+        }
+
+        RoundingReport {
+            policy,
+            violations,
+            promo_violations,
+        }
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.violations
+            .iter()
+            .chain(&self.promo_violations)
+            .cloned()
+            .collect()
+    }
+}
+
+impl fmt::Display for RoundingReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Rounding policy: {:?}", self.policy)?;
+        writeln!(f, "Authored prices off policy ({}):", self.violations.len())?;
+        for violation in &self.violations {
+            writeln!(f, "  {}", violation)?;
+        }
+        writeln!(
+            f,
+            "Promo prices off policy ({}), rounded by the simulator:",
+            self.promo_violations.len()
+        )?;
+        for violation in &self.promo_violations {
+            writeln!(f, "  {}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn end_in(digit: i64, direction: Direction) -> RoundingPolicy {
+        RoundingPolicy::EndIn { digit, direction }
+    }
+
+    fn nearest(cents: i64, direction: Direction) -> RoundingPolicy {
+        RoundingPolicy::Nearest { cents, direction }
+    }
+
+    fn rounded(policy: RoundingPolicy, prices: &[i64]) -> Vec<i64> {
+        prices.iter().map(|price| policy.round(*price)).collect()
+    }
+
+    #[test]
+    fn none_leaves_prices_alone() {
+        assert_eq!(rounded(RoundingPolicy::None, &[0, 1, 341]), [0, 1, 341]);
+        assert!(RoundingPolicy::None.allows(341));
+    }
+
+    #[test]
+    fn end_in_rounds_to_the_digit_in_each_direction() {
+        let prices = [341, 345, 349];
+        assert_eq!(
+            rounded(end_in(9, Direction::Nearest), &prices),
+            [339, 349, 349]
+        );
+        assert_eq!(rounded(end_in(9, Direction::Up), &prices), [349, 349, 349]);
+        assert_eq!(
+            rounded(end_in(9, Direction::Down), &prices),
+            [339, 339, 349]
+        );
+        assert_eq!(
+            rounded(end_in(5, Direction::Nearest), &[340, 350]),
+            [345, 355]
+        );
+        assert!(end_in(9, Direction::Nearest).allows(349));
+        assert!(!end_in(9, Direction::Nearest).allows(345));
+    }
+
+    #[test]
+    fn nearest_rounds_to_a_multiple_in_each_direction() {
+        let prices = [342, 343, 345];
+        assert_eq!(
+            rounded(nearest(5, Direction::Nearest), &prices),
+            [340, 345, 345]
+        );
+        assert_eq!(rounded(nearest(5, Direction::Up), &prices), [345, 345, 345]);
+        assert_eq!(
+            rounded(nearest(5, Direction::Down), &prices),
+            [340, 340, 345]
+        );
+        // Ties go up.
+        assert_eq!(rounded(nearest(10, Direction::Nearest), &[345]), [350]);
+        assert_eq!(rounded(nearest(1, Direction::Down), &[343]), [343]);
+    }
+
+    #[test]
+    fn rounding_never_makes_a_paid_price_free() {
+        assert_eq!(rounded(end_in(9, Direction::Down), &[5, 9, 12]), [9, 9, 9]);
+        assert_eq!(rounded(end_in(0, Direction::Down), &[5, 12]), [10, 10]);
+        assert_eq!(rounded(end_in(9, Direction::Nearest), &[1]), [9]);
+        assert_eq!(rounded(nearest(5, Direction::Down), &[3]), [5]);
+        assert_eq!(rounded(nearest(25, Direction::Nearest), &[10]), [25]);
+        // Free and negative prices, e.g. credits, are left alone.
+        assert_eq!(rounded(end_in(9, Direction::Down), &[0, -5]), [0, -5]);
+        assert!(end_in(9, Direction::Down).allows(0));
+    }
+}
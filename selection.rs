@@ -0,0 +1,95 @@
+//! Helpers for evaluating `ItemSelection`s against the built menu.
+
+use adjunct::{Category, ItemDefinition, ItemSelection, Menu, SlotDefinition, SlotType, ID};
+use std::collections::BTreeSet;
+
+/// Tags an `ItemSelection` selects on.
+pub fn tags(selection: &ItemSelection) -> Vec<&str> {
+    match selection {
+        ItemSelection::Tag(tag) => vec![tag.as_str()],
+        ItemSelection::AnyTag(tags) => tags.iter().map(|tag| tag.as_str()).collect(),
+        _ => vec![],
+    }
+}
+
+pub fn matches(selection: &ItemSelection, item: &ItemDefinition) -> bool {
+    match selection {
+        ItemSelection::Id(id) => item.id == *id,
+        ItemSelection::AnyId(ids) => ids.contains(&item.id),
+        ItemSelection::Tag(tag) => item.tags.contains(tag),
+        ItemSelection::AnyTag(tags) => tags.iter().any(|tag| item.tags.contains(tag)),
+        _ => false,
+    }
+}
+
+/// Ids of every item that some slot on the menu can hold.
+pub fn slot_contents(menu: &Menu) -> BTreeSet<ID> {
+    let slots = all_slots(menu);
+    menu.items
+        .iter()
+        .filter(|item| slots.iter().any(|(_, slot)| matches(&slot.selection, item)))
+        .map(|item| item.id)
+        .collect()
+}
+
+/// Items sold on their own: those a category shows, those offered by an
+/// item shell (e.g. the burritos behind the "Burrito" button), and those no
+/// slot can hold.
+pub fn sellable(menu: &Menu) -> Vec<&ItemDefinition> {
+    let contents = slot_contents(menu);
+    let slots = all_slots(menu);
+    menu.items
+        .iter()
+        .filter(|item| {
+            menu.categories
+                .iter()
+                .any(|category| category.tags.iter().any(|tag| item.tags.contains(tag)))
+                || slots.iter().any(|(_, slot)| {
+                    matches!(slot.slot_type, SlotType::ItemShell) && matches(&slot.selection, item)
+                })
+                || !contents.contains(&item.id)
+        })
+        .collect()
+}
+
+/// Items `category` shows, and the items its item shells offer.
+pub fn category_items<'a>(menu: &'a Menu, category: &Category) -> Vec<&'a ItemDefinition> {
+    let shown: Vec<&ItemDefinition> = menu
+        .items
+        .iter()
+        .filter(|item| category.tags.iter().any(|tag| item.tags.contains(tag)))
+        .collect();
+    menu.items
+        .iter()
+        .filter(|item| {
+            shown.iter().any(|shown| {
+                shown.id == item.id
+                    || shown.slots.iter().any(|slot| {
+                        matches!(slot.slot_type, SlotType::ItemShell)
+                            && matches(&slot.selection, item)
+                    })
+            })
+        })
+        .collect()
+}
+
+/// Whether `item` is an item shell, a button that only picks another item.
+pub fn is_shell(item: &ItemDefinition) -> bool {
+    item.slots
+        .iter()
+        .any(|slot| matches!(slot.slot_type, SlotType::ItemShell))
+}
+
+/// Every slot on the menu together with a readable path, e.g. `"2 Crispy Taco Combo / Entree"`.
+pub fn all_slots(menu: &Menu) -> Vec<(String, &SlotDefinition)> {
+    let mut slots = Vec::new();
+    for slot in &menu.slots {
+        slots.push((slot.name.clone(), slot));
+    }
+    for item in &menu.items {
+        for slot in &item.slots {
+            slots.push((format!("{} / {}", item.long_name, slot.name), slot));
+        }
+    }
+    slots
+}
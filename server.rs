@@ -0,0 +1,402 @@
+//! Local HTTP service for POS terminals and kiosks.
+//!
+//! `serve [--port <port>]` serves what `build` and `availability` published
+//! in the current directory, on 127.0.0.1 (port 8080 by default):
+//!
+//! - `GET /menu.json`, the base menu.
+//! - `GET /units/<unit>/menu.json`, the unit's price tier menu, or the base
+//!   menu for units without a tier.
+//! - `GET /units/<unit>/availability.json`, the unit's out-of-stock overlay.
+//! - `GET /health`, whether there is a menu to serve and its version.
+//!
+//! Files are read on every request, so a rebuild or rollback is served
+//! straight away. Every file gets a strong ETag from its contents, and a
+//! request whose `If-None-Match` lists it gets `304 Not Modified`.
+
+use crate::hash;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+pub const DEFAULT_PORT: u16 = 8080;
+const MENU_FILE: &str = "menu.json";
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub if_none_match: Option<String>,
+}
+
+impl Request {
+    /// Reads the request line and headers; requests we serve have no body.
+    pub fn read(reader: &mut impl BufRead) -> Result<Self, String> {
+        let mut line = String::new();
+        reader
+            .read_line(&mut line)
+            .map_err(|e| format!("couldnt read request: {}", e))?;
+        let mut parts = line.split_whitespace();
+        let (method, target) = match (parts.next(), parts.next()) {
+            (Some(method), Some(target)) => (method.to_string(), target),
+            _ => return Err(format!("bad request line {:?}", line.trim_end())),
+        };
+        let path = target.split('?').next().unwrap_or("").to_string();
+
+        let mut if_none_match = None;
+        loop {
+            let mut header = String::new();
+            reader
+                .read_line(&mut header)
+                .map_err(|e| format!("couldnt read request: {}", e))?;
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("if-none-match") {
+                    if_none_match = Some(value.trim().to_string());
+                }
+            }
+        }
+        Ok(Request {
+            method,
+            path,
+            if_none_match,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub etag: Option<String>,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: String) -> Self {
+        Response {
+            status,
+            etag: None,
+            body,
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Response::json(status, serde_json::json!({ "error": message }).to_string())
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            304 => "Not Modified",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            503 => "Service Unavailable",
+            _ => "",
+        }
+    }
+
+    /// Writes the response; `HEAD` requests get the headers only.
+    pub fn write_to(&self, out: &mut impl Write, head: bool) -> std::io::Result<()> {
+        write!(out, "HTTP/1.1 {} {}\r\n", self.status, self.reason())?;
+        if let Some(etag) = &self.etag {
+            write!(out, "ETag: {}\r\n", etag)?;
+            write!(out, "Cache-Control: no-cache\r\n")?;
+        }
+        if self.status == 405 {
+            write!(out, "Allow: GET, HEAD\r\n")?;
+        }
+        if self.status != 304 {
+            write!(out, "Content-Type: application/json\r\n")?;
+            write!(out, "Content-Length: {}\r\n", self.body.len())?;
+        }
+        write!(out, "Connection: close\r\n\r\n")?;
+        if !head && self.status != 304 {
+            out.write_all(self.body.as_bytes())?;
+        }
+        out.flush()
+    }
+}
+
+/// Strong ETag of `contents`.
+pub fn etag(contents: &str) -> String {
+    format!("\"{}\"", hash::hex(contents.as_bytes()))
+}
+
+/// Whether an `If-None-Match` value lists `etag`. Weak tags never match, as
+/// the comparison is strong.
+fn none_match(header: &str, etag: &str) -> bool {
+    header
+        .split(',')
+        .map(str::trim)
+        .any(|tag| tag == "*" || tag == etag)
+}
+
+/// Unit ids end up in file names, so only plain ones are accepted.
+fn valid_unit(unit: &str) -> bool {
+    !unit.is_empty()
+        && unit
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Serves published files from a directory.
+#[derive(Clone, Debug)]
+pub struct MenuServer {
+    root: PathBuf,
+}
+
+impl MenuServer {
+    pub fn new(root: &Path) -> Self {
+        MenuServer {
+            root: root.to_path_buf(),
+        }
+    }
+
+    fn read(&self, path: &str) -> Option<String> {
+        fs::read_to_string(self.root.join(path)).ok()
+    }
+
+    fn file(&self, path: &str, request: &Request) -> Response {
+        let contents = match self.read(path) {
+            Some(contents) => contents,
+            None => return Response::error(404, &format!("{} is not published", path)),
+        };
+        let etag = etag(&contents);
+        let status = match &request.if_none_match {
+            Some(header) if none_match(header, &etag) => 304,
+            _ => 200,
+        };
+        Response {
+            status,
+            etag: Some(etag),
+            body: contents,
+        }
+    }
+
+    fn unit_menu(&self, unit: &str, request: &Request) -> Response {
+        let tiered = format!("units/{}/{}", unit, MENU_FILE);
+        if self.root.join(&tiered).is_file() {
+            self.file(&tiered, request)
+        } else {
+            self.file(MENU_FILE, request)
+        }
+    }
+
+    fn health(&self) -> Response {
+        let menu = match self.read(MENU_FILE) {
+            Some(menu) => menu,
+            None => {
+                let body = serde_json::json!({ "status": "no menu published" });
+                return Response::json(503, body.to_string());
+            }
+        };
+        let version = serde_json::from_str::<serde_json::Value>(&menu)
+            .ok()
+            .and_then(|menu| menu.pointer("/version/number").cloned());
+        let body = serde_json::json!({
+            "status": "ok",
+            "version": version,
+            "etag": etag(&menu),
+        });
+        Response::json(200, body.to_string())
+    }
+
+    pub fn respond(&self, request: &Request) -> Response {
+        if request.method != "GET" && request.method != "HEAD" {
+            return Response::error(405, &format!("{} is not supported", request.method));
+        }
+        let segments: Vec<&str> = request.path.trim_start_matches('/').split('/').collect();
+        match segments.as_slice() {
+            ["health"] => self.health(),
+            ["menu.json"] => self.file(MENU_FILE, request),
+            ["units", unit, _] if !valid_unit(unit) => {
+                Response::error(400, &format!("bad unit id {:?}", unit))
+            }
+            ["units", unit, "menu.json"] => self.unit_menu(unit, request),
+            ["units", unit, "availability.json"] => {
+                self.file(&format!("availability.{}.json", unit), request)
+            }
+            _ => Response::error(404, &format!("nothing at {}", request.path)),
+        }
+    }
+
+    fn handle(&self, stream: TcpStream) {
+        let _ = stream.set_read_timeout(Some(TIMEOUT));
+        let mut reader = BufReader::new(&stream);
+        let (response, head) = match Request::read(&mut reader) {
+            Ok(request) => (self.respond(&request), request.method == "HEAD"),
+            Err(e) => (Response::error(400, &e), false),
+        };
+        let mut stream = &stream;
+        if let Err(e) = response.write_to(&mut stream, head) {
+            eprintln!("warning: couldnt send response: {}", e);
+        }
+    }
+
+    /// Answers connections on `listener`, one thread each, until the process
+    /// stops.
+    pub fn serve(&self, listener: TcpListener) {
+        for stream in listener.incoming() {
+            match stream {
+                Ok(stream) => {
+                    let server = self.clone();
+                    thread::spawn(move || server.handle(stream));
+                }
+                Err(e) => eprintln!("warning: couldnt accept connection: {}", e),
+            }
+        }
+    }
+}
+
+/// `serve [--port <port>]`
+pub fn run(args: &[String]) {
+    let port = match args {
+        [] => DEFAULT_PORT,
+        [flag, port] if flag == "--port" => port.parse().expect("--port must be a number"),
+        _ => panic!("usage: serve [--port <port>]"),
+    };
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .unwrap_or_else(|e| panic!("Couldnt listen on port {}: {}", port, e));
+    println!("Serving published menus on http://127.0.0.1:{}", port);
+    MenuServer::new(Path::new(".")).serve(listener);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    /// A directory with a base menu, a tier menu for unit 12 and an
+    /// availability overlay for unit 94.
+    fn published(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("menu-server-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("units/12")).unwrap();
+        fs::write(
+            root.join("menu.json"),
+            r#"{"items":[],"version":{"number":3,"hash":"ab"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("units/12/menu.json"),
+            r#"{"items":[{"price":104}]}"#,
+        )
+        .unwrap();
+        fs::write(root.join("availability.94.json"), r#"{"unit":"94"}"#).unwrap();
+        root
+    }
+
+    /// Starts a server on a free localhost port.
+    fn start(root: &Path) -> u16 {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = MenuServer::new(root);
+        thread::spawn(move || server.serve(listener));
+        port
+    }
+
+    /// Sends a raw request and returns the status, headers and body.
+    fn get(port: u16, request: &str) -> (u16, String, String) {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, head.to_string(), body.to_string())
+    }
+
+    fn header<'a>(head: &'a str, name: &str) -> Option<&'a str> {
+        head.lines()
+            .filter_map(|line| line.split_once(": "))
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    #[test]
+    fn serves_unit_menus_with_etags() {
+        let root = published("etags");
+        let port = start(&root);
+
+        let (status, head, body) = get(port, "GET /units/12/menu.json HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 200);
+        assert_eq!(body, r#"{"items":[{"price":104}]}"#);
+        let etag = header(&head, "ETag").unwrap().to_string();
+        assert!(etag.starts_with('"') && etag.ends_with('"'));
+
+        let request = format!(
+            "GET /units/12/menu.json HTTP/1.1\r\nIf-None-Match: \"other\", {}\r\n\r\n",
+            etag
+        );
+        let (status, head, body) = get(port, &request);
+        assert_eq!(status, 304);
+        assert_eq!(header(&head, "ETag"), Some(etag.as_str()));
+        assert_eq!(body, "");
+
+        // A unit without a tier gets the base menu.
+        let (status, _, body) = get(port, "GET /units/7/menu.json HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 200);
+        assert_eq!(body, fs::read_to_string(root.join("menu.json")).unwrap());
+
+        // A rebuild changes the tag.
+        fs::write(
+            root.join("units/12/menu.json"),
+            r#"{"items":[{"price":109}]}"#,
+        )
+        .unwrap();
+        let (status, head, _) = get(port, &request);
+        assert_eq!(status, 200);
+        assert_ne!(header(&head, "ETag"), Some(etag.as_str()));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn serves_availability_and_health() {
+        let root = published("health");
+        let port = start(&root);
+
+        let (status, _, body) = get(port, "GET /units/94/availability.json HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 200);
+        assert_eq!(body, r#"{"unit":"94"}"#);
+        let (status, _, _) = get(port, "GET /units/12/availability.json HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 404);
+
+        let (status, _, body) = get(port, "GET /health HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 200);
+        let health: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(health["status"], "ok");
+        assert_eq!(health["version"], 3);
+
+        fs::remove_file(root.join("menu.json")).unwrap();
+        let (status, _, _) = get(port, "GET /health HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 503);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn refuses_bad_requests() {
+        let root = published("refuses");
+        let port = start(&root);
+
+        let (status, _, _) = get(port, "GET /units/../menu.json HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 400);
+        let (status, head, _) = get(port, "POST /menu.json HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 405);
+        assert_eq!(header(&head, "Allow"), Some("GET, HEAD"));
+        let (status, _, _) = get(port, "GET /units/12/prices.json HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 404);
+        let (status, head, body) = get(port, "HEAD /menu.json HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 200);
+        assert!(header(&head, "Content-Length").is_some());
+        assert_eq!(body, "");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,742 @@
+//! Order pricing simulator over a published `menu.json`.
+//!
+//! An order names items by PLU, optionally with a variation, modifiers and
+//! choices for each slot. Slots left out of the order get their defaults.
+//! Prices follow the menu: variation prices, slot price overrides (including
+//! per-variation overrides inherited from the parent, e.g. combo sizes),
+//! `free_quantity`, modifier upcharges, dynamic pricing in effect at the order
+//! time, and the requested discounts.
+
+use crate::dayparts::window_contains;
+use crate::dietary::ItemDietary;
+use crate::document::MenuDocument;
+use crate::nutrition::{Nutrition, NutritionTable};
+use crate::rounding::RoundingPolicy;
+use crate::tax::{allocate, TaxBreakdown, TaxCategory};
+use adjunct::{
+    DiscountAmount, DiscountDefinition, ItemDefinition, Menu, Modification, OrderConstraint,
+    OrderTimeConstraint, PricingModification, PricingModificationStyle, SlotDefinition,
+};
+use chrono::NaiveDateTime;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Order {
+    /// Local store time, e.g. `2026-10-19T08:30:00`.
+    pub time: String,
+    pub lines: Vec<OrderLine>,
+    /// Discount identifiers, e.g. `"1"` for 10% off. A discount that is not
+    /// `single` can be listed again to apply it again.
+    #[serde(default)]
+    pub discounts: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrderLine {
+    pub plu: String,
+    #[serde(default = "one")]
+    pub quantity: i64,
+    #[serde(default)]
+    pub variation: Option<String>,
+    #[serde(default)]
+    pub modifiers: Vec<Modification>,
+    #[serde(default)]
+    pub slots: Vec<SlotChoice>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SlotChoice {
+    pub slot: String,
+    pub items: Vec<OrderLine>,
+}
+
+fn one() -> i64 {
+    1
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SimulationError {
+    InvalidTime(String),
+    UnknownPlu(String),
+    InvalidQuantity {
+        plu: String,
+        quantity: i64,
+    },
+    UnknownVariation {
+        item: String,
+        variation: String,
+    },
+    UnknownSlot {
+        item: String,
+        slot: String,
+    },
+    NotInSlot {
+        slot: String,
+        item: String,
+    },
+    SlotQuantity {
+        slot: String,
+        quantity: i64,
+        minimum: i64,
+        maximum: i64,
+    },
+    OutOfDaypart {
+        item: String,
+        dayparts: Vec<String>,
+    },
+    UnknownDiscount(String),
+    DiscountNotApplicable(String),
+    Incombinable(String),
+    RepeatedDiscount(String),
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimulationError::InvalidTime(time) => write!(f, "invalid order time {}", time),
+            SimulationError::UnknownPlu(plu) => write!(f, "no item with PLU {}", plu),
+            SimulationError::InvalidQuantity { plu, quantity } => {
+                write!(
+                    f,
+                    "PLU {} ordered {} times, expected at least 1",
+                    plu, quantity
+                )
+            }
+            SimulationError::UnknownVariation { item, variation } => {
+                write!(f, "{} has no variation {}", item, variation)
+            }
+            SimulationError::UnknownSlot { item, slot } => {
+                write!(f, "{} has no slot {}", item, slot)
+            }
+            SimulationError::NotInSlot { slot, item } => {
+                write!(f, "{} cannot go in {}", item, slot)
+            }
+            SimulationError::SlotQuantity {
+                slot,
+                quantity,
+                minimum,
+                maximum,
+            } => write!(
+                f,
+                "{} holds {} items, expected {} to {}",
+                slot, quantity, minimum, maximum
+            ),
+            SimulationError::OutOfDaypart { item, dayparts } => {
+                write!(f, "{} is only sold during {}", item, dayparts.join(", "))
+            }
+            SimulationError::UnknownDiscount(id) => write!(f, "no discount with identifier {}", id),
+            SimulationError::DiscountNotApplicable(name) => {
+                write!(f, "{} does not apply to this order", name)
+            }
+            SimulationError::Incombinable(name) => {
+                write!(f, "{} cannot be combined with other discounts", name)
+            }
+            SimulationError::RepeatedDiscount(name) => {
+                write!(f, "{} can only be applied once", name)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PricedLine {
+    pub plu: String,
+    pub name: String,
+    pub quantity: i64,
+    /// The ordered variation, or the parent's when this item has one of the
+    /// same name (the drink in a Large combo).
+    pub variation: Option<String>,
+    /// Price of one unit including everything in its slots.
+    pub unit_price: i64,
+    pub total: i64,
+    pub components: Vec<PricedLine>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppliedDiscount {
+    pub identifier: String,
+    pub name: String,
+    pub amount: i64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Receipt {
+    pub lines: Vec<PricedLine>,
+    pub subtotal: i64,
+    pub discounts: Vec<AppliedDiscount>,
+    pub total: i64,
+}
+
+pub fn parse_time(time: &str) -> Result<NaiveDateTime, SimulationError> {
+    NaiveDateTime::parse_from_str(time, "%Y-%m-%dT%H:%M:%S")
+        .map_err(|_| SimulationError::InvalidTime(time.to_string()))
+}
+
+pub struct Simulator<'a> {
+    document: &'a MenuDocument,
+    rounding: RoundingPolicy,
+}
+
+impl<'a> Simulator<'a> {
+    pub fn new(document: &'a MenuDocument) -> Self {
+        Simulator {
+            document,
+            rounding: RoundingPolicy::None,
+        }
+    }
+
+    /// Rounds prices changed by dynamic pricing with `policy`.
+    pub fn rounding(mut self, policy: RoundingPolicy) -> Self {
+        self.rounding = policy;
+        self
+    }
+
+    fn menu(&self) -> &'a Menu {
+        &self.document.menu
+    }
+
+    fn item(&self, plu: &str) -> Result<&'a ItemDefinition, SimulationError> {
+        self.menu()
+            .items
+            .iter()
+            .find(|item| item.plu == plu)
+            .ok_or_else(|| SimulationError::UnknownPlu(plu.to_string()))
+    }
+
+    pub fn price(&self, order: &Order) -> Result<Receipt, SimulationError> {
+        let at = parse_time(&order.time)?;
+        let mut lines = Vec::new();
+        for line in &order.lines {
+            check_quantity(line)?;
+            let item = self.item(&line.plu)?;
+            self.check_daypart(item, at)?;
+            let base = self.dynamic_price(item, self.base_price(item, line)?, at);
+            lines.push(self.price_line(item, line, base, Some(at))?);
+        }
+
+        let subtotal = lines.iter().map(|line| line.total).sum();
+        let discounts = self.apply_discounts(order, &lines, subtotal, at)?;
+        let discounted: i64 = discounts.iter().map(|discount| discount.amount).sum();
+        Ok(Receipt {
+            lines,
+            subtotal,
+            discounts,
+            total: (subtotal - discounted).max(0),
+        })
+    }
+
+    /// Allergen and dietary flags of each order line, with the customer's
+    /// substitutions applied. Lines with unknown PLUs are skipped.
+    pub fn dietary(&self, order: &Order) -> Vec<(String, ItemDietary)> {
+        order
+            .lines
+            .iter()
+            .filter_map(|line| self.item(&line.plu).ok().map(|item| (item, line)))
+            .map(|(item, line)| {
+                let dietary = self.document.dietary.rollup(self.menu(), item, Some(line));
+                (item.long_name.clone(), dietary)
+            })
+            .collect()
+    }
+
+    /// What a customer pays for `item` with its slot defaults, before
+    /// dynamic pricing and discounts.
+    pub fn sell_price(
+        &self,
+        item: &ItemDefinition,
+        variation: Option<&str>,
+    ) -> Result<i64, SimulationError> {
+        let line = OrderLine {
+            plu: item.plu.clone(),
+            quantity: 1,
+            variation: variation.map(String::from),
+            modifiers: vec![],
+            slots: vec![],
+        };
+        let base = self.base_price(item, &line)?;
+        Ok(self.price_line(item, &line, base, None)?.unit_price)
+    }
+
+    /// Tax on a priced order at the unit's rates, in basis points by category.
+    pub fn tax(&self, receipt: &Receipt, rates: &BTreeMap<TaxCategory, i64>) -> TaxBreakdown {
+        let lines = receipt
+            .lines
+            .iter()
+            .map(|line| (line.name.clone(), self.tax_bases(line)))
+            .collect();
+        TaxBreakdown::compute(lines, receipt.subtotal - receipt.total, rates)
+    }
+
+    /// A line's total split by tax category.
+    fn tax_bases(&self, line: &PricedLine) -> BTreeMap<TaxCategory, i64> {
+        let unit = self.unit_tax_bases(line);
+        let weights: Vec<i64> = unit.values().copied().collect();
+        unit.keys()
+            .copied()
+            .zip(allocate(line.total, &weights))
+            .collect()
+    }
+
+    fn unit_tax_bases(&self, line: &PricedLine) -> BTreeMap<TaxCategory, i64> {
+        let taxes = &self.document.tax;
+        let mut bases = BTreeMap::new();
+        let item = match self.item(&line.plu) {
+            Ok(item) => item,
+            Err(_) => return bases,
+        };
+        let own = line.unit_price
+            - line
+                .components
+                .iter()
+                .map(|component| component.total)
+                .sum::<i64>();
+        if taxes.split.contains(&item.id) && !line.components.is_empty() {
+            let components: Vec<(&ItemDefinition, &PricedLine)> = line
+                .components
+                .iter()
+                .filter_map(|component| Some((self.item(&component.plu).ok()?, component)))
+                .collect();
+            let standalone: Vec<i64> = components
+                .iter()
+                .map(|(item, component)| {
+                    let price = self.sell_price(item, component.variation.as_deref());
+                    price.unwrap_or(0) * component.quantity
+                })
+                .collect();
+            for ((component, _), share) in components.iter().zip(allocate(own, &standalone)) {
+                *bases.entry(taxes.category(component.id)).or_insert(0) += share;
+            }
+        } else {
+            *bases.entry(taxes.category(item.id)).or_insert(0) += own;
+        }
+        for component in &line.components {
+            for (category, amount) in self.tax_bases(component) {
+                *bases.entry(category).or_insert(0) += amount;
+            }
+        }
+        bases
+    }
+
+    /// Calories and macros of each order line (all units of it) and of the
+    /// whole order.
+    pub fn nutrition(
+        &self,
+        order: &Order,
+        table: &NutritionTable,
+    ) -> Result<(Vec<(String, Nutrition)>, Nutrition), SimulationError> {
+        let mut lines = Vec::new();
+        let mut total = Nutrition::default();
+        for line in &order.lines {
+            let item = self.item(&line.plu)?;
+            let nutrition = table.for_line(self.menu(), item, line, None, line.quantity as f64);
+            total.add(nutrition.clone());
+            lines.push((item.long_name.clone(), nutrition));
+        }
+        Ok((lines, total))
+    }
+
+    /// Refuses items outside their own dayparts, and items whose every
+    /// category is outside its dayparts.
+    fn check_daypart(
+        &self,
+        item: &ItemDefinition,
+        at: NaiveDateTime,
+    ) -> Result<(), SimulationError> {
+        let schedule = &self.document.dayparts;
+        let categories: Vec<&str> = self
+            .menu()
+            .categories
+            .iter()
+            .filter(|category| category.tags.iter().any(|tag| item.tags.contains(tag)))
+            .map(|category| category.name.as_str())
+            .collect();
+        let category_open = categories.is_empty()
+            || categories
+                .iter()
+                .any(|name| schedule.category_available(name, at));
+        if schedule.item_available(item.id, at) && category_open {
+            return Ok(());
+        }
+        let mut dayparts = schedule.item_dayparts(item.id).to_vec();
+        for name in categories {
+            for daypart in schedule.categories.get(name).into_iter().flatten() {
+                if !dayparts.contains(daypart) {
+                    dayparts.push(daypart.clone());
+                }
+            }
+        }
+        Err(SimulationError::OutOfDaypart {
+            item: item.long_name.clone(),
+            dayparts,
+        })
+    }
+
+    /// The item's own price for the ordered variation, before slots.
+    fn base_price(&self, item: &ItemDefinition, line: &OrderLine) -> Result<i64, SimulationError> {
+        match &line.variation {
+            None => Ok(item.price as i64),
+            Some(name) => {
+                let variation = item
+                    .variations
+                    .iter()
+                    .find(|variation| &variation.name == name)
+                    .ok_or_else(|| SimulationError::UnknownVariation {
+                        item: item.long_name.clone(),
+                        variation: name.clone(),
+                    })?;
+                Ok(variation
+                    .price
+                    .map(|price| price as i64)
+                    .unwrap_or(item.price as i64))
+            }
+        }
+    }
+
+    fn dynamic_price(&self, item: &ItemDefinition, price: i64, at: NaiveDateTime) -> i64 {
+        let mut price = price;
+        let mut applied = false;
+        for rule_set in &self.menu().dynamic_pricing {
+            if !constraint_allows_time(&rule_set.auto_constraints, at) {
+                continue;
+            }
+            for rule in &rule_set.rules {
+                if crate::selection::matches(&rule.selection, item) {
+                    price = apply_modification(&rule.pricing_modification, price);
+                    applied = true;
+                }
+            }
+        }
+        if applied {
+            self.rounding.round(price.max(0))
+        } else {
+            price
+        }
+    }
+
+    fn upcharges(&self, item: &ItemDefinition, line: &OrderLine) -> i64 {
+        line.modifiers
+            .iter()
+            .filter_map(|modifier| item.modifier_upcharge.get(modifier))
+            .map(|price| *price as i64)
+            .sum()
+    }
+
+    /// Prices one line whose own unit price (before slots) is `base`. With
+    /// the order time `at`, items chosen in its slots must be on sale then.
+    fn price_line(
+        &self,
+        item: &ItemDefinition,
+        line: &OrderLine,
+        base: i64,
+        at: Option<NaiveDateTime>,
+    ) -> Result<PricedLine, SimulationError> {
+        for choice in &line.slots {
+            if !item.slots.iter().any(|slot| slot.name == choice.slot) {
+                return Err(SimulationError::UnknownSlot {
+                    item: item.long_name.clone(),
+                    slot: choice.slot.clone(),
+                });
+            }
+        }
+
+        let mut unit_price = base + self.upcharges(item, line);
+        let mut components = Vec::new();
+        for slot in &item.slots {
+            let chosen = line.slots.iter().find(|choice| choice.slot == slot.name);
+            for component in self.price_slot(item, slot, chosen, line.variation.as_deref(), at)? {
+                unit_price += component.total;
+                components.push(component);
+            }
+        }
+
+        Ok(PricedLine {
+            plu: item.plu.clone(),
+            name: item.long_name.clone(),
+            quantity: line.quantity,
+            variation: line.variation.clone(),
+            unit_price,
+            total: unit_price * line.quantity,
+            components,
+        })
+    }
+
+    fn price_slot(
+        &self,
+        parent: &ItemDefinition,
+        slot: &SlotDefinition,
+        chosen: Option<&SlotChoice>,
+        parent_variation: Option<&str>,
+        at: Option<NaiveDateTime>,
+    ) -> Result<Vec<PricedLine>, SimulationError> {
+        let path = format!("{} / {}", parent.long_name, slot.name);
+        let lines: Vec<OrderLine> = match chosen {
+            Some(choice) => choice.items.clone(),
+            None => default_lines(self.menu(), slot),
+        };
+
+        for line in &lines {
+            check_quantity(line)?;
+        }
+        let quantity: i64 = lines.iter().map(|line| line.quantity).sum();
+        let minimum = slot.minimum_quantity as i64;
+        let maximum = slot.maximum_quantity as i64;
+        if chosen.is_some() && (quantity < minimum || (maximum > 0 && quantity > maximum)) {
+            return Err(SimulationError::SlotQuantity {
+                slot: path,
+                quantity,
+                minimum,
+                maximum,
+            });
+        }
+
+        let mut free = slot.free_quantity as i64;
+        let mut components = Vec::new();
+        for line in &lines {
+            let item = self.item(&line.plu)?;
+            if !crate::selection::matches(&slot.selection, item) {
+                return Err(SimulationError::NotInSlot {
+                    slot: path,
+                    item: item.long_name.clone(),
+                });
+            }
+            if let Some(at) = at {
+                self.check_daypart(item, at)?;
+            }
+            let variation = line.variation.as_deref().or(parent_variation);
+            let base = match slot_override(slot, item, variation) {
+                Some(price) => price,
+                None if slot.default_item_ids.contains(&item.id) => 0,
+                None => self.base_price(item, line).unwrap_or(item.price as i64),
+            };
+            let mut priced = self.price_line(item, line, base, at)?;
+            if priced.variation.is_none() {
+                priced.variation = parent_variation
+                    .filter(|name| {
+                        item.variations
+                            .iter()
+                            .any(|variation| variation.name == *name)
+                    })
+                    .map(String::from);
+            }
+            let free_units = free.min(priced.quantity);
+            free -= free_units;
+            priced.total = priced.unit_price * (priced.quantity - free_units);
+            components.push(priced);
+        }
+        Ok(components)
+    }
+
+    fn apply_discounts(
+        &self,
+        order: &Order,
+        lines: &[PricedLine],
+        subtotal: i64,
+        at: NaiveDateTime,
+    ) -> Result<Vec<AppliedDiscount>, SimulationError> {
+        let mut applied = Vec::new();
+        let mut remaining = subtotal;
+        for identifier in &order.discounts {
+            let discount = self
+                .menu()
+                .discounts
+                .iter()
+                .find(|discount| &discount.identifier == identifier)
+                .ok_or_else(|| SimulationError::UnknownDiscount(identifier.clone()))?;
+            if discount.incombinable && order.discounts.len() > 1 {
+                return Err(SimulationError::Incombinable(discount.name.clone()));
+            }
+            if discount.single
+                && applied
+                    .iter()
+                    .any(|previous: &AppliedDiscount| &previous.identifier == identifier)
+            {
+                return Err(SimulationError::RepeatedDiscount(discount.name.clone()));
+            }
+            if !self.discount_applies(discount, lines, subtotal, at) {
+                return Err(SimulationError::DiscountNotApplicable(
+                    discount.name.clone(),
+                ));
+            }
+            let mut amount = match discount.amount {
+                DiscountAmount::Flat(amount) => amount as i64,
+                DiscountAmount::PercentOrder(percent) => subtotal * percent as i64 / 100,
+                DiscountAmount::Set(price) => {
+                    (self.qualifying_total(discount, lines) - price as i64).max(0)
+                }
+            };
+            if let Some(max_amount) = discount.max_amount {
+                amount = amount.min(max_amount as i64);
+            }
+            amount = amount.min(remaining);
+            remaining -= amount;
+            applied.push(AppliedDiscount {
+                identifier: discount.identifier.clone(),
+                name: discount.name.clone(),
+                amount,
+            });
+        }
+        Ok(applied)
+    }
+
+    fn qualifying_total(&self, discount: &DiscountDefinition, lines: &[PricedLine]) -> i64 {
+        lines
+            .iter()
+            .filter(|line| {
+                let item = self.item(&line.plu).ok();
+                discount
+                    .constraints
+                    .iter()
+                    .any(|constraint| match (constraint, item) {
+                        (OrderConstraint::ItemQuantity(constraint), Some(item)) => {
+                            crate::selection::matches(&constraint.selection, item)
+                        }
+                        _ => false,
+                    })
+            })
+            .map(|line| line.total)
+            .sum()
+    }
+
+    fn discount_applies(
+        &self,
+        discount: &DiscountDefinition,
+        lines: &[PricedLine],
+        subtotal: i64,
+        at: NaiveDateTime,
+    ) -> bool {
+        discount
+            .constraints
+            .iter()
+            .all(|constraint| match constraint {
+                OrderConstraint::OrderTotal(total) => {
+                    subtotal >= total.minimum_amount as i64
+                        && subtotal <= total.maximum_amount as i64
+                }
+                OrderConstraint::ItemQuantity(quantity) => {
+                    let count: i64 = lines
+                        .iter()
+                        .filter(|line| {
+                            self.item(&line.plu)
+                                .map(|item| crate::selection::matches(&quantity.selection, item))
+                                .unwrap_or(false)
+                        })
+                        .map(|line| line.quantity)
+                        .sum();
+                    count >= quantity.minimum_quantity as i64
+                        && count <= quantity.maximum_quantity as i64
+                }
+                OrderConstraint::Time(time) => time_allows(time, at),
+            })
+    }
+}
+
+fn check_quantity(line: &OrderLine) -> Result<(), SimulationError> {
+    if line.quantity > 0 {
+        return Ok(());
+    }
+    Err(SimulationError::InvalidQuantity {
+        plu: line.plu.clone(),
+        quantity: line.quantity,
+    })
+}
+
+/// `price` after a dynamic pricing modification, before rounding.
+pub fn apply_modification(modification: &PricingModification, price: i64) -> i64 {
+    let amount = modification.amount as i64;
+    let price = match modification.style {
+        PricingModificationStyle::Set => amount,
+        _ => price + amount,
+    };
+    price.max(0)
+}
+
+/// A slot's default contents as order lines, `default_quantity` of each.
+pub fn default_lines(menu: &Menu, slot: &SlotDefinition) -> Vec<OrderLine> {
+    let quantity = (slot.default_quantity as i64).max(1);
+    slot.default_item_ids
+        .iter()
+        .filter_map(|id| menu.items.iter().find(|item| item.id == *id))
+        .map(|item| OrderLine {
+            plu: item.plu.clone(),
+            quantity,
+            variation: None,
+            modifiers: vec![],
+            slots: vec![],
+        })
+        .collect()
+}
+
+/// The price a slot sets for `item`, preferring an override for the variation.
+fn slot_override(
+    slot: &SlotDefinition,
+    item: &ItemDefinition,
+    variation: Option<&str>,
+) -> Option<i64> {
+    let applies = |price_override: &&adjunct::PriceOverride| {
+        price_override.item_ids.contains(&item.id)
+            || price_override
+                .tags
+                .iter()
+                .any(|tag| item.tags.contains(tag))
+    };
+    let for_variation = slot
+        .price_overrides
+        .iter()
+        .filter(applies)
+        .find(|price_override| {
+            variation.is_some() && price_override.variation.as_deref() == variation
+        });
+    let general = slot
+        .price_overrides
+        .iter()
+        .filter(applies)
+        .find(|price_override| price_override.variation.is_none());
+    for_variation
+        .or(general)
+        .map(|price_override| price_override.price as i64)
+}
+
+fn time_allows(time: &OrderTimeConstraint, at: NaiveDateTime) -> bool {
+    window_contains(&time.day_of_week, time.start_time, time.stop_time, at)
+}
+
+fn constraint_allows_time(constraint: &OrderConstraint, at: NaiveDateTime) -> bool {
+    match constraint {
+        OrderConstraint::Time(time) => time_allows(time, at),
+        _ => true,
+    }
+}
+
+impl fmt::Display for Receipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn write_line(f: &mut fmt::Formatter, line: &PricedLine, depth: usize) -> fmt::Result {
+            writeln!(
+                f,
+                "{}{} x {:<30} {:>8}",
+                "  ".repeat(depth),
+                line.quantity,
+                line.name,
+                cents(line.total)
+            )?;
+            for component in &line.components {
+                write_line(f, component, depth + 1)?;
+            }
+            Ok(())
+        }
+        for line in &self.lines {
+            write_line(f, line, 0)?;
+        }
+        writeln!(f, "{:<34} {:>8}", "Subtotal", cents(self.subtotal))?;
+        for discount in &self.discounts {
+            writeln!(f, "{:<34} {:>8}", discount.name, cents(-discount.amount))?;
+        }
+        writeln!(f, "{:<34} {:>8}", "Total", cents(self.total))
+    }
+}
+
+pub fn cents(amount: i64) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    format!("{}{}.{:02}", sign, amount.abs() / 100, amount.abs() % 100)
+}
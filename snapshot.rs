@@ -0,0 +1,197 @@
+//! Golden snapshot of the built menu.
+//!
+//! `menu_matches_golden` builds the menu and compares its `menu.json` field
+//! by field with `snapshots/menu.json`, so a macro or data change that alters
+//! the published menu shows up as a failing test. When a change is intended,
+//! run `BLESS=1 cargo test snapshot` to write the new golden file, and commit
+//! it with the change.
+
+use crate::menu;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const GOLDEN_FILE: &str = "snapshots/menu.json";
+/// Differences to list before summarizing the rest.
+const MAX_SHOWN: usize = 50;
+
+#[derive(Debug, PartialEq)]
+enum Difference {
+    Added(String, Value),
+    Removed(String, Value),
+    Changed(String, Value, Value),
+}
+
+impl std::fmt::Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Difference::Added(path, value) => write!(f, "+ {}: {}", path, value),
+            Difference::Removed(path, value) => write!(f, "- {}: {}", path, value),
+            Difference::Changed(path, old, new) => write!(f, "~ {}: {} -> {}", path, old, new),
+        }
+    }
+}
+
+/// How an array element is named in a path: by the first of its
+/// `long_name`, `name` or `plu` that is unique in the array, e.g.
+/// `items[Potato Oles]`, otherwise by index.
+fn element_keys(values: &[Value]) -> Vec<String> {
+    let field = |value: &Value, key: &str| value.get(key).and_then(Value::as_str).map(String::from);
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            ["long_name", "name", "plu"]
+                .iter()
+                .filter_map(|key| field(value, key).map(|name| (key, name)))
+                .find(|(key, name)| {
+                    values
+                        .iter()
+                        .filter(|other| field(other, key).as_ref() == Some(name))
+                        .count()
+                        == 1
+                })
+                .map_or(i.to_string(), |(_, name)| name)
+        })
+        .collect()
+}
+
+fn compare(path: &str, old: &Value, new: &Value, differences: &mut Vec<Difference>) {
+    let join = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                match new.get(key) {
+                    Some(new_value) => compare(&join(key), old_value, new_value, differences),
+                    None => differences.push(Difference::Removed(join(key), old_value.clone())),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    differences.push(Difference::Added(join(key), new_value.clone()));
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            let old: BTreeMap<String, &Value> = element_keys(old).into_iter().zip(old).collect();
+            let new_keys = element_keys(new);
+            for (key, new_value) in new_keys.iter().zip(new) {
+                let element = format!("{}[{}]", path, key);
+                match old.get(key) {
+                    Some(old_value) => compare(&element, old_value, new_value, differences),
+                    None => differences.push(Difference::Added(element, new_value.clone())),
+                }
+            }
+            for (key, old_value) in old {
+                if !new_keys.contains(&key) {
+                    differences.push(Difference::Removed(
+                        format!("{}[{}]", path, key),
+                        old_value.clone(),
+                    ));
+                }
+            }
+        }
+        _ if old != new => differences.push(Difference::Changed(
+            path.to_string(),
+            old.clone(),
+            new.clone(),
+        )),
+        _ => {}
+    }
+}
+
+fn differences(old: &Value, new: &Value) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    compare("", old, new, &mut differences);
+    differences
+}
+
+fn golden_path() -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(GOLDEN_FILE)
+}
+
+#[test]
+fn menu_matches_golden() {
+    let built = menu::build().to_json();
+    let path = golden_path();
+    if std::env::var_os("BLESS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).expect("Couldnt create snapshots dir");
+        fs::write(&path, &built).expect("Couldnt write golden menu");
+        return;
+    }
+
+    let golden = fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "Couldnt read {}: {}\nrun `BLESS=1 cargo test snapshot` to create it",
+            path.display(),
+            e
+        )
+    });
+    let golden: Value = serde_json::from_str(&golden).expect("Couldnt parse golden menu");
+    let built: Value = serde_json::from_str(&built).expect("Couldnt parse built menu");
+    let differences = differences(&golden, &built);
+    if differences.is_empty() {
+        return;
+    }
+
+    let mut report = format!(
+        "menu.json differs from {} in {} places:\n",
+        GOLDEN_FILE,
+        differences.len()
+    );
+    for difference in differences.iter().take(MAX_SHOWN) {
+        report.push_str(&format!("  {}\n", difference));
+    }
+    if differences.len() > MAX_SHOWN {
+        report.push_str(&format!(
+            "  ... and {} more\n",
+            differences.len() - MAX_SHOWN
+        ));
+    }
+    report.push_str("if this is intended, run `BLESS=1 cargo test snapshot` and commit the result");
+    panic!("{}", report);
+}
+
+#[test]
+fn menu_build_is_deterministic() {
+    assert_eq!(menu::build().to_json(), menu::build().to_json());
+}
+
+#[test]
+fn differences_are_named_by_path() {
+    let old = serde_json::json!({
+        "items": [{"long_name": "Taco", "price": 129}, {"long_name": "Burrito", "price": 399}],
+        "discounts": [],
+    });
+    let new = serde_json::json!({
+        "items": [{"long_name": "Taco", "price": 139}, {"long_name": "Nachos", "price": 299}],
+        "discounts": [],
+        "version": 2,
+    });
+    assert_eq!(
+        differences(&old, &new),
+        vec![
+            Difference::Changed(
+                "items[Taco].price".to_string(),
+                serde_json::json!(129),
+                serde_json::json!(139)
+            ),
+            Difference::Added(
+                "items[Nachos]".to_string(),
+                serde_json::json!({"long_name": "Nachos", "price": 299})
+            ),
+            Difference::Removed(
+                "items[Burrito]".to_string(),
+                serde_json::json!({"long_name": "Burrito", "price": 399})
+            ),
+            Difference::Added("version".to_string(), serde_json::json!(2)),
+        ]
+    );
+}
@@ -0,0 +1,167 @@
+//! Registry of every tag on the menu: who carries it and who selects on it.
+
+use crate::selection;
+use adjunct::{Menu, OrderConstraint};
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Default)]
+pub struct TagUsage {
+    pub items: Vec<String>,
+    pub slots: Vec<String>,
+    pub categories: Vec<String>,
+    pub price_overrides: Vec<String>,
+    pub promotions: Vec<String>,
+}
+
+impl TagUsage {
+    fn is_assigned(&self) -> bool {
+        !self.items.is_empty()
+    }
+
+    fn is_selected(&self) -> bool {
+        !(self.slots.is_empty()
+            && self.categories.is_empty()
+            && self.price_overrides.is_empty()
+            && self.promotions.is_empty())
+    }
+}
+
+pub struct TagRegistry {
+    pub tags: BTreeMap<String, TagUsage>,
+}
+
+impl TagRegistry {
+    pub fn from_menu(menu: &Menu) -> Self {
+        let mut tags: BTreeMap<String, TagUsage> = BTreeMap::new();
+
+        for item in &menu.items {
+            for tag in &item.tags {
+                tags.entry(tag.clone())
+                    .or_default()
+                    .items
+                    .push(item.long_name.clone());
+            }
+        }
+
+        for (path, slot) in selection::all_slots(menu) {
+            for tag in selection::tags(&slot.selection)
+                .into_iter()
+                .chain(selection::tags(&slot.hidden))
+            {
+                tags.entry(tag.to_owned())
+                    .or_default()
+                    .slots
+                    .push(path.clone());
+            }
+            for price_override in &slot.price_overrides {
+                for tag in &price_override.tags {
+                    tags.entry(tag.clone())
+                        .or_default()
+                        .price_overrides
+                        .push(path.clone());
+                }
+            }
+        }
+
+        for category in &menu.categories {
+            for tag in &category.tags {
+                tags.entry(tag.clone())
+                    .or_default()
+                    .categories
+                    .push(category.name.clone());
+            }
+        }
+
+        for discount in &menu.discounts {
+            for constraint in &discount.constraints {
+                if let OrderConstraint::ItemQuantity(constraint) = constraint {
+                    for tag in selection::tags(&constraint.selection) {
+                        tags.entry(tag.to_owned())
+                            .or_default()
+                            .promotions
+                            .push(discount.name.clone());
+                    }
+                }
+            }
+        }
+
+        for rule_set in &menu.dynamic_pricing {
+            for rule in &rule_set.rules {
+                for tag in selection::tags(&rule.selection) {
+                    tags.entry(tag.to_owned())
+                        .or_default()
+                        .promotions
+                        .push(rule_set.name.clone());
+                }
+            }
+        }
+
+        for usage in tags.values_mut() {
+            usage.slots.dedup();
+            usage.price_overrides.dedup();
+            usage.promotions.dedup();
+        }
+
+        TagRegistry { tags }
+    }
+
+    /// Tags that something selects on but no item carries, so the selection is always empty.
+    pub fn selected_but_unassigned(&self) -> Vec<&str> {
+        self.tags
+            .iter()
+            .filter(|(_, usage)| usage.is_selected() && !usage.is_assigned())
+            .map(|(tag, _)| tag.as_str())
+            .collect()
+    }
+
+    /// Tags carried by items that nothing selects on.
+    pub fn assigned_but_unselected(&self) -> Vec<&str> {
+        self.tags
+            .iter()
+            .filter(|(_, usage)| usage.is_assigned() && !usage.is_selected())
+            .map(|(tag, _)| tag.as_str())
+            .collect()
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for tag in self.selected_but_unassigned() {
+            warnings.push(format!(
+                "tag \"{}\" is selected but no item carries it",
+                tag
+            ));
+        }
+        for tag in self.assigned_but_unselected() {
+            warnings.push(format!(
+                "tag \"{}\" is assigned but nothing selects on it",
+                tag
+            ));
+        }
+        warnings
+    }
+}
+
+impl fmt::Display for TagRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (tag, usage) in &self.tags {
+            writeln!(f, "{}", tag)?;
+            let sections = [
+                ("items", &usage.items),
+                ("slots", &usage.slots),
+                ("categories", &usage.categories),
+                ("price overrides", &usage.price_overrides),
+                ("promotions", &usage.promotions),
+            ];
+            for (label, names) in sections.iter() {
+                if !names.is_empty() {
+                    writeln!(f, "  {} ({}): {}", label, names.len(), names.join(", "))?;
+                }
+            }
+        }
+        for warning in self.warnings() {
+            writeln!(f, "warning: {}", warning)?;
+        }
+        Ok(())
+    }
+}
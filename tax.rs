@@ -0,0 +1,229 @@
+//! Tax categories and order tax.
+//!
+//! Every item is taxed as food unless the menu assigns it another category.
+//! Combos marked for splitting have their own price spread over their
+//! components in proportion to each component's standalone price, so the
+//! drink in a combo is taxed as a beverage. Order discounts, including
+//! `DiscountAmount::PercentOrder`, are spread over every line in proportion
+//! to its total, taxable or not, before tax is worked out.
+
+use crate::simulator::cents;
+use adjunct::ID;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxCategory {
+    Food,
+    Beverage,
+    Exempt,
+}
+
+/// Tax categories as published in `menu.json`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct TaxAssignments {
+    /// Items that are not food.
+    pub items: BTreeMap<ID, TaxCategory>,
+    /// Combos whose own price is split over their components.
+    pub split: BTreeSet<ID>,
+}
+
+impl TaxAssignments {
+    pub fn category(&self, id: ID) -> TaxCategory {
+        self.items.get(&id).copied().unwrap_or(TaxCategory::Food)
+    }
+}
+
+/// Splits `total` in proportion to `weights`, giving leftover cents to the
+/// largest remainders. Weights that sum to zero, e.g. a combo credit
+/// offsetting its components, split evenly.
+pub fn allocate(total: i64, weights: &[i64]) -> Vec<i64> {
+    if weights.is_empty() {
+        return vec![];
+    }
+    let weights: Vec<i64> = if weights.iter().sum::<i64>() == 0 {
+        vec![1; weights.len()]
+    } else {
+        weights.to_vec()
+    };
+    let sum: i64 = weights.iter().sum();
+    let mut shares: Vec<i64> = weights.iter().map(|weight| total * weight / sum).collect();
+    let mut order: Vec<usize> = (0..weights.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse((total * weights[i]) % sum));
+    let leftover = total - shares.iter().sum::<i64>();
+    for &i in order.iter().cycle().take(leftover.unsigned_abs() as usize) {
+        shares[i] += leftover.signum();
+    }
+    shares
+}
+
+/// `amount` at `rate` basis points, rounded half up to the cent.
+pub fn tax_on(amount: i64, rate: i64) -> i64 {
+    (amount * rate + 5000).div_euclid(10000)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineTax {
+    pub name: String,
+    /// Amount taxed in each category, after the line's share of discounts.
+    pub taxable: BTreeMap<TaxCategory, i64>,
+    pub discount: i64,
+    pub tax: i64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaxBreakdown {
+    pub lines: Vec<LineTax>,
+    pub tax: i64,
+    /// Categories on the order that the unit has no rate for.
+    pub missing_rates: Vec<TaxCategory>,
+}
+
+impl TaxBreakdown {
+    /// Tax for lines whose totals are already split by category, after
+    /// `discount` is spread over them.
+    pub fn compute(
+        lines: Vec<(String, BTreeMap<TaxCategory, i64>)>,
+        discount: i64,
+        rates: &BTreeMap<TaxCategory, i64>,
+    ) -> Self {
+        let totals: Vec<i64> = lines
+            .iter()
+            .map(|(_, bases)| bases.values().sum())
+            .collect();
+        let discounts = allocate(discount, &totals);
+
+        let mut missing_rates = Vec::new();
+        let mut taxed = Vec::new();
+        for ((name, bases), discount) in lines.into_iter().zip(discounts) {
+            let categories: Vec<TaxCategory> = bases.keys().copied().collect();
+            let amounts: Vec<i64> = bases.values().copied().collect();
+            let shares = allocate(discount, &amounts);
+            let mut taxable = BTreeMap::new();
+            let mut tax = 0;
+            for ((category, amount), share) in categories.into_iter().zip(amounts).zip(shares) {
+                let amount = amount - share;
+                let rate = match (category, rates.get(&category)) {
+                    (TaxCategory::Exempt, _) => 0,
+                    (_, Some(rate)) => *rate,
+                    (_, None) => {
+                        if amount > 0 && !missing_rates.contains(&category) {
+                            missing_rates.push(category);
+                        }
+                        0
+                    }
+                };
+                tax += tax_on(amount, rate);
+                taxable.insert(category, amount);
+            }
+            taxed.push(LineTax {
+                name,
+                taxable,
+                discount,
+                tax,
+            });
+        }
+
+        TaxBreakdown {
+            tax: taxed.iter().map(|line| line.tax).sum(),
+            lines: taxed,
+            missing_rates,
+        }
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.missing_rates
+            .iter()
+            .map(|category| format!("no tax rate for {:?}, taxed at 0", category))
+            .collect()
+    }
+}
+
+impl fmt::Display for TaxBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in &self.lines {
+            let taxable: Vec<String> = line
+                .taxable
+                .iter()
+                .map(|(category, amount)| format!("{:?} {}", category, cents(*amount)))
+                .collect();
+            writeln!(
+                f,
+                "{:<34} {:>8}  ({}; discount {})",
+                format!("Tax on {}", line.name),
+                cents(line.tax),
+                taxable.join(", "),
+                cents(line.discount)
+            )?;
+        }
+        writeln!(f, "{:<34} {:>8}", "Tax", cents(self.tax))?;
+        for warning in self.warnings() {
+            writeln!(f, "warning: {}", warning)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocate_adds_up_to_the_total() {
+        let cases: &[(i64, &[i64])] = &[
+            (100, &[1, 1, 1]),
+            (1000, &[335, 199, 149]),
+            (-7, &[1, 2]),
+            (201, &[600, -100, 3]),
+            (5, &[0, 0]),
+        ];
+        for (total, weights) in cases {
+            let shares = allocate(*total, weights);
+            assert_eq!(shares.len(), weights.len());
+            assert_eq!(shares.iter().sum::<i64>(), *total, "{:?}", weights);
+        }
+        assert_eq!(allocate(100, &[1, 1, 1]), [34, 33, 33]);
+        assert_eq!(allocate(100, &[]), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn allocate_splits_evenly_when_weights_cancel_out() {
+        // A combo credit offsetting its components used to divide by zero.
+        assert_eq!(allocate(90, &[300, 150, -450]), [30, 30, 30]);
+        assert_eq!(allocate(91, &[300, -300]), [46, 45]);
+    }
+
+    #[test]
+    fn compute_spreads_the_discount_before_tax() {
+        let lines = vec![
+            (
+                "Combo".to_string(),
+                vec![(TaxCategory::Food, 500), (TaxCategory::Beverage, 200)]
+                    .into_iter()
+                    .collect(),
+            ),
+            (
+                "Water".to_string(),
+                vec![(TaxCategory::Exempt, 100)].into_iter().collect(),
+            ),
+        ];
+        let rates = vec![(TaxCategory::Food, 800)].into_iter().collect();
+        let breakdown = TaxBreakdown::compute(lines, 80, &rates);
+
+        assert_eq!(
+            breakdown
+                .lines
+                .iter()
+                .map(|line| line.discount)
+                .collect::<Vec<_>>(),
+            [70, 10]
+        );
+        assert_eq!(breakdown.lines[0].taxable[&TaxCategory::Food], 450);
+        assert_eq!(breakdown.lines[0].taxable[&TaxCategory::Beverage], 180);
+        // 8% of 4.50; beverages have no rate here and are taxed at 0.
+        assert_eq!(breakdown.tax, 36);
+        assert_eq!(breakdown.missing_rates, [TaxCategory::Beverage]);
+    }
+}
@@ -0,0 +1,161 @@
+//! Regional price tiers.
+//!
+//! `price_tiers.json` defines tiers as a percentage on top of base prices,
+//! assigns units (stores) to them and lists per-item exceptions, e.g.
+//!
+//! ```json
+//! {
+//!   "tiers": {
+//!     "1": {},
+//!     "2": {"percent": 4, "exceptions": {"1000": "base", "12400": {"price": 1049}}}
+//!   },
+//!   "units": {"94": "2"}
+//! }
+//! ```
+//!
+//! The build writes `units/<unit>/menu.json` for every assigned unit with
+//! item, variation, upcharge and slot override prices scaled by the tier and
+//! rounded by the tier's rounding policy (the menu's policy if it has none).
+
+use crate::adjust::Change;
+use crate::document::MenuDocument;
+use crate::rounding::RoundingPolicy;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+
+pub const TIERS_FILE: &str = "price_tiers.json";
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TierException {
+    /// Keep the base menu's prices.
+    Base,
+    /// Use this percentage instead of the tier's.
+    Percent(f64),
+    /// Fix the item's own price; its variations and overrides follow the tier.
+    Price(i64),
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct PriceTier {
+    /// Percentage on top of base prices, e.g. 4 for +4%.
+    #[serde(default)]
+    pub percent: f64,
+    #[serde(default)]
+    pub rounding: Option<RoundingPolicy>,
+    /// Exceptions by PLU.
+    #[serde(default)]
+    pub exceptions: BTreeMap<String, TierException>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct PriceTiers {
+    #[serde(default)]
+    pub tiers: BTreeMap<String, PriceTier>,
+    /// Tier name by unit id.
+    #[serde(default)]
+    pub units: BTreeMap<String, String>,
+}
+
+impl PriceTiers {
+    /// The tiers in `price_tiers.json`, or none when there is no such file.
+    pub fn load() -> Self {
+        match fs::read_to_string(TIERS_FILE) {
+            Ok(json) => serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("Couldnt parse {}: {}", TIERS_FILE, e)),
+            Err(_) => PriceTiers::default(),
+        }
+    }
+
+    pub fn tier(&self, unit: &str) -> Option<&PriceTier> {
+        self.units.get(unit).and_then(|name| self.tiers.get(name))
+    }
+
+    pub fn warnings(&self, document: &MenuDocument) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for (unit, name) in &self.units {
+            if !self.tiers.contains_key(name) {
+                warnings.push(format!("unit {} is in unknown price tier {}", unit, name));
+            }
+        }
+        for (name, tier) in &self.tiers {
+            for plu in tier.exceptions.keys() {
+                if !document.menu.items.iter().any(|item| &item.plu == plu) {
+                    warnings.push(format!(
+                        "price tier {} has an exception for unknown PLU {}",
+                        name, plu
+                    ));
+                }
+            }
+        }
+        warnings
+    }
+}
+
+impl PriceTier {
+    /// `document` with this tier's prices.
+    pub fn apply(&self, document: &MenuDocument, default_rounding: RoundingPolicy) -> MenuDocument {
+        let policy = self.rounding.unwrap_or(default_rounding);
+        let mut priced = document.clone();
+        let tier_change = Change::Percent(self.percent);
+        for slot in &mut priced.menu.slots {
+            for price_override in &mut slot.price_overrides {
+                price_override.price = tier_change.apply(price_override.price as i64, &policy) as _;
+            }
+        }
+        for item in &mut priced.menu.items {
+            let (change, fixed) = match self.exceptions.get(&item.plu) {
+                Some(TierException::Base) => continue,
+                Some(TierException::Percent(percent)) => (Change::Percent(*percent), None),
+                Some(TierException::Price(price)) => (tier_change, Some(*price)),
+                None => (tier_change, None),
+            };
+            let price = fixed.unwrap_or_else(|| change.apply(item.price as i64, &policy));
+            item.price = price as _;
+            for variation in &mut item.variations {
+                if let Some(price) = variation.price {
+                    variation.price = Some(change.apply(price as i64, &policy) as _);
+                }
+            }
+            for price in item.modifier_upcharge.values_mut() {
+                *price = change.apply(*price as i64, &policy) as _;
+            }
+            for slot in &mut item.slots {
+                for price_override in &mut slot.price_overrides {
+                    price_override.price = change.apply(price_override.price as i64, &policy) as _;
+                }
+            }
+        }
+        priced
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu;
+    use crate::rounding::Direction;
+
+    #[test]
+    fn tier_at_base_keeps_base_prices() {
+        let document = menu::build();
+        let policy = RoundingPolicy::EndIn {
+            digit: 9,
+            direction: Direction::Nearest,
+        };
+        let priced = PriceTier::default().apply(&document, policy);
+        assert_eq!(priced.to_json(), document.to_json());
+    }
+
+    #[test]
+    fn combo_credits_keep_their_sign() {
+        let policy = RoundingPolicy::EndIn {
+            digit: 9,
+            direction: Direction::Nearest,
+        };
+        assert_eq!(Change::Percent(10.0).apply(-36, &policy), -39);
+        assert_eq!(Change::Percent(10.0).apply(720, &policy), 789);
+        assert_eq!(Change::Percent(0.0).apply(720, &policy), 720);
+    }
+}
@@ -0,0 +1,663 @@
+//! Watch mode: rebuild and republish whenever a build input changes.
+//!
+//! `watch [--overlay <file>]... [--interval <seconds>]` polls the files a
+//! build reads (price tiers, rounding, display name rules, translations and
+//! images), the menu sources, the price change inputs and the given unit
+//! overlays.
+//!
+//! - A changed input rebuilds only the outputs that read it, e.g. a new
+//!   translation rebuilds the localized menus but not the unit menus. The
+//!   result is validated, diagnostics and what changed in each menu are
+//!   printed, and it is published. Publishing a new version stamps it into
+//!   every file, so then all of them are written again.
+//! - A changed menu source runs `cargo build` and restarts the watcher on the
+//!   new binary, since the menu is compiled in.
+//! - A changed `price_requests.json` is proposed as `changes.json`, and a
+//!   changed `changes.json` is shown along with whether `apply` would take it.
+//! - A changed overlay rewrites that unit's availability.
+//!
+//! A build that fails, e.g. because a price file does not parse or the menu
+//! sources do not compile, is not published, so the last good output stays
+//! in place until the input is fixed.
+
+use crate::assets::{self, AssetReport};
+use crate::availability::Availability;
+use crate::changelog::Changelog;
+use crate::display_names;
+use crate::document::MenuDocument;
+use crate::localization;
+use crate::overlay::UnitOverlay;
+use crate::price_changes::{ChangeSet, CHANGES_FILE, MENU_SOURCE_DIR, PRICE_REQUESTS_FILE};
+use crate::publish::{PublishIndex, PUBLISH_DIR};
+use crate::rounding;
+use crate::tiers;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+const USAGE: &str = "usage: watch [--overlay <file>]... [--interval <seconds>]";
+
+type Files = Vec<(String, String)>;
+
+/// The parts of a build that read files, each rebuilt only when one of those
+/// files changes. `menu.json` and the tag and category checks only depend on
+/// the compiled-in menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Output {
+    /// `assets.json` and the image warnings.
+    Assets,
+    /// The display name and rounding warnings.
+    Rules,
+    /// `menu.<locale>.json`.
+    Locales,
+    /// `units/<unit>/menu.json`.
+    Units,
+}
+
+const OUTPUTS: [Output; 4] = [
+    Output::Assets,
+    Output::Rules,
+    Output::Locales,
+    Output::Units,
+];
+
+impl Output {
+    fn inputs(self) -> Vec<PathBuf> {
+        match self {
+            Output::Assets => vec![assets::asset_dir()],
+            Output::Rules => vec![
+                PathBuf::from(display_names::RULES_FILE),
+                PathBuf::from(rounding::ROUNDING_FILE),
+            ],
+            Output::Locales => vec![PathBuf::from(localization::LOCALE_DIR)],
+            Output::Units => vec![
+                PathBuf::from(tiers::TIERS_FILE),
+                PathBuf::from(rounding::ROUNDING_FILE),
+            ],
+        }
+    }
+}
+
+/// The outputs that read any of `changes`.
+fn affected(changes: &BTreeSet<PathBuf>) -> BTreeSet<Output> {
+    OUTPUTS
+        .iter()
+        .copied()
+        .filter(|output| under(changes, &output.inputs()))
+        .collect()
+}
+
+/// Whether any of `changes` is one of `roots` or inside it.
+fn under(changes: &BTreeSet<PathBuf>, roots: &[PathBuf]) -> bool {
+    changes
+        .iter()
+        .any(|path| roots.iter().any(|root| path.starts_with(root)))
+}
+
+/// One output's files and warnings.
+#[derive(Clone, Default)]
+struct Part {
+    files: Files,
+    warnings: Vec<String>,
+}
+
+/// Builds `output`, and for `Output::Assets` the asset report it came from.
+fn build(document: &MenuDocument, output: Output) -> (Part, Option<AssetReport>) {
+    let (files, warnings) = match output {
+        Output::Assets => {
+            let report = AssetReport::check(&document.menu, &assets::asset_dir());
+            let part = Part {
+                files: vec![("assets.json".to_string(), report.manifest_json())],
+                warnings: report.warnings(),
+            };
+            return (part, Some(report));
+        }
+        Output::Rules => (Vec::new(), crate::rule_warnings(&document.menu)),
+        Output::Locales => crate::locale_files(document),
+        Output::Units => crate::unit_files(document),
+    };
+    (Part { files, warnings }, None)
+}
+
+/// When each file under the watched paths was last changed, and its size.
+type Snapshot = BTreeMap<PathBuf, (Option<SystemTime>, u64)>;
+
+fn scan(path: &Path, snapshot: &mut Snapshot) {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => return,
+    };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            scan(&entry.path(), snapshot);
+        }
+    } else {
+        snapshot.insert(
+            path.to_path_buf(),
+            (metadata.modified().ok(), metadata.len()),
+        );
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for path in paths {
+        scan(path, &mut snapshot);
+    }
+    snapshot
+}
+
+/// Files added, removed or changed between two snapshots.
+fn changed(old: &Snapshot, new: &Snapshot) -> BTreeSet<PathBuf> {
+    old.keys()
+        .chain(new.keys())
+        .filter(|path| old.get(*path) != new.get(*path))
+        .cloned()
+        .collect()
+}
+
+/// The message a build panicked with.
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| {
+            payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+        })
+        .unwrap_or_else(|| "build failed".to_string())
+}
+
+/// Runs `build`, turning a panic into an error. The panic hook is silenced
+/// only meanwhile: build errors are reported by the watcher, anything else
+/// still panics loudly.
+fn attempt<T>(build: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let built = panic::catch_unwind(AssertUnwindSafe(build));
+    panic::set_hook(hook);
+    built.map_err(panic_message)
+}
+
+/// Problems that keep a build from being published: menus that do not parse
+/// back, and items or variations priced below zero.
+fn validate(files: &[(String, String)]) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (path, contents) in files.iter().filter(|(path, _)| path.ends_with(".json")) {
+        if path == "assets.json" {
+            continue;
+        }
+        let document: MenuDocument = match serde_json::from_str(contents) {
+            Ok(document) => document,
+            Err(e) => {
+                errors.push(format!("{} does not parse: {}", path, e));
+                continue;
+            }
+        };
+        for item in &document.menu.items {
+            if (item.price as i64) < 0 {
+                errors.push(format!("{}: {} is priced below zero", path, item.long_name));
+            }
+            for variation in &item.variations {
+                if variation.price.is_some_and(|price| (price as i64) < 0) {
+                    errors.push(format!(
+                        "{}: {} {} is priced below zero",
+                        path, item.long_name, variation.name
+                    ));
+                }
+            }
+        }
+    }
+    errors
+}
+
+/// What changed in each menu between the last good build and this one.
+fn print_changes(previous: &BTreeMap<String, String>, files: &[(String, String)]) {
+    for (path, contents) in files {
+        if path == "assets.json" {
+            continue;
+        }
+        let old = match previous.get(path) {
+            Some(old) => old,
+            None => {
+                println!("New file {}", path);
+                continue;
+            }
+        };
+        let (old, new) = match (
+            serde_json::from_str::<MenuDocument>(old),
+            serde_json::from_str::<MenuDocument>(contents),
+        ) {
+            (Ok(old), Ok(new)) => (old, new),
+            _ => continue,
+        };
+        let changelog = Changelog::new(
+            &format!("{} (last good)", path),
+            &old.menu,
+            "this build",
+            &new.menu,
+        );
+        if !changelog.diff.is_empty() {
+            print!("{}", changelog.text());
+        }
+    }
+    for path in previous.keys() {
+        if !files.iter().any(|(file, _)| file == path) {
+            println!("Removed file {}", path);
+        }
+    }
+}
+
+/// What is published now, to compare the next build with.
+fn published() -> BTreeMap<String, String> {
+    let root = Path::new(PUBLISH_DIR);
+    PublishIndex::load(root)
+        .current()
+        .map(|release| {
+            release
+                .files
+                .iter()
+                .filter_map(|path| Some((path.clone(), fs::read_to_string(root.join(path)).ok()?)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+struct Watcher<'a> {
+    document: &'a MenuDocument,
+    /// `menu.json` and the warnings about the menu itself.
+    menu: Part,
+    /// The last good build of each output.
+    outputs: BTreeMap<Output, Part>,
+    /// The asset report of the last good build.
+    assets: Option<AssetReport>,
+    /// Outputs whose last build failed, rebuilt again with the next change.
+    failed: BTreeSet<Output>,
+    /// The last good build's files.
+    previous: BTreeMap<String, String>,
+    /// Warnings already shown, so a rebuild only shows what is new.
+    warnings: BTreeSet<String>,
+    /// The last good availability of each overlay.
+    availability: BTreeMap<PathBuf, Availability>,
+}
+
+impl Watcher<'_> {
+    /// Rebuilds `outputs` and publishes them with the rest of the last good
+    /// build; on failure the published files are left alone.
+    fn rebuild(&mut self, outputs: BTreeSet<Output>) {
+        let document = self.document;
+        let outputs: BTreeSet<Output> = outputs.union(&self.failed).copied().collect();
+        let mut parts = self.outputs.clone();
+        let mut assets = None;
+        for &output in &outputs {
+            match attempt(|| build(document, output)) {
+                Ok((part, report)) => {
+                    parts.insert(output, part);
+                    assets = report.or(assets);
+                }
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    eprintln!("Build failed, keeping the last good menu");
+                    self.failed = outputs;
+                    return;
+                }
+            }
+        }
+        let files: Files = self
+            .menu
+            .files
+            .iter()
+            .chain(parts.values().flat_map(|part| &part.files))
+            .cloned()
+            .collect();
+
+        let warnings: BTreeSet<String> = self
+            .menu
+            .warnings
+            .iter()
+            .chain(parts.values().flat_map(|part| &part.warnings))
+            .cloned()
+            .collect();
+        for warning in warnings.difference(&self.warnings) {
+            eprintln!("warning: {}", warning);
+        }
+        let fixed = self.warnings.difference(&warnings).count();
+        if fixed > 0 {
+            println!("{} warnings fixed", fixed);
+        }
+        self.warnings = warnings;
+        let errors = validate(&files);
+        if !errors.is_empty() {
+            for error in &errors {
+                eprintln!("error: {}", error);
+            }
+            eprintln!("Validation failed, keeping the last good menu");
+            self.failed = outputs;
+            return;
+        }
+
+        print_changes(&self.previous, &files);
+        let assets = assets.or_else(|| self.assets.take());
+        let report = assets.as_ref().expect("Couldnt find the asset report");
+        match attempt(|| crate::publish_files(document, report, &files)) {
+            Ok(()) => {
+                self.previous = files.into_iter().collect();
+                self.outputs = parts;
+                self.failed.clear();
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                self.failed = outputs;
+            }
+        }
+        self.assets = assets;
+    }
+
+    /// Works out and writes a unit's availability; an overlay that does not
+    /// load leaves the last good availability file in place.
+    fn rebuild_overlay(&mut self, path: &Path) {
+        let menu = &self.document.menu;
+        let availability = match attempt(|| {
+            Availability::apply(menu, &UnitOverlay::load(&path.to_string_lossy()))
+        }) {
+            Ok(availability) => availability,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                eprintln!("Keeping the last good availability for {}", path.display());
+                return;
+            }
+        };
+        for warning in availability.warnings() {
+            eprintln!("warning: {}", warning);
+        }
+
+        let unavailable = |availability: &Availability| -> BTreeSet<String> {
+            availability
+                .unavailable_items
+                .iter()
+                .map(|item| format!("{} ({})", item.name, item.plu))
+                .collect()
+        };
+        let now = unavailable(&availability);
+        let before = self
+            .availability
+            .get(path)
+            .map(unavailable)
+            .unwrap_or_default();
+        for item in now.difference(&before) {
+            println!("{}: now unavailable: {}", availability.unit, item);
+        }
+        for item in before.difference(&now) {
+            println!("{}: available again: {}", availability.unit, item);
+        }
+        availability.write();
+        println!("Wrote {}", availability.file_name());
+        self.availability.insert(path.to_path_buf(), availability);
+    }
+
+    /// Proposes `price_requests.json` as the change set to review.
+    fn propose_prices(&self) {
+        match attempt(|| crate::propose_prices(self.document, PRICE_REQUESTS_FILE)) {
+            Ok(Ok(changes)) => {
+                changes.write(CHANGES_FILE);
+                println!("Proposed {} from {}", CHANGES_FILE, PRICE_REQUESTS_FILE);
+            }
+            Ok(Err(e)) | Err(e) => eprintln!("error: {}", e),
+        }
+    }
+
+    /// Shows `changes.json` and whether `apply` would take it.
+    fn review_changes(&self) {
+        match attempt(|| ChangeSet::load(CHANGES_FILE)) {
+            Ok(changes) => {
+                print!("{}", changes);
+                match changes.check(&self.document.menu, Path::new(MENU_SOURCE_DIR)) {
+                    Ok(()) => println!("Ready to apply"),
+                    Err(e) => println!("Cannot apply yet: {}", e),
+                }
+            }
+            Err(e) => eprintln!("error: {}", e),
+        }
+    }
+}
+
+/// Rebuilds the binary and, if that works, restarts the watcher on it. A
+/// build that fails leaves this watcher running on the last good menu.
+fn rebuild_binary(exe: &Path) {
+    let release: &[&str] = if cfg!(debug_assertions) {
+        &[]
+    } else {
+        &["--release"]
+    };
+    match Command::new("cargo").arg("build").args(release).status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            eprintln!("Build failed, keeping the last good menu");
+            return;
+        }
+        Err(e) => {
+            eprintln!("error: couldnt run cargo: {}", e);
+            return;
+        }
+    }
+    println!("Restarting on the new menu");
+    let error = restart(Command::new(exe).args(std::env::args_os().skip(1)));
+    eprintln!("error: couldnt restart {}: {}", exe.display(), error);
+}
+
+/// Replaces this process with `command`.
+#[cfg(unix)]
+fn restart(command: &mut Command) -> io::Error {
+    use std::os::unix::process::CommandExt;
+    command.exec()
+}
+
+/// Runs `command` in place of this process.
+#[cfg(not(unix))]
+fn restart(command: &mut Command) -> io::Error {
+    match command.status() {
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(e) => e,
+    }
+}
+
+/// The overlays to watch and how often to look, or `None` if `args` are not
+/// understood.
+fn parse_args(args: &[String]) -> Option<(Vec<PathBuf>, Duration)> {
+    let mut overlays = Vec::new();
+    let mut interval = DEFAULT_INTERVAL;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--overlay" => overlays.push(PathBuf::from(args.next()?)),
+            "--interval" => {
+                interval = Duration::try_from_secs_f64(args.next()?.parse().ok()?)
+                    .ok()
+                    .filter(|interval| !interval.is_zero())?;
+            }
+            _ => return None,
+        }
+    }
+    Some((overlays, interval))
+}
+
+/// `watch [--overlay <file>]... [--interval <seconds>]`
+pub fn run(document: &MenuDocument, args: &[String]) {
+    let (overlays, interval) = match parse_args(args) {
+        Some(parsed) => parsed,
+        None => {
+            eprintln!("{}", USAGE);
+            return;
+        }
+    };
+    // Taken before anything is rebuilt: once cargo replaces the binary, this
+    // process's own path may no longer point at it.
+    let exe = std::env::current_exe().expect("Couldnt find the watch binary");
+
+    let mut inputs: Vec<PathBuf> = OUTPUTS.iter().flat_map(|output| output.inputs()).collect();
+    inputs.sort();
+    inputs.dedup();
+    let sources = vec![PathBuf::from(MENU_SOURCE_DIR)];
+    let prices = vec![
+        PathBuf::from(PRICE_REQUESTS_FILE),
+        PathBuf::from(CHANGES_FILE),
+    ];
+    let watched: Vec<PathBuf> = inputs
+        .iter()
+        .chain(&sources)
+        .chain(&prices)
+        .chain(&overlays)
+        .cloned()
+        .collect();
+
+    let mut watcher = Watcher {
+        document,
+        menu: Part {
+            files: vec![("menu.json".to_string(), document.to_json())],
+            warnings: crate::menu_warnings(&document.menu),
+        },
+        outputs: BTreeMap::new(),
+        assets: None,
+        failed: BTreeSet::new(),
+        previous: published(),
+        warnings: BTreeSet::new(),
+        availability: BTreeMap::new(),
+    };
+    watcher.rebuild(OUTPUTS.iter().copied().collect());
+    for overlay in &overlays {
+        watcher.rebuild_overlay(overlay);
+    }
+
+    let mut last = snapshot(&watched);
+    println!("Watching {} files, press Ctrl-C to stop", last.len());
+    loop {
+        thread::sleep(interval);
+        let now = snapshot(&watched);
+        let changes = changed(&last, &now);
+        last = now;
+        if changes.is_empty() {
+            continue;
+        }
+        for path in &changes {
+            println!("Changed: {}", path.display());
+        }
+
+        if under(&changes, &sources) {
+            rebuild_binary(&exe);
+        }
+        let outputs = affected(&changes);
+        if !outputs.is_empty() {
+            watcher.rebuild(outputs);
+        }
+        for overlay in overlays.iter().filter(|overlay| changes.contains(*overlay)) {
+            watcher.rebuild_overlay(overlay);
+        }
+        // A new proposal is reviewed once the watcher sees it written.
+        if changes.contains(Path::new(PRICE_REQUESTS_FILE)) {
+            watcher.propose_prices();
+        } else if changes.contains(Path::new(CHANGES_FILE)) {
+            watcher.review_changes();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::menu;
+
+    #[test]
+    fn changed_lists_added_removed_and_modified_files() {
+        let at = |seconds| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds));
+        let old: Snapshot = vec![
+            (PathBuf::from("rounding.json"), (at(1), 10)),
+            (PathBuf::from("locales/es.json"), (at(1), 20)),
+            (PathBuf::from("images/1830.png"), (at(1), 30)),
+        ]
+        .into_iter()
+        .collect();
+        let mut new = old.clone();
+        new.insert(PathBuf::from("rounding.json"), (at(2), 10));
+        new.remove(Path::new("locales/es.json"));
+        new.insert(PathBuf::from("price_tiers.json"), (at(2), 40));
+
+        let expected: BTreeSet<PathBuf> = ["locales/es.json", "price_tiers.json", "rounding.json"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        assert_eq!(changed(&old, &new), expected);
+        assert!(changed(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn a_change_rebuilds_only_the_outputs_that_read_it() {
+        let changes =
+            |paths: &[&str]| -> BTreeSet<PathBuf> { paths.iter().map(PathBuf::from).collect() };
+        let outputs =
+            |outputs: &[Output]| -> BTreeSet<Output> { outputs.iter().copied().collect() };
+
+        let locale = Path::new(localization::LOCALE_DIR).join("es.json");
+        assert_eq!(
+            affected(&changes(&[locale.to_str().unwrap()])),
+            outputs(&[Output::Locales])
+        );
+        assert_eq!(
+            affected(&changes(&[rounding::ROUNDING_FILE])),
+            outputs(&[Output::Rules, Output::Units])
+        );
+        assert_eq!(
+            affected(&changes(&[tiers::TIERS_FILE, display_names::RULES_FILE])),
+            outputs(&[Output::Rules, Output::Units])
+        );
+        assert!(affected(&changes(&[CHANGES_FILE, "menu/tacos.rs"])).is_empty());
+    }
+
+    #[test]
+    fn parse_args_refuses_what_it_does_not_understand() {
+        let args =
+            |args: &[&str]| -> Vec<String> { args.iter().map(|arg| arg.to_string()).collect() };
+        assert_eq!(
+            parse_args(&args(&["--overlay", "unit.json", "--interval", "0.5"])),
+            Some((vec![PathBuf::from("unit.json")], Duration::from_millis(500)))
+        );
+        assert_eq!(parse_args(&[]), Some((Vec::new(), DEFAULT_INTERVAL)));
+        for bad in [
+            &["--overlay"][..],
+            &["--interval", "soon"],
+            &["--interval", "-1"],
+            &["--interval", "0"],
+            &["--verbose"],
+        ] {
+            assert_eq!(parse_args(&args(bad)), None, "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn validate_refuses_negative_prices_and_broken_menus() {
+        let mut document = menu::build();
+        assert!(validate(&[("menu.json".to_string(), document.to_json())]).is_empty());
+
+        let item = &mut document.menu.items[0];
+        item.price = -1 as _;
+        let name = item.long_name.clone();
+        let files = vec![
+            ("menu.json".to_string(), document.to_json()),
+            ("menu.es.json".to_string(), "{\"menu\": ".to_string()),
+            ("assets.json".to_string(), "not a menu".to_string()),
+        ];
+        let errors = validate(&files);
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert_eq!(
+            errors[0],
+            format!("menu.json: {} is priced below zero", name)
+        );
+        assert!(errors[1].starts_with("menu.es.json does not parse"));
+    }
+}